@@ -14,7 +14,7 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain, FFTPlanner, Radix};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::TestRng;
@@ -99,6 +99,35 @@ fn bench_coset_ifft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
     });
 }
 
+fn bench_planner_fft_in_place<F: PrimeField, const RADIX: u8>(b: &mut Bencher, degree: &usize) {
+    let (domain, mut a) = create_evaluation_domain::<F>(*degree);
+    let mut planner = FFTPlanner::new(radix(RADIX));
+    planner.precompute_for_domains([domain]);
+
+    b.iter(|| {
+        planner.fft_in_place(&domain, &mut a);
+    });
+}
+
+fn bench_planner_ifft_in_place<F: PrimeField, const RADIX: u8>(b: &mut Bencher, degree: &usize) {
+    let (domain, mut a) = create_evaluation_domain::<F>(*degree);
+    let mut planner = FFTPlanner::new(radix(RADIX));
+    planner.precompute_for_domains([domain]);
+
+    b.iter(|| {
+        planner.ifft_in_place(&domain, &mut a);
+    });
+}
+
+fn radix(radix: u8) -> Radix {
+    match radix {
+        2 => Radix::Two,
+        4 => Radix::Four,
+        8 => Radix::Eight,
+        _ => panic!("Unsupported radix {radix}"),
+    }
+}
+
 fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     let description = format!("{name:?} - subgroup_fft_in_place");
     setup_bench(c, &description, bench_fft_in_place::<F>);
@@ -110,8 +139,18 @@ fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     setup_bench(c, &description, bench_coset_ifft_in_place::<F>);
 }
 
+fn planner_benches<F: PrimeField, const RADIX: u8>(c: &mut Criterion, name: &str) {
+    let description = format!("{name:?} - planner_fft_in_place");
+    setup_bench(c, &description, bench_planner_fft_in_place::<F, RADIX>);
+    let description = format!("{name:?} - planner_ifft_in_place");
+    setup_bench(c, &description, bench_planner_ifft_in_place::<F, RADIX>);
+}
+
 fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
+    planner_benches::<Bls12_377_Fr, 2>(c, "BLS12-377 - planner radix-2");
+    planner_benches::<Bls12_377_Fr, 4>(c, "BLS12-377 - planner radix-4");
+    planner_benches::<Bls12_377_Fr, 8>(c, "BLS12-377 - planner radix-8");
 }
 
 criterion_group!(benches, bench_bls12_377);
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_algorithms::msm::*;
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::TestRng;
+
+use criterion::Criterion;
+
+#[macro_use]
+extern crate criterion;
+
+fn create_scalar_bases<G: AffineCurve<ScalarField = F>, F: PrimeField>(size: usize) -> (Vec<G>, Vec<F::BigInteger>) {
+    let mut rng = TestRng::default();
+
+    let bases = (0..size).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let scalars = (0..size).map(|_| F::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+    (bases, scalars)
+}
+
+fn precomputed_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+
+    for size in [1 << 10, 1 << 14, 1 << 16] {
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(size);
+
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 ({size})"), |b| {
+            b.iter(|| VariableBase::msm(&bases, &scalars))
+        });
+
+        // Benchmark one copy of the bases, eight copies, and one copy per window.
+        let copy_size = size * std::mem::size_of::<G1Affine>();
+        for num_copies in [1, 8, usize::MAX] {
+            let precomputed = PrecomputedBases::new(&bases, copy_size.saturating_mul(num_copies));
+            let num_copies = precomputed.num_copies();
+            c.bench_function(&format!("Precomputed MSM on BLS12-377 ({size}, {num_copies} copies)"), |b| {
+                b.iter(|| precomputed.msm(&scalars).unwrap())
+            });
+        }
+    }
+}
+
+fn precomputation_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+
+    let size = 1 << 14;
+    let (bases, _) = create_scalar_bases::<G1Affine, Fr>(size);
+    let copy_size = size * std::mem::size_of::<G1Affine>();
+
+    c.bench_function(&format!("Precompute bases on BLS12-377 ({size})"), |b| {
+        b.iter(|| PrecomputedBases::new(&bases, copy_size.saturating_mul(8)))
+    });
+}
+
+criterion_group! {
+    name = precomputed_group;
+    config = Criterion::default().sample_size(10);
+    targets = precomputed_bls12_377, precomputation_bls12_377
+}
+
+criterion_main!(precomputed_group);
@@ -220,7 +220,7 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
-    fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
+    pub(crate) fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
     }
 
@@ -885,6 +885,16 @@ pub struct FFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> FFTPrecomputation<F> {
+    /// Returns the domain of the precomputation.
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Returns the first `self.domain.size() / 2` roots of unity.
+    pub(crate) fn roots(&self) -> &[F] {
+        &self.roots
+    }
+
     pub fn to_ifft_precomputation(&self) -> IFFTPrecomputation<F> {
         let mut inverse_roots = self.roots.clone();
         snarkvm_fields::batch_inversion(&mut inverse_roots);
@@ -915,6 +925,16 @@ pub struct IFFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> IFFTPrecomputation<F> {
+    /// Returns the domain of the precomputation.
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Returns the inverses of the first `self.domain.size() / 2` roots of unity.
+    pub(crate) fn inverse_roots(&self) -> &[F] {
+        &self.inverse_roots
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { inverse_roots: vec![], domain: *domain }));
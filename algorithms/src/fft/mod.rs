@@ -21,6 +21,9 @@ pub use domain::EvaluationDomain;
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod planner;
+pub use planner::{FFTPlanner, Radix};
+
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
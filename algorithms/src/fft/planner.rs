@@ -0,0 +1,391 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An FFT planner, which caches the twiddles of the domains it is prepared for,
+//! and performs (I)FFTs with radix-2, radix-4, or radix-8 passes.
+
+use crate::{
+    cfg_into_iter,
+    cfg_iter_mut,
+    fft::{
+        DensePolynomial,
+        DomainCoeff,
+        EvaluationDomain,
+        Evaluations,
+        domain::{FFTPrecomputation, IFFTPrecomputation, derange},
+    },
+};
+use snarkvm_fields::{FftField, PrimeField};
+use snarkvm_utilities::execute_with_max_available_threads;
+
+use std::{collections::BTreeMap, sync::Arc};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The minimum number of butterflies handed to a single thread within a pass.
+const MIN_BLOCK_SIZE: usize = 1 << 6;
+
+/// The radix of the passes performed by the planner.
+///
+/// A radix-`2^r` pass performs `r` consecutive radix-2 stages on data that is loaded once,
+/// which reduces the number of passes over memory by a factor of `r`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Radix {
+    Two,
+    #[default]
+    Four,
+    Eight,
+}
+
+impl Radix {
+    /// Returns the number of radix-2 stages performed by a single pass.
+    pub const fn log(&self) -> u32 {
+        match self {
+            Self::Two => 1,
+            Self::Four => 2,
+            Self::Eight => 3,
+        }
+    }
+}
+
+/// Performs (I)FFTs over any number of domains, reusing the twiddles of the domains it was prepared for.
+///
+/// The twiddles of a domain are derived from those of the smallest cached domain that contains it,
+/// so seeding the planner with the largest domain of a circuit avoids recomputing roots of unity
+/// for every smaller domain.
+#[derive(Clone, Debug)]
+pub struct FFTPlanner<F: FftField> {
+    /// The radix of the passes.
+    radix: Radix,
+    /// The FFT twiddles, keyed by the domain size.
+    fft_cache: BTreeMap<usize, Arc<FFTPrecomputation<F>>>,
+    /// The IFFT twiddles, keyed by the domain size.
+    ifft_cache: BTreeMap<usize, Arc<IFFTPrecomputation<F>>>,
+}
+
+impl<F: FftField> FFTPlanner<F> {
+    /// Initializes an empty planner with the given radix.
+    pub fn new(radix: Radix) -> Self {
+        Self { radix, fft_cache: Default::default(), ifft_cache: Default::default() }
+    }
+
+    /// Initializes a planner with the given radix, seeded with the given precomputations.
+    pub fn with_precomputation(
+        radix: Radix,
+        fft_precomputation: Arc<FFTPrecomputation<F>>,
+        ifft_precomputation: Arc<IFFTPrecomputation<F>>,
+    ) -> Self {
+        let mut planner = Self::new(radix);
+        planner.fft_cache.insert(fft_precomputation.domain().size(), fft_precomputation);
+        planner.ifft_cache.insert(ifft_precomputation.domain().size(), ifft_precomputation);
+        planner
+    }
+
+    /// Returns the radix of the passes.
+    pub const fn radix(&self) -> Radix {
+        self.radix
+    }
+
+    /// Returns the sizes of the domains for which FFT twiddles are cached.
+    pub fn cached_domain_sizes(&self) -> Vec<usize> {
+        self.fft_cache.keys().copied().collect()
+    }
+
+    /// Caches the twiddles for each of the given domains.
+    pub fn precompute_for_domains(&mut self, domains: impl IntoIterator<Item = EvaluationDomain<F>>) {
+        for domain in domains {
+            let fft_precomputation = self.fft_precomputation(&domain);
+            self.fft_cache.insert(domain.size(), fft_precomputation);
+            let ifft_precomputation = self.ifft_precomputation(&domain);
+            self.ifft_cache.insert(domain.size(), ifft_precomputation);
+        }
+    }
+
+    /// Returns the FFT twiddles for the given domain.
+    /// If they are not cached, they are derived from the smallest cached superdomain, or computed.
+    pub fn fft_precomputation(&self, domain: &EvaluationDomain<F>) -> Arc<FFTPrecomputation<F>> {
+        let mut candidates = self.fft_cache.range(domain.size()..);
+        match candidates.next() {
+            Some((&size, precomputation)) if size == domain.size() => precomputation.clone(),
+            Some((_, precomputation)) => match precomputation.precomputation_for_subdomain(domain) {
+                Some(precomputation) => Arc::new(precomputation.into_owned()),
+                None => Arc::new(domain.precompute_fft()),
+            },
+            None => Arc::new(domain.precompute_fft()),
+        }
+    }
+
+    /// Returns the IFFT twiddles for the given domain.
+    /// If they are not cached, they are derived from the smallest cached superdomain, or computed.
+    pub fn ifft_precomputation(&self, domain: &EvaluationDomain<F>) -> Arc<IFFTPrecomputation<F>> {
+        let mut candidates = self.ifft_cache.range(domain.size()..);
+        match candidates.next() {
+            Some((&size, precomputation)) if size == domain.size() => precomputation.clone(),
+            Some((_, precomputation)) => match precomputation.precomputation_for_subdomain(domain) {
+                Some(precomputation) => Arc::new(precomputation.into_owned()),
+                None => Arc::new(domain.precompute_ifft()),
+            },
+            None => Arc::new(domain.precompute_ifft()),
+        }
+    }
+
+    /// Computes the FFT of `coeffs` over `domain`, padding `coeffs` with zeros to the size of the domain.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, domain: &EvaluationDomain<F>, coeffs: &mut Vec<T>) {
+        execute_with_max_available_threads(|| {
+            coeffs.resize(domain.size(), T::zero());
+            let precomputation = self.fft_precomputation(domain);
+            self.dif_passes(coeffs, precomputation.roots());
+            derange(coeffs);
+        });
+    }
+
+    /// Computes the IFFT of `evals` over `domain`, padding `evals` with zeros to the size of the domain.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, domain: &EvaluationDomain<F>, evals: &mut Vec<T>) {
+        execute_with_max_available_threads(|| {
+            evals.resize(domain.size(), T::zero());
+            let precomputation = self.ifft_precomputation(domain);
+            derange(evals);
+            self.dit_passes(evals, precomputation.inverse_roots());
+            cfg_iter_mut!(evals).for_each(|val| *val *= domain.size_inv);
+        });
+    }
+
+    /// Computes the FFT of `coeffs` over the coset of `domain` used by `EvaluationDomain::coset_fft`.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, domain: &EvaluationDomain<F>, coeffs: &mut Vec<T>) {
+        EvaluationDomain::distribute_powers(coeffs, F::multiplicative_generator());
+        self.fft_in_place(domain, coeffs);
+    }
+
+    /// Computes the IFFT of `evals` over the coset of `domain` used by `EvaluationDomain::coset_ifft`.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, domain: &EvaluationDomain<F>, evals: &mut Vec<T>) {
+        self.ifft_in_place(domain, evals);
+        EvaluationDomain::distribute_powers(evals, domain.generator_inv);
+    }
+
+    /// Performs the decimation-in-frequency stages, taking in-order inputs to bit-reversed outputs.
+    /// This produces the same output as the radix-2 `io_helper_with_roots`.
+    fn dif_passes<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
+        let log_n = xi.len().trailing_zeros();
+        let mut remaining = log_n;
+        while remaining > 0 {
+            let log_radix = self.radix.log().min(remaining);
+            // The gap of the last stage in this pass.
+            let quarter = 1 << (remaining - log_radix);
+            Self::pass(xi, roots, quarter, log_radix, Self::dif_butterflies);
+            remaining -= log_radix;
+        }
+    }
+
+    /// Performs the decimation-in-time stages, taking bit-reversed inputs to in-order outputs.
+    /// This produces the same output as the radix-2 `oi_helper_with_roots`.
+    fn dit_passes<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
+        let log_n = xi.len().trailing_zeros();
+        let mut done = 0;
+        while done < log_n {
+            let log_radix = self.radix.log().min(log_n - done);
+            // The gap of the first stage in this pass.
+            let quarter = 1 << done;
+            Self::pass(xi, roots, quarter, log_radix, Self::dit_butterflies);
+            done += log_radix;
+        }
+    }
+
+    /// Applies `butterflies` to every group of `2^log_radix` elements `xi[c + j + k * quarter]`,
+    /// where `c` is the start of a chunk of size `quarter << log_radix` and `j < quarter`.
+    fn pass<T: DomainCoeff<F>>(
+        xi: &mut [T],
+        roots: &[F],
+        quarter: usize,
+        log_radix: u32,
+        butterflies: fn(&mut [T], &[F], usize, usize, usize, u32),
+    ) {
+        let n = xi.len();
+        let radix = 1 << log_radix;
+        let chunk_size = quarter * radix;
+        let num_chunks = n / chunk_size;
+
+        #[cfg(not(feature = "serial"))]
+        let max_threads = snarkvm_utilities::parallel::max_available_threads();
+        #[cfg(feature = "serial")]
+        let max_threads = 1;
+
+        // Split each chunk into blocks of consecutive `j`s, so that every thread has enough work.
+        let block_size = match num_chunks >= max_threads {
+            true => quarter,
+            false => (n / radix / max_threads).clamp(MIN_BLOCK_SIZE.min(quarter), quarter),
+        };
+
+        // Collect the disjoint slices `xi[c + j0 + k * quarter..][..block_size]` of every block.
+        let mut blocks = Vec::with_capacity(n / radix / block_size);
+        for chunk in xi.chunks_mut(chunk_size) {
+            let mut rows = chunk.chunks_mut(quarter).map(|row| row.chunks_mut(block_size)).collect::<Vec<_>>();
+            for block_index in 0..quarter / block_size {
+                let block = rows.iter_mut().map(|row| row.next().unwrap()).collect::<Vec<_>>();
+                blocks.push((block_index * block_size, block));
+            }
+        }
+
+        cfg_into_iter!(blocks).for_each(|(j_start, mut block)| {
+            let mut values = [T::zero(); 8];
+            for jj in 0..block[0].len() {
+                for (value, row) in values.iter_mut().zip(&block) {
+                    *value = row[jj];
+                }
+                butterflies(&mut values[..radix], roots, n, quarter, j_start + jj, log_radix);
+                for (value, row) in values.iter().zip(&mut block) {
+                    row[jj] = *value;
+                }
+            }
+        });
+    }
+
+    /// Performs the decimation-in-frequency stages with gaps `quarter * 2^{log_radix - 1}, ..., quarter`
+    /// on `values[k] = xi[c + j + k * quarter]`.
+    #[inline(always)]
+    fn dif_butterflies<T: DomainCoeff<F>>(
+        values: &mut [T],
+        roots: &[F],
+        n: usize,
+        quarter: usize,
+        j: usize,
+        log_radix: u32,
+    ) {
+        for s in (0..log_radix).rev() {
+            let h = 1 << s;
+            // The stage with gap `h * quarter` uses the `2 * h * quarter`-th roots of unity.
+            let step = n / (2 * h * quarter);
+            for k in (0..values.len()).filter(|k| k & h == 0) {
+                let root = roots[(j + (k % h) * quarter) * step];
+                let (lo, hi) = (values[k], values[k + h]);
+                values[k] = lo + hi;
+                values[k + h] = lo - hi;
+                values[k + h] *= root;
+            }
+        }
+    }
+
+    /// Performs the decimation-in-time stages with gaps `quarter, ..., quarter * 2^{log_radix - 1}`
+    /// on `values[k] = xi[c + j + k * quarter]`.
+    #[inline(always)]
+    fn dit_butterflies<T: DomainCoeff<F>>(
+        values: &mut [T],
+        roots: &[F],
+        n: usize,
+        quarter: usize,
+        j: usize,
+        log_radix: u32,
+    ) {
+        for s in 0..log_radix {
+            let h = 1 << s;
+            // The stage with gap `h * quarter` uses the `2 * h * quarter`-th roots of unity.
+            let step = n / (2 * h * quarter);
+            for k in (0..values.len()).filter(|k| k & h == 0) {
+                let root = roots[(j + (k % h) * quarter) * step];
+                let (lo, mut hi) = (values[k], values[k + h]);
+                hi *= root;
+                values[k] = lo + hi;
+                values[k + h] = lo - hi;
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FFTPlanner<F> {
+    /// Interpolates the given evaluations into a polynomial.
+    pub fn interpolate(&self, evals: Evaluations<F>) -> DensePolynomial<F> {
+        let domain = evals.domain();
+        let mut evals = evals.evaluations;
+        self.ifft_in_place(&domain, &mut evals);
+        DensePolynomial::from_coefficients_vec(evals)
+    }
+
+    /// Interpolates the given evaluations into a polynomial, without consuming them.
+    pub fn interpolate_by_ref(&self, evals: &Evaluations<F>) -> DensePolynomial<F> {
+        let mut coeffs = evals.evaluations.clone();
+        self.ifft_in_place(&evals.domain(), &mut coeffs);
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const RADICES: [Radix; 3] = [Radix::Two, Radix::Four, Radix::Eight];
+
+    #[test]
+    fn test_planner_fft_matches_domain() {
+        let mut rng = TestRng::default();
+
+        for radix in RADICES {
+            let planner = FFTPlanner::<Fr>::new(radix);
+            for log_size in 0..12 {
+                let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+                let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+                let mut evals = coeffs.clone();
+                planner.fft_in_place(&domain, &mut evals);
+                assert_eq!(evals, domain.fft(&coeffs), "radix: {radix:?}, size: {}", domain.size());
+
+                let mut recovered = evals.clone();
+                planner.ifft_in_place(&domain, &mut recovered);
+                assert_eq!(recovered, coeffs, "radix: {radix:?}, size: {}", domain.size());
+
+                let mut coset_evals = coeffs.clone();
+                planner.coset_fft_in_place(&domain, &mut coset_evals);
+                assert_eq!(coset_evals, domain.coset_fft(&coeffs));
+
+                planner.coset_ifft_in_place(&domain, &mut coset_evals);
+                assert_eq!(coset_evals, coeffs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_planner_pads_inputs() {
+        let mut rng = TestRng::default();
+
+        let planner = FFTPlanner::<Fr>::new(Radix::Eight);
+        let domain = EvaluationDomain::<Fr>::new(1 << 7).unwrap();
+        let poly = DensePolynomial::<Fr>::rand(40, &mut rng);
+
+        let mut evals = poly.coeffs.clone();
+        planner.fft_in_place(&domain, &mut evals);
+        assert_eq!(evals, domain.fft(&poly.coeffs));
+        assert_eq!(planner.interpolate(Evaluations::from_vec_and_domain(evals, domain)), poly);
+    }
+
+    #[test]
+    fn test_planner_derives_subdomains() {
+        let largest = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        let fft_precomputation = Arc::new(largest.precompute_fft());
+        let ifft_precomputation = Arc::new(fft_precomputation.to_ifft_precomputation());
+        let mut planner = FFTPlanner::with_precomputation(Radix::Four, fft_precomputation, ifft_precomputation);
+
+        let domains = (0..=10).map(|log_size| EvaluationDomain::<Fr>::new(1 << log_size).unwrap()).collect::<Vec<_>>();
+        planner.precompute_for_domains(domains.iter().copied());
+        assert_eq!(planner.cached_domain_sizes(), domains.iter().map(|d| d.size()).collect::<Vec<_>>());
+
+        // Ensure the cached twiddles match freshly computed ones, and are reused on later calls.
+        for domain in &domains {
+            let precomputation = planner.fft_precomputation(domain);
+            assert_eq!(*precomputation, domain.precompute_fft());
+            assert!(Arc::ptr_eq(&precomputation, &planner.fft_precomputation(domain)));
+            assert_eq!(*planner.ifft_precomputation(domain), domain.precompute_ifft());
+        }
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks, cfg_into_iter, cfg_iter, cfg_reduce_with};
+
+use anyhow::{ensure, Result};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Precomputed tables for a multi-scalar multiplication over a fixed set of bases.
+///
+/// Pippenger's algorithm splits every scalar into `num_windows` windows of `window_size` bits,
+/// and pays for `window_size` doublings between consecutive windows. When the bases are known
+/// ahead of time (e.g. the powers of an SRS), we can instead store `num_copies` shifted copies
+/// `2^{window_size * stride * j} * base` of every base, so that a single bucket pass covers
+/// `num_copies` windows at once. With enough memory (`num_copies == num_windows`), the MSM needs
+/// no doublings at all and every window shares the same buckets.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The number of bases.
+    num_bases: usize,
+    /// The window size, in bits.
+    window_size: usize,
+    /// The number of windows covering a scalar.
+    num_windows: usize,
+    /// The number of shifted copies stored for each base.
+    num_copies: usize,
+    /// The number of windows between consecutive copies.
+    stride: usize,
+    /// The points `2^{window_size * stride * j} * bases[i]`, stored at index `i * num_copies + j`.
+    tables: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Precomputes the tables for the given bases, using at most `memory_budget` bytes
+    /// (but always at least one copy of the bases).
+    pub fn new(bases: &[G], memory_budget: usize) -> Self {
+        let num_bases = bases.len();
+
+        // Determine the window size (chosen as in the variable-base MSM).
+        let window_size = match num_bases < 32 {
+            true => 3,
+            false => super::ln_without_floats(num_bases) + 2,
+        };
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + window_size - 1) / window_size;
+
+        // Determine how many copies of the bases fit in the memory budget.
+        let bytes_per_copy = num_bases.saturating_mul(core::mem::size_of::<G>()).max(1);
+        let max_copies = (memory_budget / bytes_per_copy).clamp(1, num_windows);
+        // Spread the windows evenly across the copies, and drop any copy that would go unused.
+        let stride = (num_windows + max_copies - 1) / max_copies;
+        let num_copies = (num_windows + stride - 1) / stride;
+
+        // Compute the shifted copies of each base.
+        let shift = window_size * stride;
+        let tables = cfg_iter!(bases)
+            .map(|base| {
+                let mut power = base.to_projective();
+                (0..num_copies)
+                    .map(|_| {
+                        let copy = power;
+                        for _ in 0..shift {
+                            power.double_in_place();
+                        }
+                        copy
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let tables = G::Projective::batch_normalization_into_affine(tables.into_iter().flatten().collect());
+
+        Self { num_bases, window_size, num_windows, num_copies, stride, tables }
+    }
+
+    /// Returns the number of bases.
+    pub const fn num_bases(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns the window size, in bits.
+    pub const fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the number of shifted copies stored for each base.
+    pub const fn num_copies(&self) -> usize {
+        self.num_copies
+    }
+
+    /// Returns the number of bytes used by the tables.
+    pub fn size_in_bytes(&self) -> usize {
+        self.tables.len() * core::mem::size_of::<G>()
+    }
+
+    /// Returns `sum_i scalars[i] * bases[i]`, for the first `scalars.len()` bases.
+    pub fn msm(&self, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> Result<G::Projective> {
+        self.msm_with_offset(0, scalars)
+    }
+
+    /// Returns `sum_i scalars[i] * bases[start + i]`.
+    pub fn msm_with_offset(
+        &self,
+        start: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective> {
+        // Ensure the scalars are within the range of the precomputed bases.
+        ensure!(
+            start.checked_add(scalars.len()).is_some_and(|end| end <= self.num_bases),
+            "Found {} scalars at offset {start}, but only {} bases were precomputed",
+            scalars.len(),
+            self.num_bases
+        );
+
+        // Each pass covers the windows `r, r + stride, r + 2 * stride, ...`,
+        // so we traverse the passes from high to low, doubling in between.
+        let pass_sums: Vec<_> =
+            cfg_into_iter!(0..self.stride).map(|offset| self.pass(start, scalars, offset)).collect();
+        Ok(pass_sums.into_iter().rev().fold(G::Projective::zero(), |mut total, pass_sum| {
+            for _ in 0..self.window_size {
+                total.double_in_place();
+            }
+            total + pass_sum
+        }))
+    }
+
+    /// Returns the bucket sum for the windows `offset, offset + stride, offset + 2 * stride, ...`.
+    fn pass(
+        &self,
+        start: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        offset: usize,
+    ) -> G::Projective {
+        let num_buckets = (1 << self.window_size) - 1;
+        let chunk_size = Self::chunk_size(scalars.len());
+
+        // Accumulate the buckets for each chunk of scalars independently.
+        let chunk_buckets = cfg_chunks!(scalars, chunk_size).enumerate().map(|(chunk_index, chunk)| {
+            // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+            let mut buckets = vec![G::Projective::zero(); num_buckets];
+            for (i, scalar) in chunk.iter().enumerate() {
+                let index = start + chunk_index * chunk_size + i;
+                let table = &self.tables[index * self.num_copies..][..self.num_copies];
+                for (j, point) in table.iter().enumerate() {
+                    let window = j * self.stride + offset;
+                    if window >= self.num_windows {
+                        break;
+                    }
+                    let digit = window_digit(scalar.as_ref(), window * self.window_size, self.window_size);
+                    if digit != 0 {
+                        buckets[digit - 1].add_assign_mixed(point);
+                    }
+                }
+            }
+            buckets
+        });
+        let buckets = cfg_reduce_with!(chunk_buckets, |mut a, b| {
+            a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+            a
+        })
+        .unwrap_or_default();
+
+        // Sum the buckets, weighted by their index.
+        buckets
+            .into_iter()
+            .rev()
+            .scan(G::Projective::zero(), |sum, bucket| {
+                *sum += bucket;
+                Some(*sum)
+            })
+            .sum()
+    }
+
+    /// Returns the number of scalars processed by each thread.
+    fn chunk_size(num_scalars: usize) -> usize {
+        #[cfg(not(feature = "serial"))]
+        let num_threads = rayon::current_num_threads();
+        #[cfg(feature = "serial")]
+        let num_threads = 1;
+
+        ((num_scalars + num_threads - 1) / num_threads).max(1)
+    }
+}
+
+/// Returns the `c`-bit digit of the little-endian `limbs`, starting at bit `w_start`.
+fn window_digit(limbs: &[u64], w_start: usize, c: usize) -> usize {
+    let limb = w_start / 64;
+    let shift = w_start % 64;
+
+    let mut digit = limbs[limb] >> shift;
+    // If the window straddles two limbs, fetch the remaining bits from the next limb.
+    if shift + c > 64 && limb + 1 < limbs.len() {
+        digit |= limbs[limb + 1] << (64 - shift);
+    }
+    (digit & ((1 << c) - 1)) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msm::VariableBase;
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn create_scalar_bases(rng: &mut TestRng, size: usize) -> (Vec<G1Affine>, Vec<<Fr as PrimeField>::BigInteger>) {
+        let bases = (0..size).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..size).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+        (bases, scalars)
+    }
+
+    #[test]
+    fn test_precomputed_msm() {
+        let mut rng = TestRng::default();
+
+        for msm_size in [1, 5, 10, 50, 100, 500] {
+            let (bases, scalars) = create_scalar_bases(&mut rng, msm_size);
+            let expected = VariableBase::msm(&bases, &scalars).to_affine();
+
+            // Check a minimal budget, a full budget, and a budget in between.
+            let copy_size = msm_size * core::mem::size_of::<G1Affine>();
+            for memory_budget in [0, 7 * copy_size, usize::MAX] {
+                let precomputed = PrecomputedBases::new(&bases, memory_budget);
+                assert!(precomputed.size_in_bytes() <= memory_budget.max(copy_size));
+                assert_eq!(expected, precomputed.msm(&scalars).unwrap().to_affine(), "MSM size: {msm_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_precomputed_msm_with_fewer_scalars() {
+        let mut rng = TestRng::default();
+
+        let (bases, scalars) = create_scalar_bases(&mut rng, 100);
+        let precomputed = PrecomputedBases::new(&bases, usize::MAX);
+        assert_eq!(precomputed.num_bases(), 100);
+        assert!(precomputed.msm(&[]).unwrap().is_zero());
+
+        // Ensure a prefix of the bases can be used.
+        for num_scalars in [1, 37, 100] {
+            let expected = VariableBase::msm(&bases[..num_scalars], &scalars[..num_scalars]).to_affine();
+            assert_eq!(expected, precomputed.msm(&scalars[..num_scalars]).unwrap().to_affine());
+        }
+
+        // Ensure a window of the bases can be used.
+        for (start, num_scalars) in [(1, 99), (42, 17), (99, 1)] {
+            let expected = VariableBase::msm(&bases[start..][..num_scalars], &scalars[..num_scalars]).to_affine();
+            assert_eq!(expected, precomputed.msm_with_offset(start, &scalars[..num_scalars]).unwrap().to_affine());
+        }
+
+        // Ensure scalars beyond the precomputed bases are rejected.
+        let (_, more_scalars) = create_scalar_bases(&mut rng, 101);
+        assert!(precomputed.msm(&more_scalars).is_err());
+        assert!(precomputed.msm_with_offset(99, &scalars[..2]).is_err());
+        assert!(precomputed.msm_with_offset(usize::MAX, &scalars[..1]).is_err());
+    }
+}
@@ -14,6 +14,7 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::PrecomputedBases,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -152,6 +153,8 @@ pub struct Powers<'a, E: PairingEngine> {
     pub powers_of_beta_g: Cow<'a, [E::G1Affine]>,
     /// Group elements of the form `β^i γG`, for different values of `i`.
     pub powers_of_beta_times_gamma_g: Cow<'a, [E::G1Affine]>,
+    /// Optional precomputed tables containing `powers_of_beta_g`, along with the index of its first element.
+    pub precomputed_powers_of_beta_g: Option<(&'a PrecomputedBases<E::G1Affine>, usize)>,
 }
 
 impl<E: PairingEngine> Powers<'_, E> {
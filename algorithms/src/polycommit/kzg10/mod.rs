@@ -115,7 +115,13 @@ impl<E: PairingEngine> KZG10<E> {
                 let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
 
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-                let commitment = VariableBase::msm(bases, &plain_coeffs);
+                let commitment = match powers.precomputed_powers_of_beta_g {
+                    // If the bases have been precomputed, use the fixed-base tables.
+                    Some((precomputed, start)) => {
+                        precomputed.msm_with_offset(start + num_leading_zeros, &plain_coeffs)?
+                    }
+                    None => VariableBase::msm(bases, &plain_coeffs),
+                };
                 end_timer!(msm_time);
 
                 commitment
@@ -506,6 +512,7 @@ mod tests {
             let powers = Powers {
                 powers_of_beta_g: Cow::Owned(powers_of_beta_g),
                 powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
+                precomputed_powers_of_beta_g: None,
             };
             let vk = VerifierKey {
                 g: pp.power_of_beta_g(0).unwrap(),
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::{LabeledPolynomial, PolynomialInfo};
-use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
+use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, msm::PrecomputedBases, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
@@ -59,6 +59,14 @@ pub struct CommitterKey<E: PairingEngine> {
     /// Sorted in ascending order from smallest bound to largest bound.
     /// This is `None` if `self` does not support enforcing any degree bounds.
     pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// The precomputed fixed-base MSM tables for `powers_of_beta_g`.
+    /// These are derived from the powers, and are not serialized.
+    pub precomputed_powers_of_beta_g: Option<PrecomputedBases<E::G1Affine>>,
+
+    /// The precomputed fixed-base MSM tables for `shifted_powers_of_beta_g`.
+    /// These are derived from the powers, and are not serialized.
+    pub precomputed_shifted_powers_of_beta_g: Option<PrecomputedBases<E::G1Affine>>,
 }
 
 impl<E: PairingEngine> FromBytes for CommitterKey<E> {
@@ -182,6 +190,8 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
             shifted_powers_of_beta_g,
             shifted_powers_of_beta_times_gamma_g,
             enforced_degree_bounds,
+            precomputed_powers_of_beta_g: None,
+            precomputed_shifted_powers_of_beta_g: None,
         })
     }
 }
@@ -268,6 +278,27 @@ impl<E: PairingEngine> CommitterKey<E> {
     fn len(&self) -> usize {
         if self.shifted_powers_of_beta_g.is_some() { self.shifted_powers_of_beta_g.as_ref().unwrap().len() } else { 0 }
     }
+
+    /// Precomputes fixed-base MSM tables for `powers_of_beta_g` and `shifted_powers_of_beta_g`,
+    /// so that repeated commitments with this key reuse the tables instead of starting from scratch.
+    /// The `memory_budget` (in bytes) is split between the two in proportion to their sizes.
+    /// If the budget does not cover at least two copies of the powers, then no tables are precomputed,
+    /// as a single copy saves no work over the variable-base MSM.
+    pub fn precompute_msm_tables(&mut self, memory_budget: usize) {
+        let num_powers = self.powers_of_beta_g.len();
+        let num_shifted_powers = self.shifted_powers_of_beta_g.as_ref().map_or(0, |powers| powers.len());
+        let budget_per_power = memory_budget / (num_powers + num_shifted_powers).max(1);
+        if budget_per_power < 2 * core::mem::size_of::<E::G1Affine>() {
+            return;
+        }
+
+        self.precomputed_powers_of_beta_g =
+            Some(PrecomputedBases::new(&self.powers_of_beta_g, budget_per_power.saturating_mul(num_powers)));
+        self.precomputed_shifted_powers_of_beta_g = self
+            .shifted_powers_of_beta_g
+            .as_ref()
+            .map(|powers| PrecomputedBases::new(powers, budget_per_power.saturating_mul(num_shifted_powers)));
+    }
 }
 
 /// `CommitterUnionKey` is a union of `CommitterKey`s, useful for multi-circuit batch proofs.
@@ -294,6 +325,12 @@ pub struct CommitterUnionKey<'a, E: PairingEngine> {
     /// Sorted in ascending order from smallest bound to largest bound.
     /// This is `None` if `self` does not support enforcing any degree bounds.
     pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// The precomputed fixed-base MSM tables for `powers_of_beta_g`, if any.
+    pub precomputed_powers_of_beta_g: Option<&'a PrecomputedBases<E::G1Affine>>,
+
+    /// The precomputed fixed-base MSM tables for `shifted_powers_of_beta_g`, if any.
+    pub precomputed_shifted_powers_of_beta_g: Option<&'a PrecomputedBases<E::G1Affine>>,
 }
 
 impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
@@ -302,6 +339,7 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
         kzg10::Powers {
             powers_of_beta_g: self.powers_of_beta_g.unwrap().as_slice().into(),
             powers_of_beta_times_gamma_g: self.powers_of_beta_times_gamma_g.unwrap().as_slice().into(),
+            precomputed_powers_of_beta_g: self.precomputed_powers_of_beta_g.map(|precomputed| (precomputed, 0)),
         }
     }
 
@@ -318,6 +356,9 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
                 };
 
                 let ck = kzg10::Powers {
+                    precomputed_powers_of_beta_g: self
+                        .precomputed_shifted_powers_of_beta_g
+                        .map(|precomputed| (precomputed, powers_range.start)),
                     powers_of_beta_g: shifted_powers_of_beta_g[powers_range].into(),
                     powers_of_beta_times_gamma_g: shifted_powers_of_beta_times_gamma_g[&bound].clone().into(),
                 };
@@ -347,6 +388,8 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
             shifted_powers_of_beta_g: None,
             shifted_powers_of_beta_times_gamma_g: None,
             enforced_degree_bounds: None,
+            precomputed_powers_of_beta_g: None,
+            precomputed_shifted_powers_of_beta_g: None,
         };
         let mut enforced_degree_bounds = vec![];
         let mut biggest_ck: Option<&CommitterKey<E>> = None;
@@ -373,6 +416,8 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
         ck_union.powers_of_beta_g = Some(&biggest_ck.powers_of_beta_g);
         ck_union.powers_of_beta_times_gamma_g = Some(&biggest_ck.powers_of_beta_times_gamma_g);
         ck_union.shifted_powers_of_beta_g = biggest_ck.shifted_powers_of_beta_g.as_ref();
+        ck_union.precomputed_powers_of_beta_g = biggest_ck.precomputed_powers_of_beta_g.as_ref();
+        ck_union.precomputed_shifted_powers_of_beta_g = biggest_ck.precomputed_shifted_powers_of_beta_g.as_ref();
 
         if !enforced_degree_bounds.is_empty() {
             enforced_degree_bounds.sort();
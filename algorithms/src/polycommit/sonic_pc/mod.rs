@@ -152,6 +152,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             shifted_powers_of_beta_g,
             shifted_powers_of_beta_times_gamma_g,
             enforced_degree_bounds,
+            precomputed_powers_of_beta_g: None,
+            precomputed_shifted_powers_of_beta_g: None,
         };
 
         let vk = pp.to_universal_verifier()?;
@@ -681,8 +683,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 mod tests {
     #![allow(non_camel_case_types)]
 
-    use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
+    use super::{CommitterKey, CommitterUnionKey, LabeledPolynomial, SonicKZG10};
+    use crate::{crypto_hash::PoseidonSponge, fft::DensePolynomial, polycommit::test_templates::*};
     use snarkvm_curves::bls12_377::{Bls12_377, Fq};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
@@ -710,6 +712,42 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_commit_with_precomputed_msm_tables() {
+        let rng = &mut TestRng::default();
+        let (max_degree, supported_degree, degree_bounds) = (64, 48, [16, 32]);
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+
+        // Trim the same committer key twice, and precompute the MSM tables for one of them.
+        let (ck, _) = PC_Bls12_377::trim(&pp, supported_degree, None, 0, Some(&degree_bounds)).unwrap();
+        let (mut precomputed_ck, _) = PC_Bls12_377::trim(&pp, supported_degree, None, 0, Some(&degree_bounds)).unwrap();
+        precomputed_ck.precompute_msm_tables(1 << 20);
+        assert!(precomputed_ck.precomputed_powers_of_beta_g.is_some());
+        assert!(precomputed_ck.precomputed_shifted_powers_of_beta_g.is_some());
+        // Ensure no tables are precomputed, if the budget does not cover two copies of the powers.
+        let (mut skipped_ck, _) = PC_Bls12_377::trim(&pp, supported_degree, None, 0, Some(&degree_bounds)).unwrap();
+        skipped_ck.precompute_msm_tables(1 << 10);
+        assert!(skipped_ck.precomputed_powers_of_beta_g.is_none());
+
+        // Sample polynomials with and without degree bounds.
+        let polynomials = [(48, None), (5, None), (16, Some(16)), (30, Some(32))]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (degree, degree_bound))| {
+                LabeledPolynomial::new(format!("Test{i}"), DensePolynomial::rand(degree, rng), degree_bound, None)
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the commitments match.
+        let commit = |ck: &CommitterKey<Bls12_377>| {
+            let ck = CommitterUnionKey::union(std::iter::once(ck));
+            PC_Bls12_377::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), None).unwrap().0
+        };
+        assert_eq!(commit(&ck), commit(&precomputed_ck));
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -16,6 +16,8 @@ use crate::{
     fft::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         EvaluationDomain,
+        FFTPlanner,
+        Radix,
     },
     polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination},
     r1cs::SynthesisError,
@@ -32,7 +34,7 @@ use snarkvm_fields::{Field, PrimeField};
 
 use core::{borrow::Borrow, marker::PhantomData};
 use itertools::Itertools;
-use std::{collections::BTreeMap, fmt::Write};
+use std::{collections::BTreeMap, fmt::Write, sync::Arc};
 
 /// The algebraic holographic proof defined in [CHMMVW19](https://eprint.iacr.org/2019/1047).
 /// Currently, this AHP only supports inputs of size one
@@ -162,6 +164,19 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Some((fft_precomputation, ifft_precomputation))
     }
 
+    /// Initializes an FFT planner seeded with the given precomputations,
+    /// which caches the twiddles of the domains with the given sizes.
+    pub fn fft_planner(
+        fft_precomputation: Arc<FFTPrecomputation<F>>,
+        ifft_precomputation: Arc<IFFTPrecomputation<F>>,
+        domain_sizes: &[usize],
+    ) -> Option<FFTPlanner<F>> {
+        let domains = domain_sizes.iter().map(|size| EvaluationDomain::new(*size)).collect::<Option<Vec<_>>>()?;
+        let mut planner = FFTPlanner::with_precomputation(Radix::default(), fft_precomputation, ifft_precomputation);
+        planner.precompute_for_domains(domains);
+        Some(planner)
+    }
+
     /// Construct the linear combinations that are checked by the AHP.
     /// Public input should be unformatted.
     /// We construct the linear combinations as per section 5 of our protocol documentation.
@@ -18,6 +18,7 @@ use crate::{
     fft::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         EvaluationDomain,
+        FFTPlanner,
     },
     polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
@@ -34,6 +35,7 @@ use blake2::Digest;
 use hex::FromHex;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{serialize::*, SerializationError};
+use std::sync::Arc;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, CanonicalSerialize, CanonicalDeserialize)]
 pub struct CircuitId(pub [u8; 32]);
@@ -79,8 +81,10 @@ pub struct Circuit<F: PrimeField, SM: SNARKMode> {
     pub b_arith: MatrixEvals<F>,
     pub c_arith: MatrixEvals<F>,
 
-    pub fft_precomputation: FFTPrecomputation<F>,
-    pub ifft_precomputation: IFFTPrecomputation<F>,
+    pub fft_precomputation: Arc<FFTPrecomputation<F>>,
+    pub ifft_precomputation: Arc<IFFTPrecomputation<F>>,
+    /// Caches the twiddles of the circuit's domains, and is shared across the prover rounds.
+    pub fft_planner: FFTPlanner<F>,
     pub(crate) _mode: PhantomData<SM>,
     pub(crate) id: CircuitId,
 }
@@ -215,6 +219,15 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalDeserialize for Circuit<F, SM> {
             non_zero_c_domain_size,
         )
         .ok_or(SerializationError::InvalidData)?;
+        let (fft_precomputation, ifft_precomputation) = (Arc::new(fft_precomputation), Arc::new(ifft_precomputation));
+        let fft_planner = AHPForR1CS::<F, SM>::fft_planner(fft_precomputation.clone(), ifft_precomputation.clone(), &[
+            constraint_domain_size,
+            variable_domain_size,
+            non_zero_a_domain_size,
+            non_zero_b_domain_size,
+            non_zero_c_domain_size,
+        ])
+        .ok_or(SerializationError::InvalidData)?;
         let a = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
         let b = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
         let c = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
@@ -229,6 +242,7 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalDeserialize for Circuit<F, SM> {
             c_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             fft_precomputation,
             ifft_precomputation,
+            fft_planner,
             _mode: PhantomData,
             id,
         })
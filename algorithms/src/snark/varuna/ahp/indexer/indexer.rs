@@ -32,7 +32,7 @@ use snarkvm_utilities::cfg_into_iter;
 use anyhow::{anyhow, ensure, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -74,6 +74,15 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             non_zero_c_domain.size(),
         )
         .ok_or(anyhow!("The polynomial degree is too large"))?;
+        let (fft_precomputation, ifft_precomputation) = (Arc::new(fft_precomputation), Arc::new(ifft_precomputation));
+        let fft_planner = Self::fft_planner(fft_precomputation.clone(), ifft_precomputation.clone(), &[
+            constraint_domain.size(),
+            variable_domain.size(),
+            non_zero_a_domain.size(),
+            non_zero_b_domain.size(),
+            non_zero_c_domain.size(),
+        ])
+        .ok_or(anyhow!("The polynomial degree is too large"))?;
         end_timer!(fft_precomp_time);
 
         Ok(Circuit {
@@ -86,6 +95,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             c_arith,
             fft_precomputation,
             ifft_precomputation,
+            fft_planner,
             id,
             _mode: PhantomData,
         })
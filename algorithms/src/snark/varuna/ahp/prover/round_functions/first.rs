@@ -138,7 +138,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let x_evals = {
             let mut coeffs = x_poly.coeffs;
             coeffs.resize(variable_domain.size(), F::zero());
-            variable_domain.in_order_fft_in_place_with_pc(&mut coeffs, &circuit.fft_precomputation);
+            circuit.fft_planner.fft_in_place(&variable_domain, &mut coeffs);
             coeffs
         };
 
@@ -149,8 +149,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                 _ => w_extended[k - (k / ratio) - 1] - x_evals[k],
             })
             .collect();
-        let w_poly = EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, variable_domain)
-            .interpolate_with_pc(&circuit.ifft_precomputation);
+        let w_poly =
+            circuit.fft_planner.interpolate(EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, variable_domain));
         let (w_poly, remainder) = w_poly.divide_by_vanishing_poly(input_domain).unwrap();
         assert!(remainder.is_zero());
 
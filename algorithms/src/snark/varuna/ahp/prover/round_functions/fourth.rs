@@ -19,6 +19,7 @@ use crate::{
         DensePolynomial,
         EvaluationDomain,
         Evaluations as EvaluationsOnDomain,
+        FFTPlanner,
     },
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
@@ -113,6 +114,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                         max_non_zero_domain_size,
                         &circuit.fft_precomputation,
                         &circuit.ifft_precomputation,
+                        &circuit.fft_planner,
                     );
                     (circuit, result)
                 });
@@ -164,6 +166,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         max_non_zero_domain: EvaluationDomain<F>,
         fft_precomputation: &FFTPrecomputation<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
+        fft_planner: &FFTPlanner<F>,
     ) -> Result<(Sum<F>, Lhs<F>, Gpoly<F>, Apoly<F>, Bpoly<F>)> {
         let (row_on_K, col_on_K, row_col_val) =
             (&arithmetization.row, &arithmetization.col, &arithmetization.row_col_val);
@@ -175,8 +178,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let a_poly_time = start_timer!(|| format!("Computing a poly for {label}"));
             let a_poly = {
                 let evals = cfg_iter!(row_col_val.evaluations).map(|v| v_R_i_alpha_v_C_i_beta * v).collect();
-                EvaluationsOnDomain::from_vec_and_domain(evals, non_zero_domain)
-                    .interpolate_with_pc(ifft_precomputation)
+                fft_planner.interpolate(EvaluationsOnDomain::from_vec_and_domain(evals, non_zero_domain))
             };
             end_timer!(a_poly_time);
             a_poly
@@ -190,8 +192,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                     .zip_eq(&col_on_K.evaluations)
                     .map(|(&r, &c)| R_size * C_size * (alpha_beta - beta * r - alpha * c + r * c))
                     .collect();
-                EvaluationsOnDomain::from_vec_and_domain(evals, non_zero_domain)
-                    .interpolate_with_pc(ifft_precomputation)
+                fft_planner.interpolate(EvaluationsOnDomain::from_vec_and_domain(evals, non_zero_domain))
             };
             end_timer!(b_poly_time);
             b_poly
@@ -214,8 +215,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         let f_poly_time = start_timer!(|| format!("Computing f poly for {label}"));
         // we define f as the rational equation for which we're running the sumcheck protocol
-        let f = EvaluationsOnDomain::from_vec_and_domain(f_evals_on_K, non_zero_domain)
-            .interpolate_with_pc(ifft_precomputation);
+        let f = fft_planner.interpolate(EvaluationsOnDomain::from_vec_and_domain(f_evals_on_K, non_zero_domain));
 
         end_timer!(f_poly_time);
         let g = DensePolynomial::from_coefficients_slice(&f.coeffs[1..]);
@@ -150,7 +150,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let poly_time = start_timer!(|| format!("Computing {label}"));
 
         let evals = EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain);
-        let poly = evals.interpolate_with_pc_by_ref(&circuit.ifft_precomputation);
+        let poly = circuit.fft_planner.interpolate_by_ref(&evals);
 
         debug_assert!(
             poly.evaluate_over_domain_by_ref(constraint_domain)
@@ -19,6 +19,7 @@ use crate::{
         DensePolynomial,
         EvaluationDomain,
         Evaluations,
+        FFTPlanner,
     },
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
@@ -152,6 +153,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let variable_domain = &circuit_specific_state.variable_domain;
             let fft_precomputation = &circuit.fft_precomputation;
             let ifft_precomputation = &circuit.ifft_precomputation;
+            let fft_planner = &circuit.fft_planner;
 
             for (&instance_combiner, assignment) in itertools::izip!(instance_combiners, assignments_i) {
                 for (label, matrix_combiner) in itertools::izip!(matrix_labels, matrix_combiners) {
@@ -165,6 +167,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                             max_variable_domain,
                             fft_precomputation,
                             ifft_precomputation,
+                            fft_planner,
                             assignment,
                             matrix_transpose,
                             *alpha,
@@ -283,6 +286,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         max_variable_domain: &EvaluationDomain<F>,
         fft_precomputation: &FFTPrecomputation<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
+        fft_planner: &FFTPlanner<F>,
         assignment: &DensePolynomial<F>,
         matrix_transpose: &Matrix<F>,
         alpha: F,
@@ -306,8 +310,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         end_timer!(m_at_alpha_evals_time);
 
         let z_m_at_alpha_time = start_timer!(|| format!("Compute z_m_at_alpha_time for {_label}"));
-        let m_at_alpha = Evaluations::from_vec_and_domain(m_at_alpha_evals, *variable_domain)
-            .interpolate_with_pc(ifft_precomputation);
+        let m_at_alpha = fft_planner.interpolate(Evaluations::from_vec_and_domain(m_at_alpha_evals, *variable_domain));
         let mut multiplier = PolyMultiplier::new();
         multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
         multiplier.add_polynomial(m_at_alpha, "m_at_alpha");
@@ -38,6 +38,11 @@ pub struct CircuitProvingKey<E: PairingEngine, SM: SNARKMode> {
     pub committer_key: Arc<sonic_pc::CommitterKey<E>>,
 }
 
+impl<E: PairingEngine, SM: SNARKMode> CircuitProvingKey<E, SM> {
+    /// The memory budget (in bytes) of the fixed-base MSM tables, which are precomputed for the committer key.
+    pub const MSM_TABLES_MEMORY_BUDGET: usize = 1 << 26;
+}
+
 impl<E: PairingEngine, SM: SNARKMode> ToBytes for CircuitProvingKey<E, SM> {
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
         CanonicalSerialize::serialize_compressed(&self.circuit_verifying_key, &mut writer)?;
@@ -52,7 +57,10 @@ impl<E: PairingEngine, SM: SNARKMode> FromBytes for CircuitProvingKey<E, SM> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         let circuit_verifying_key = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
         let circuit = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
-        let committer_key = Arc::new(FromBytes::read_le(&mut reader)?);
+        let mut committer_key: sonic_pc::CommitterKey<E> = FromBytes::read_le(&mut reader)?;
+        // Precompute the fixed-base MSM tables, which are not serialized.
+        committer_key.precompute_msm_tables(Self::MSM_TABLES_MEMORY_BUDGET);
+        let committer_key = Arc::new(committer_key);
 
         Ok(Self { circuit_verifying_key, circuit, committer_key })
     }
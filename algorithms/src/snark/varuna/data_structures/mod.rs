@@ -28,6 +28,10 @@ pub use circuit_verifying_key::*;
 pub(super) mod proof;
 pub use proof::*;
 
+/// The versioned byte encoding of the Varuna data structures.
+pub(super) mod versioned;
+pub use versioned::*;
+
 /// A test circuit.
 #[cfg(any(test, feature = "test"))]
 pub(super) mod test_circuit;
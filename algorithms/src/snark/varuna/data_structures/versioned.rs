@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+/// A Varuna data structure with a versioned byte encoding.
+///
+/// The encoding starts with a version byte, which determines the layout of the remaining bytes:
+///  - Version 1 is followed by the compressed `CanonicalSerialize` encoding of the data structure.
+///  - Version 2 is followed by the size of the compressed encoding in bytes, and then the compressed encoding.
+///    The size bounds the reader, and ensures the data structure is read in full.
+///
+/// Note: The size of an encoding determines the fees and storage costs of a transaction, so version 2 is
+/// only accepted on read, and is not written until it is activated at a consensus height.
+pub trait VersionedBytes: CanonicalSerialize + CanonicalDeserialize {
+    /// The version of the encoding that is written.
+    const VERSION: u8 = 1;
+
+    /// Writes the data structure, using the current version of the encoding.
+    fn write_versioned_le<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.write_versioned_le_as(Self::VERSION, writer)
+    }
+
+    /// Writes the data structure, using the given version of the encoding.
+    fn write_versioned_le_as<W: Write>(&self, version: u8, mut writer: W) -> io::Result<()> {
+        // Serialize the data structure.
+        let mut encoding = Vec::new();
+        self.serialize_compressed(&mut encoding)
+            .map_err(|_| error("Failed to serialize a versioned data structure"))?;
+        // Write the version.
+        version.write_le(&mut writer)?;
+        // Write the data structure, using the layout of the given version.
+        match version {
+            1 => (),
+            2 => {
+                // Write the size of the encoding.
+                // Note: The size is taken from the encoding, as `compressed_size` may overestimate it.
+                let size = u32::try_from(encoding.len()).map_err(|e| error(e.to_string()))?;
+                size.write_le(&mut writer)?;
+            }
+            _ => return Err(error(format!("Invalid data structure version ({version})"))),
+        }
+        // Write the encoding.
+        writer.write_all(&encoding)
+    }
+
+    /// Reads the data structure, using the version of the encoding that was written.
+    fn read_versioned_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Read the data structure, using the layout of the given version.
+        match version {
+            1 => Self::deserialize_compressed(&mut reader)
+                .map_err(|_| error("Failed to deserialize a (v1) data structure")),
+            2 => {
+                // Read the size of the encoding.
+                let size = u32::read_le(&mut reader)?;
+                // Read the encoding, without reading past its size.
+                let mut encoding = reader.take(u64::from(size));
+                let candidate = Self::deserialize_compressed(&mut encoding)
+                    .map_err(|_| error("Failed to deserialize a (v2) data structure"))?;
+                // Ensure the encoding was read in full.
+                match encoding.limit() == 0 {
+                    true => Ok(candidate),
+                    false => Err(error("The size of a (v2) data structure does not match its encoding")),
+                }
+            }
+            _ => Err(error(format!("Invalid data structure version ({version})"))),
+        }
+    }
+}
+
+impl<E: PairingEngine> VersionedBytes for Certificate<E> {}
+
+impl<E: PairingEngine> VersionedBytes for CircuitVerifyingKey<E> {}
+
+impl<E: PairingEngine> VersionedBytes for Proof<E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polycommit::{kzg10::KZGProof, sonic_pc};
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fr, G1Affine},
+        AffineCurve,
+    };
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_versions() {
+        let rng = &mut TestRng::default();
+
+        // Sample a certificate.
+        let proof = KZGProof::<Bls12_377> { w: G1Affine::prime_subgroup_generator(), random_v: Some(Fr::rand(rng)) };
+        let certificate = Certificate::new(sonic_pc::BatchLCProof { proof: sonic_pc::BatchProof(vec![proof]) });
+
+        // Ensure the current version round-trips, and is written without a size.
+        let mut bytes = vec![];
+        certificate.write_versioned_le(&mut bytes).unwrap();
+        assert_eq!(bytes, [&[1u8][..], &certificate.to_bytes_le().unwrap()].concat());
+        assert_eq!(Certificate::read_versioned_le(&bytes[..]).unwrap(), certificate);
+
+        // Ensure version 2 round-trips.
+        let mut bytes = vec![];
+        certificate.write_versioned_le_as(2, &mut bytes).unwrap();
+        assert_eq!(bytes[0], 2);
+        assert_eq!(Certificate::read_versioned_le(&bytes[..]).unwrap(), certificate);
+
+        // Ensure a size that does not match the encoding is rejected.
+        let mut candidate = bytes.clone();
+        candidate[1] += 1;
+        candidate.push(0);
+        assert!(Certificate::<Bls12_377>::read_versioned_le(&candidate[..]).is_err());
+
+        // Ensure an unknown version is rejected.
+        let mut candidate = bytes;
+        candidate[0] = 3;
+        assert!(Certificate::<Bls12_377>::read_versioned_le(&candidate[..]).is_err());
+        assert!(certificate.write_versioned_le_as(3, &mut vec![]).is_err());
+    }
+}
@@ -90,13 +90,15 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
             // Varuna only needs degree 2 random polynomials.
             let supported_hiding_bound = 1;
             let supported_lagrange_sizes = [].into_iter(); // TODO: consider removing lagrange_bases_at_beta_g from CommitterKey
-            let (committer_key, _) = SonicKZG10::<E, FS>::trim(
+            let (mut committer_key, _) = SonicKZG10::<E, FS>::trim(
                 universal_srs,
                 indexed_circuit.max_degree()?,
                 supported_lagrange_sizes,
                 supported_hiding_bound,
                 Some(coefficient_support.as_slice()),
             )?;
+            // Precompute the fixed-base MSM tables, so that every commitment with this key reuses them.
+            committer_key.precompute_msm_tables(CircuitProvingKey::<E, SM>::MSM_TABLES_MEMORY_BUDGET);
 
             let ck = CommitterUnionKey::union(std::iter::once(&committer_key));
 
@@ -0,0 +1,308 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Assignment, AssignmentLC, AssignmentVariable};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{BigInteger, ToBytes};
+
+use console::prelude::{Result, bail, ensure};
+use indexmap::IndexMap;
+use std::io::Write;
+
+/// The magic bytes of an iden3 `.r1cs` file.
+pub(super) const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+/// The version of the iden3 `.r1cs` format.
+pub(super) const R1CS_VERSION: u32 = 1;
+/// The magic bytes of an iden3 `.wtns` file.
+pub(super) const WTNS_MAGIC: &[u8; 4] = b"wtns";
+/// The version of the iden3 `.wtns` format.
+pub(super) const WTNS_VERSION: u32 = 2;
+
+/// The section types of an iden3 `.r1cs` file.
+pub(super) const R1CS_HEADER_SECTION: u32 = 1;
+pub(super) const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+pub(super) const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+/// The section types of an iden3 `.wtns` file.
+pub(super) const WTNS_HEADER_SECTION: u32 = 1;
+pub(super) const WTNS_WITNESS_SECTION: u32 = 2;
+
+/// Exports of the assignment for external tooling.
+///
+/// The wires are laid out as in circom: wire `0` is the constant `1`, followed by the remaining
+/// public variables, and then the private variables. The circuit has no designated outputs, and
+/// every private variable is exported as an internal wire (i.e. not as a private input).
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the number of wires in the assignment.
+    pub fn num_wires(&self) -> u64 {
+        self.num_public() + self.num_private()
+    }
+
+    /// Returns the constraints of the assignment in the iden3 `.r1cs` binary format.
+    pub fn to_r1cs_bytes(&self) -> Result<Vec<u8>> {
+        let num_public = u32::try_from(self.num_public())?;
+        let num_wires = u32::try_from(self.num_wires())?;
+        let num_constraints = u32::try_from(self.num_constraints())?;
+        ensure!(num_public > 0, "The assignment is missing the constant public variable");
+
+        // Write the header section.
+        let mut header = Vec::new();
+        (field_size_in_bytes::<F>() as u32).write_le(&mut header)?;
+        write_field_modulus::<F>(&mut header)?;
+        num_wires.write_le(&mut header)?;
+        // The number of public outputs.
+        0u32.write_le(&mut header)?;
+        // The number of public inputs, excluding the constant wire.
+        (num_public - 1).write_le(&mut header)?;
+        // The number of private inputs.
+        0u32.write_le(&mut header)?;
+        // The number of labels.
+        u64::from(num_wires).write_le(&mut header)?;
+        num_constraints.write_le(&mut header)?;
+
+        // Write the constraints section.
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.constraints().iter() {
+            for lc in [a, b, c] {
+                let terms = self.to_wire_terms(lc)?;
+                (terms.len() as u32).write_le(&mut constraints)?;
+                for (wire, coefficient) in terms {
+                    wire.write_le(&mut constraints)?;
+                    coefficient.to_bigint().write_le(&mut constraints)?;
+                }
+            }
+        }
+
+        // Write the wire-to-label section, which maps every wire to itself.
+        let mut labels = Vec::with_capacity(num_wires as usize * 8);
+        for wire in 0..u64::from(num_wires) {
+            wire.write_le(&mut labels)?;
+        }
+
+        let mut bytes = Vec::new();
+        write_file_header(&mut bytes, R1CS_MAGIC, R1CS_VERSION, 3)?;
+        write_section(&mut bytes, R1CS_HEADER_SECTION, &header)?;
+        write_section(&mut bytes, R1CS_CONSTRAINTS_SECTION, &constraints)?;
+        write_section(&mut bytes, R1CS_WIRE_TO_LABEL_SECTION, &labels)?;
+        Ok(bytes)
+    }
+
+    /// Returns the constraints of the assignment as JSON, in the layout of `snarkjs r1cs export json`.
+    pub fn to_r1cs_json(&self) -> Result<serde_json::Value> {
+        let constraints = self
+            .constraints()
+            .iter()
+            .map(|(a, b, c)| {
+                [a, b, c]
+                    .into_iter()
+                    .map(|lc| {
+                        let terms = self.to_wire_terms(lc)?.into_iter();
+                        Ok(terms
+                            .map(|(wire, coefficient)| (wire.to_string(), coefficient.to_string().into()))
+                            .collect())
+                    })
+                    .collect::<Result<Vec<serde_json::Map<_, _>>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(serde_json::json!({
+            "n8": field_size_in_bytes::<F>(),
+            "prime": F::modulus().to_string(),
+            "nVars": self.num_wires(),
+            "nOutputs": 0,
+            "nPubInputs": self.num_public().saturating_sub(1),
+            "nPrvInputs": 0,
+            "nLabels": self.num_wires(),
+            "nConstraints": self.num_constraints(),
+            "useCustomGates": false,
+            "constraints": constraints,
+            "map": (0..self.num_wires()).collect::<Vec<_>>(),
+        }))
+    }
+
+    /// Returns the values of the wires of the assignment in the iden3 `.wtns` binary format.
+    pub fn to_wtns_bytes(&self) -> Result<Vec<u8>> {
+        let num_wires = u32::try_from(self.num_wires())?;
+
+        // Write the header section.
+        let mut header = Vec::new();
+        (field_size_in_bytes::<F>() as u32).write_le(&mut header)?;
+        write_field_modulus::<F>(&mut header)?;
+        num_wires.write_le(&mut header)?;
+
+        // Write the witness section, in wire order.
+        let mut witness = Vec::with_capacity(num_wires as usize * field_size_in_bytes::<F>());
+        for (_, value) in self.public_inputs().iter().chain(self.private_inputs().iter()) {
+            value.to_bigint().write_le(&mut witness)?;
+        }
+
+        let mut bytes = Vec::new();
+        write_file_header(&mut bytes, WTNS_MAGIC, WTNS_VERSION, 2)?;
+        write_section(&mut bytes, WTNS_HEADER_SECTION, &header)?;
+        write_section(&mut bytes, WTNS_WITNESS_SECTION, &witness)?;
+        Ok(bytes)
+    }
+
+    /// Returns the terms of the given linear combination as `(wire, coefficient)` pairs,
+    /// where the constant term is assigned to wire `0`, and the terms on the same wire are merged.
+    fn to_wire_terms(&self, lc: &AssignmentLC<F>) -> Result<Vec<(u32, F)>> {
+        let mut terms = IndexMap::<u32, F>::new();
+        if !lc.constant().is_zero() {
+            terms.insert(0, lc.constant());
+        }
+        for (variable, coefficient) in lc.terms() {
+            let wire = match variable {
+                AssignmentVariable::Constant(_) => {
+                    bail!("The assignment contains a constant variable in a linear combination")
+                }
+                AssignmentVariable::Public(index) => u32::try_from(*index)?,
+                AssignmentVariable::Private(index) => u32::try_from(self.num_public() + index)?,
+            };
+            *terms.entry(wire).or_insert_with(F::zero) += coefficient;
+        }
+        terms.retain(|_, coefficient| !coefficient.is_zero());
+        Ok(terms.into_iter().collect())
+    }
+}
+
+/// Returns the number of bytes used to encode a field element, as a multiple of 8.
+pub(super) fn field_size_in_bytes<F: PrimeField>() -> usize {
+    <F::BigInteger as BigInteger>::NUM_LIMBS * 8
+}
+
+/// Writes the modulus of the field in little-endian order.
+fn write_field_modulus<F: PrimeField>(writer: &mut Vec<u8>) -> Result<()> {
+    Ok(F::modulus().write_le(writer)?)
+}
+
+/// Writes the magic bytes, version, and number of sections of an iden3 binary file.
+fn write_file_header(writer: &mut Vec<u8>, magic: &[u8; 4], version: u32, num_sections: u32) -> Result<()> {
+    writer.write_all(magic)?;
+    version.write_le(&mut *writer)?;
+    Ok(num_sections.write_le(writer)?)
+}
+
+/// Writes a section of an iden3 binary file, prefixed by its type and size.
+fn write_section(writer: &mut Vec<u8>, section_type: u32, contents: &[u8]) -> Result<()> {
+    section_type.write_le(&mut *writer)?;
+    (contents.len() as u64).write_le(&mut *writer)?;
+    Ok(writer.write_all(contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Assignment, Circuit, Environment, Mode};
+    use snarkvm_fields::{One, PrimeField};
+    use snarkvm_utilities::FromBytes;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    /// Returns the assignment of `(a + 1) * a == b`, for a private `a` and a public `b`.
+    fn sample_assignment() -> Assignment<F> {
+        let a = Circuit::new_variable(Mode::Private, F::from(2u64));
+        let b = Circuit::new_variable(Mode::Public, F::from(6u64));
+        Circuit::enforce(|| (Circuit::one() + &a, &a, &b));
+        assert!(Circuit::is_satisfied());
+        Circuit::eject_assignment_and_reset()
+    }
+
+    /// Reads a little-endian `u32` at the given offset.
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// Reads a little-endian `u64` at the given offset.
+    fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_r1cs_bytes() {
+        let assignment = sample_assignment();
+        let bytes = assignment.to_r1cs_bytes().unwrap();
+
+        // Check the file header.
+        assert_eq!(&bytes[0..4], b"r1cs");
+        assert_eq!(read_u32(&bytes, 4), 1);
+        assert_eq!(read_u32(&bytes, 8), 3);
+
+        // Check the header section.
+        assert_eq!(read_u32(&bytes, 12), 1);
+        let header_size = read_u64(&bytes, 16) as usize;
+        let header = &bytes[24..24 + header_size];
+        assert_eq!(read_u32(header, 0), 32);
+        assert_eq!(&header[36..40], &(assignment.num_wires() as u32).to_le_bytes());
+        assert_eq!(read_u32(header, 40), 0);
+        assert_eq!(read_u32(header, 44), assignment.num_public() as u32 - 1);
+        assert_eq!(read_u32(header, 48), 0);
+        assert_eq!(read_u64(header, 52), assignment.num_wires());
+        assert_eq!(read_u32(header, 60), assignment.num_constraints() as u32);
+
+        // Check the constraints section holds the sole constraint.
+        let offset = 24 + header_size;
+        assert_eq!(read_u32(&bytes, offset), 2);
+        let constraints_size = read_u64(&bytes, offset + 4) as usize;
+        let constraints = &bytes[offset + 12..offset + 12 + constraints_size];
+        // A = 1 * w_0 + 1 * w_a, B = 1 * w_a, C = 1 * w_b.
+        assert_eq!(read_u32(constraints, 0), 2);
+        assert_eq!(constraints_size, 3 * 4 + 4 * (4 + 32));
+
+        // Check the wire-to-label section.
+        let offset = offset + 12 + constraints_size;
+        assert_eq!(read_u32(&bytes, offset), 3);
+        assert_eq!(read_u64(&bytes, offset + 4), 8 * assignment.num_wires());
+        assert_eq!(bytes.len(), offset + 12 + 8 * assignment.num_wires() as usize);
+    }
+
+    #[test]
+    fn test_r1cs_json() {
+        let assignment = sample_assignment();
+        let json = assignment.to_r1cs_json().unwrap();
+
+        assert_eq!(json["n8"], 32);
+        assert_eq!(json["prime"], F::modulus().to_string());
+        assert_eq!(json["nVars"], 3);
+        assert_eq!(json["nPubInputs"], 1);
+        assert_eq!(json["nConstraints"], 1);
+        // The public variable `b` is wire 1, and the private variable `a` is wire 2.
+        assert_eq!(json["constraints"][0][0], serde_json::json!({ "0": "1", "2": "1" }));
+        assert_eq!(json["constraints"][0][1], serde_json::json!({ "2": "1" }));
+        assert_eq!(json["constraints"][0][2], serde_json::json!({ "1": "1" }));
+    }
+
+    #[test]
+    fn test_wtns_bytes() {
+        let assignment = sample_assignment();
+        let bytes = assignment.to_wtns_bytes().unwrap();
+
+        // Check the file header.
+        assert_eq!(&bytes[0..4], b"wtns");
+        assert_eq!(read_u32(&bytes, 4), 2);
+        assert_eq!(read_u32(&bytes, 8), 2);
+
+        // Check the header section.
+        assert_eq!(read_u32(&bytes, 12), 1);
+        assert_eq!(read_u64(&bytes, 16), 40);
+        assert_eq!(read_u32(&bytes, 24), 32);
+        assert_eq!(read_u32(&bytes, 60), 3);
+
+        // Check the witness section holds `[1, b, a]`.
+        assert_eq!(read_u32(&bytes, 64), 2);
+        assert_eq!(read_u64(&bytes, 68), 3 * 32);
+        let witness = &bytes[76..];
+        let expected = [F::one(), F::from(6u64), F::from(2u64)];
+        for (chunk, expected) in witness.chunks(32).zip(expected) {
+            assert_eq!(F::from_bigint(FromBytes::read_le(chunk).unwrap()), Some(expected));
+        }
+    }
+}
@@ -23,6 +23,8 @@ pub use constraint::*;
 
 pub(super) mod converter;
 
+mod export;
+
 pub mod count;
 pub use count::*;
 
@@ -35,6 +37,9 @@ pub use linear_combination::*;
 mod mode;
 pub use mode::*;
 
+mod r1cs_file;
+pub use r1cs_file::*;
+
 pub mod variable;
 pub use variable::*;
 
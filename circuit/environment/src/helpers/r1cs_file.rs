@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::export::{
+    R1CS_CONSTRAINTS_SECTION,
+    R1CS_HEADER_SECTION,
+    R1CS_MAGIC,
+    R1CS_VERSION,
+    WTNS_HEADER_SECTION,
+    WTNS_MAGIC,
+    WTNS_VERSION,
+    WTNS_WITNESS_SECTION,
+    field_size_in_bytes,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::FromBytes;
+
+use console::prelude::{Result, bail, ensure};
+use indexmap::IndexMap;
+
+/// A linear combination of an external R1CS, as `(wire, coefficient)` pairs.
+pub type R1CSFileLC<F> = Vec<(u32, F)>;
+
+/// An R1CS in the iden3 `.r1cs` format, as produced by circom.
+///
+/// The wires are laid out as `[1, public outputs, public inputs, private inputs, internal wires]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct R1CSFile<F: PrimeField> {
+    /// The number of wires, including the constant wire.
+    num_wires: u32,
+    /// The number of public outputs.
+    num_public_outputs: u32,
+    /// The number of public inputs.
+    num_public_inputs: u32,
+    /// The number of private inputs.
+    num_private_inputs: u32,
+    /// The constraints, as `(A, B, C)` linear combinations for which `A * B = C`.
+    constraints: Vec<(R1CSFileLC<F>, R1CSFileLC<F>, R1CSFileLC<F>)>,
+}
+
+impl<F: PrimeField> R1CSFile<F> {
+    /// Initializes a new R1CS, ensuring every constraint refers to an existing wire.
+    pub fn new(
+        num_wires: u32,
+        num_public_outputs: u32,
+        num_public_inputs: u32,
+        num_private_inputs: u32,
+        constraints: Vec<(R1CSFileLC<F>, R1CSFileLC<F>, R1CSFileLC<F>)>,
+    ) -> Result<Self> {
+        // Ensure the wires fit the constant wire, outputs, and inputs.
+        let num_io_wires = 1u64 + num_public_outputs as u64 + num_public_inputs as u64 + num_private_inputs as u64;
+        ensure!(num_io_wires <= num_wires as u64, "The R1CS has fewer wires than inputs and outputs");
+        // Ensure every term refers to an existing wire.
+        for (a, b, c) in &constraints {
+            for (wire, _) in a.iter().chain(b).chain(c) {
+                ensure!(*wire < num_wires, "The R1CS refers to wire {wire}, but only has {num_wires} wires");
+            }
+        }
+        Ok(Self { num_wires, num_public_outputs, num_public_inputs, num_private_inputs, constraints })
+    }
+
+    /// Reads an R1CS from the iden3 `.r1cs` binary format.
+    pub fn from_r1cs_bytes(bytes: &[u8]) -> Result<Self> {
+        let sections = read_sections(bytes, R1CS_MAGIC, R1CS_VERSION)?;
+
+        // Read the header section.
+        let Some(mut header) = sections.get(&R1CS_HEADER_SECTION).copied() else {
+            bail!("The R1CS is missing its header section")
+        };
+        read_field_header::<F>(&mut header)?;
+        let num_wires = u32::read_le(&mut header)?;
+        let num_public_outputs = u32::read_le(&mut header)?;
+        let num_public_inputs = u32::read_le(&mut header)?;
+        let num_private_inputs = u32::read_le(&mut header)?;
+        // The labels are not needed to synthesize the R1CS.
+        let _num_labels = u64::read_le(&mut header)?;
+        let num_constraints = u32::read_le(&mut header)?;
+
+        // Read the constraints section.
+        let Some(mut reader) = sections.get(&R1CS_CONSTRAINTS_SECTION).copied() else {
+            bail!("The R1CS is missing its constraints section")
+        };
+        let read_lc = |reader: &mut &[u8]| -> Result<R1CSFileLC<F>> {
+            let num_terms = u32::read_le(&mut *reader)?;
+            (0..num_terms).map(|_| Ok((u32::read_le(&mut *reader)?, read_field(&mut *reader)?))).collect()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| Ok((read_lc(&mut reader)?, read_lc(&mut reader)?, read_lc(&mut reader)?)))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(reader.is_empty(), "The R1CS constraints section has trailing bytes");
+
+        Self::new(num_wires, num_public_outputs, num_public_inputs, num_private_inputs, constraints)
+    }
+
+    /// Reads a witness for this R1CS from the iden3 `.wtns` binary format.
+    pub fn witness_from_wtns_bytes(&self, bytes: &[u8]) -> Result<Vec<F>> {
+        let sections = read_sections(bytes, WTNS_MAGIC, WTNS_VERSION)?;
+
+        // Read the header section.
+        let Some(mut header) = sections.get(&WTNS_HEADER_SECTION).copied() else {
+            bail!("The witness is missing its header section")
+        };
+        read_field_header::<F>(&mut header)?;
+        let num_wires = u32::read_le(&mut header)?;
+        ensure!(num_wires == self.num_wires, "Expected a witness for {} wires, found {num_wires}", self.num_wires);
+
+        // Read the witness section.
+        let Some(mut reader) = sections.get(&WTNS_WITNESS_SECTION).copied() else {
+            bail!("The witness is missing its witness section")
+        };
+        let witness = (0..num_wires).map(|_| read_field(&mut reader)).collect::<Result<Vec<_>>>()?;
+        ensure!(reader.is_empty(), "The witness section has trailing bytes");
+        Ok(witness)
+    }
+
+    /// Returns the number of wires, including the constant wire.
+    pub const fn num_wires(&self) -> u32 {
+        self.num_wires
+    }
+
+    /// Returns the number of public outputs.
+    pub const fn num_public_outputs(&self) -> u32 {
+        self.num_public_outputs
+    }
+
+    /// Returns the number of public inputs.
+    pub const fn num_public_inputs(&self) -> u32 {
+        self.num_public_inputs
+    }
+
+    /// Returns the number of private inputs.
+    pub const fn num_private_inputs(&self) -> u32 {
+        self.num_private_inputs
+    }
+
+    /// Returns the constraints.
+    pub fn constraints(&self) -> &[(R1CSFileLC<F>, R1CSFileLC<F>, R1CSFileLC<F>)] {
+        &self.constraints
+    }
+
+    /// Returns `true` if the given wire values satisfy every constraint.
+    pub fn is_satisfied(&self, witness: &[F]) -> bool {
+        let evaluate =
+            |lc: &R1CSFileLC<F>| lc.iter().map(|(wire, coefficient)| witness[*wire as usize] * coefficient).sum::<F>();
+        witness.len() == self.num_wires as usize
+            && witness[0].is_one()
+            && self.constraints.iter().all(|(a, b, c)| evaluate(a) * evaluate(b) == evaluate(c))
+    }
+}
+
+/// Returns the sections of an iden3 binary file, keyed by their type.
+fn read_sections<'a>(mut bytes: &'a [u8], magic: &[u8; 4], version: u32) -> Result<IndexMap<u32, &'a [u8]>> {
+    ensure!(bytes.len() >= 12 && &bytes[..4] == magic, "Expected a file starting with '{}'", magic.escape_ascii());
+    bytes = &bytes[4..];
+    let found_version = u32::read_le(&mut bytes)?;
+    ensure!(found_version == version, "Expected version {version} of the format, found version {found_version}");
+    let num_sections = u32::read_le(&mut bytes)?;
+
+    let mut sections = IndexMap::new();
+    for _ in 0..num_sections {
+        let section_type = u32::read_le(&mut bytes)?;
+        let size = usize::try_from(u64::read_le(&mut bytes)?)?;
+        ensure!(size <= bytes.len(), "Section {section_type} exceeds the length of the file");
+        let (contents, remaining) = bytes.split_at(size);
+        ensure!(sections.insert(section_type, contents).is_none(), "Found a duplicate section {section_type}");
+        bytes = remaining;
+    }
+    ensure!(bytes.is_empty(), "The file has trailing bytes");
+    Ok(sections)
+}
+
+/// Reads the field size and modulus at the start of a header section, ensuring they match the field.
+fn read_field_header<F: PrimeField>(reader: &mut &[u8]) -> Result<()> {
+    let field_size = u32::read_le(&mut *reader)? as usize;
+    ensure!(field_size == field_size_in_bytes::<F>(), "Expected {}-byte field elements", field_size_in_bytes::<F>());
+    let modulus = F::BigInteger::read_le(&mut *reader)?;
+    ensure!(modulus == F::modulus(), "The file is defined over a different prime field");
+    Ok(())
+}
+
+/// Reads a field element in little-endian order, ensuring it is less than the modulus.
+fn read_field<F: PrimeField>(reader: &mut &[u8]) -> Result<F> {
+    match F::from_bigint(F::BigInteger::read_le(&mut *reader)?) {
+        Some(field) => Ok(field),
+        None => bail!("Found a field element that is not less than the modulus"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Environment, Mode};
+    use snarkvm_fields::One;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    #[test]
+    fn test_r1cs_bytes_round_trip() {
+        // Synthesize `(a + 1) * a == b`, for a private `a` and a public `b`.
+        let a = Circuit::new_variable(Mode::Private, F::from(2u64));
+        let b = Circuit::new_variable(Mode::Public, F::from(6u64));
+        Circuit::enforce(|| (Circuit::one() + &a, &a, &b));
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        // Read the exported R1CS.
+        let r1cs = R1CSFile::<F>::from_r1cs_bytes(&assignment.to_r1cs_bytes().unwrap()).unwrap();
+        assert_eq!(r1cs.num_wires(), 3);
+        assert_eq!(r1cs.num_public_outputs(), 0);
+        assert_eq!(r1cs.num_public_inputs(), 1);
+        assert_eq!(r1cs.num_private_inputs(), 0);
+        assert_eq!(r1cs.constraints(), &[(vec![(0, F::one()), (2, F::one())], vec![(2, F::one())], vec![(
+            1,
+            F::one()
+        )])]);
+
+        // Read the exported witness, and ensure it satisfies the R1CS.
+        let witness = r1cs.witness_from_wtns_bytes(&assignment.to_wtns_bytes().unwrap()).unwrap();
+        assert_eq!(witness, vec![F::one(), F::from(6u64), F::from(2u64)]);
+        assert!(r1cs.is_satisfied(&witness));
+        assert!(!r1cs.is_satisfied(&[F::one(), F::from(7u64), F::from(2u64)]));
+    }
+
+    #[test]
+    fn test_r1cs_rejects_invalid_files() {
+        let r1cs = R1CSFile::<F>::new(3, 1, 1, 0, vec![]).unwrap();
+
+        // Ensure a wire out of bounds is rejected.
+        assert!(R1CSFile::<F>::new(3, 1, 1, 0, vec![(vec![(3, F::one())], vec![], vec![])]).is_err());
+        // Ensure the inputs and outputs must fit in the wires.
+        assert!(R1CSFile::<F>::new(2, 1, 1, 0, vec![]).is_err());
+        // Ensure the magic bytes are checked.
+        assert!(R1CSFile::<F>::from_r1cs_bytes(b"wtns\x01\x00\x00\x00\x00\x00\x00\x00").is_err());
+        assert!(r1cs.witness_from_wtns_bytes(b"r1cs\x02\x00\x00\x00\x00\x00\x00\x00").is_err());
+        // Ensure missing sections are rejected.
+        assert!(R1CSFile::<F>::from_r1cs_bytes(b"r1cs\x01\x00\x00\x00\x00\x00\x00\x00").is_err());
+    }
+}
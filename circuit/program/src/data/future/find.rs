@@ -55,6 +55,14 @@ impl<A: Aleo> Future<A> {
                         None => bail!("Index '{index}' is out of bounds"),
                     }
                 }
+                (ArgumentRefType::Plaintext(Plaintext::Enum(variant, payload, ..)), Access::Member(identifier)) => {
+                    match variant == identifier {
+                        // Retrieve the payload and update `value` for the next iteration.
+                        true => value = ArgumentRefType::Plaintext(payload),
+                        // Halts if the enum is not of the given variant.
+                        false => bail!("Failed to locate variant '{identifier}'"),
+                    }
+                }
                 (ArgumentRefType::Future(future), Access::Index(index)) => {
                     let index = match index.eject_mode() {
                         Mode::Constant => index.eject_value(),
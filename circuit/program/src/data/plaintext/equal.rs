@@ -43,7 +43,24 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(false),
+            (Self::Enum(variant_a, a, _), Self::Enum(variant_b, b, _)) => variant_a.is_equal(variant_b) & a.is_equal(b),
+            (Self::Vector(a, length_a, _), Self::Vector(b, length_b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each element within the length for equality.
+                    let mut equal = length_a.is_equal(length_b);
+                    for (i, (plaintext_a, plaintext_b)) in a.iter().zip_eq(b.iter()).enumerate() {
+                        let is_padding = U32::constant(console::U32::new(i as u32)).is_greater_than_or_equal(length_a);
+                        equal &= is_padding | plaintext_a.is_equal(plaintext_b);
+                    }
+                    equal
+                }
+                false => Boolean::constant(false),
+            },
+            (Self::Literal(..), _)
+            | (Self::Struct(..), _)
+            | (Self::Array(..), _)
+            | (Self::Enum(..), _)
+            | (Self::Vector(..), _) => Boolean::constant(false),
         }
     }
 
@@ -73,7 +90,26 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(true),
+            (Self::Enum(variant_a, a, _), Self::Enum(variant_b, b, _)) => {
+                variant_a.is_not_equal(variant_b) | a.is_not_equal(b)
+            }
+            (Self::Vector(a, length_a, _), Self::Vector(b, length_b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each element within the length for inequality.
+                    let mut not_equal = length_a.is_not_equal(length_b);
+                    for (i, (plaintext_a, plaintext_b)) in a.iter().zip_eq(b.iter()).enumerate() {
+                        let is_element = U32::constant(console::U32::new(i as u32)).is_less_than(length_a);
+                        not_equal |= is_element & plaintext_a.is_not_equal(plaintext_b);
+                    }
+                    not_equal
+                }
+                false => Boolean::constant(true),
+            },
+            (Self::Literal(..), _)
+            | (Self::Struct(..), _)
+            | (Self::Array(..), _)
+            | (Self::Enum(..), _)
+            | (Self::Vector(..), _) => Boolean::constant(true),
         }
     }
 }
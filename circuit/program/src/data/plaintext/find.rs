@@ -23,10 +23,10 @@ impl<A: Aleo> Plaintext<A> {
         }
 
         match self {
-            // Halts if the value is not a struct or an array.
-            Self::Literal(..) => A::halt("A literal is not a struct or an array"),
+            // Halts if the value is not a struct, an array, or an enum.
+            Self::Literal(..) => A::halt("A literal is not a struct, an array, or an enum"),
             // Retrieve the value of the member (from the value).
-            Self::Struct(..) | Self::Array(..) => {
+            Self::Struct(..) | Self::Array(..) | Self::Enum(..) | Self::Vector(..) => {
                 // Initialize the plaintext starting from the top-level.
                 let mut plaintext = self;
 
@@ -54,6 +54,15 @@ impl<A: Aleo> Plaintext<A> {
                                 None => bail!("Failed to locate element '{index}'"),
                             }
                         }
+                        (Self::Enum(variant, payload, ..), Access::Member(identifier)) => {
+                            // Note: The variant is a constant, so this does not depend on the private inputs.
+                            match variant == identifier {
+                                // Retrieve the payload and update `plaintext` for the next iteration.
+                                true => plaintext = payload,
+                                // Halts if the enum is not of the given variant.
+                                false => bail!("Failed to locate variant '{identifier}'"),
+                            }
+                        }
                         _ => bail!("Invalid access `{access}``"),
                     }
                 }
@@ -86,6 +86,33 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_le.to_vec()))
         }
+        // Enum (with a trailing `false` variant bit)
+        else if variant == [true, true] && !next_bits(1)[0].eject_value() {
+            let identifier_size = U8::from_bits_le(next_bits(8)).eject_value();
+            let variant = Identifier::from_bits_le(next_bits(*identifier_size as usize));
+
+            let payload_size = U16::from_bits_le(next_bits(16)).eject_value();
+            let payload = Plaintext::from_bits_le(next_bits(*payload_size as usize));
+
+            // Cache the plaintext bits, and return the enum.
+            Self::Enum(variant, Box::new(payload), OnceCell::with_value(bits_le.to_vec()))
+        }
+        // Vector (with a trailing `true` variant bit)
+        else if variant == [true, true] {
+            let length = U32::from_bits_le(next_bits(32));
+            let num_elements = U32::from_bits_le(next_bits(32)).eject_value();
+
+            let mut elements = Vec::with_capacity(*num_elements as usize);
+            for _ in 0..*num_elements {
+                let element_size = U16::from_bits_le(next_bits(16)).eject_value();
+                let value = Plaintext::from_bits_le(next_bits(*element_size as usize));
+
+                elements.push(value);
+            }
+
+            // Cache the plaintext bits, and return the vector.
+            Self::Vector(elements, length, OnceCell::with_value(bits_le.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
@@ -161,6 +188,33 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_be.to_vec()))
         }
+        // Enum (with a trailing `false` variant bit)
+        else if variant == [true, true] && !next_bits(1)[0].eject_value() {
+            let identifier_size = U8::from_bits_be(next_bits(8)).eject_value();
+            let variant = Identifier::from_bits_be(next_bits(*identifier_size as usize));
+
+            let payload_size = U16::from_bits_be(next_bits(16)).eject_value();
+            let payload = Plaintext::from_bits_be(next_bits(*payload_size as usize));
+
+            // Cache the plaintext bits, and return the enum.
+            Self::Enum(variant, Box::new(payload), OnceCell::with_value(bits_be.to_vec()))
+        }
+        // Vector (with a trailing `true` variant bit)
+        else if variant == [true, true] {
+            let length = U32::from_bits_be(next_bits(32));
+            let num_elements = U32::from_bits_be(next_bits(32)).eject_value();
+
+            let mut elements = Vec::with_capacity(*num_elements as usize);
+            for _ in 0..*num_elements {
+                let element_size = U16::from_bits_be(next_bits(16)).eject_value();
+                let value = Plaintext::from_bits_be(next_bits(*element_size as usize));
+
+                elements.push(value);
+            }
+
+            // Cache the plaintext bits, and return the vector.
+            Self::Vector(elements, length, OnceCell::with_value(bits_be.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
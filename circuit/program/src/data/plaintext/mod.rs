@@ -24,6 +24,7 @@ mod num_randomizers;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
+mod zero;
 
 use crate::{Access, Ciphertext, Identifier, Literal, Visibility};
 use snarkvm_circuit_network::Aleo;
@@ -37,6 +38,11 @@ pub enum Plaintext<A: Aleo> {
     Struct(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext array.
     Array(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext enum variant, with its payload.
+    /// Note: The variant is always a constant, see `EnumType` for the resulting restrictions.
+    Enum(Identifier<A>, Box<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext vector, with its elements padded to the maximum length, and its length.
+    Vector(Vec<Plaintext<A>>, U32<A>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -49,6 +55,12 @@ impl<A: Aleo> Inject for Plaintext<A> {
             Self::Primitive::Literal(literal, _) => Self::Literal(Literal::new(mode, literal), Default::default()),
             Self::Primitive::Struct(struct_, _) => Self::Struct(Inject::new(mode, struct_), Default::default()),
             Self::Primitive::Array(array, _) => Self::Array(Inject::new(mode, array), Default::default()),
+            Self::Primitive::Enum(variant, payload, _) => {
+                Self::Enum(Identifier::new(mode, variant), Box::new(Plaintext::new(mode, *payload)), Default::default())
+            }
+            Self::Primitive::Vector(elements, length, _) => {
+                Self::Vector(Inject::new(mode, elements), U32::new(mode, length), Default::default())
+            }
         }
     }
 }
@@ -67,6 +79,8 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .collect::<Vec<_>>()
                 .eject_mode(),
             Self::Array(array, _) => array.iter().map(Eject::eject_mode).collect::<Vec<_>>().eject_mode(),
+            Self::Enum(variant, payload, _) => (variant, &**payload).eject_mode(),
+            Self::Vector(elements, length, _) => (elements, length).eject_mode(),
         }
     }
 
@@ -80,6 +94,14 @@ impl<A: Aleo> Eject for Plaintext<A> {
             Self::Array(array, _) => {
                 console::Plaintext::Array(array.iter().map(Eject::eject_value).collect(), Default::default())
             }
+            Self::Enum(variant, payload, _) => {
+                console::Plaintext::Enum(variant.eject_value(), Box::new(payload.eject_value()), Default::default())
+            }
+            Self::Vector(elements, length, _) => console::Plaintext::Vector(
+                elements.iter().map(Eject::eject_value).collect(),
+                length.eject_value(),
+                Default::default(),
+            ),
         }
     }
 }
@@ -368,6 +390,35 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test an enum with a literal payload.
+        run_test(Plaintext::<Circuit>::Enum(
+            Identifier::new(Mode::Private, "circle".try_into()?),
+            Box::new(Plaintext::<Circuit>::Literal(
+                Literal::Field(Field::new(Mode::Private, Uniform::rand(&mut rng))),
+                OnceCell::new(),
+            )),
+            OnceCell::new(),
+        ));
+
+        // Test an enum with a struct payload.
+        run_test(Plaintext::<Circuit>::Enum(
+            Identifier::new(Mode::Private, "square".try_into()?),
+            Box::new(Plaintext::<Circuit>::Struct(
+                IndexMap::from_iter(vec![(
+                    Identifier::new(Mode::Private, "width".try_into()?),
+                    Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, true)), OnceCell::new()),
+                )]),
+                OnceCell::new(),
+            )),
+            OnceCell::new(),
+        ));
+
+        // Test a vector of literals.
+        run_test(Plaintext::<Circuit>::new(
+            Mode::Private,
+            console::Plaintext::from_str("vector[1u8, 2u8, 0u8, 0u8; 2u32]")?,
+        ));
+
         Ok(())
     }
 }
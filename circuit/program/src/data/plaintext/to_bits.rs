@@ -64,6 +64,38 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits);
             }
+            Self::Enum(variant, payload, bits_le) => {
+                // Compute the bits of the enum.
+                let bits = bits_le.get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(false)];
+                    let payload_bits = payload.to_bits_le();
+                    variant.size_in_bits().write_bits_le(&mut bits_le);
+                    variant.write_bits_le(&mut bits_le);
+                    U16::constant(console::U16::new(payload_bits.len() as u16)).write_bits_le(&mut bits_le);
+                    bits_le.extend(payload_bits);
+                    bits_le
+                });
+                // Extend the vector with the bits of the enum.
+                vec.extend_from_slice(bits);
+            }
+            Self::Vector(elements, length, bits_le) => {
+                // Compute the bits of the vector.
+                let bits = bits_le.get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(true)];
+                    length.write_bits_le(&mut bits_le);
+                    U32::constant(console::U32::new(elements.len() as u32)).write_bits_le(&mut bits_le);
+                    for value in elements {
+                        let value_bits = value.to_bits_le();
+                        U16::constant(console::U16::new(value_bits.len() as u16)).write_bits_le(&mut bits_le);
+                        bits_le.extend(value_bits);
+                    }
+                    bits_le
+                });
+                // Extend the vector with the bits of the vector.
+                vec.extend_from_slice(bits);
+            }
         }
     }
 
@@ -114,6 +146,38 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits)
             }
+            Self::Enum(variant, payload, bits_be) => {
+                // Compute the bits of the enum.
+                let bits = bits_be.get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(false)];
+                    let payload_bits = payload.to_bits_be();
+                    variant.size_in_bits().write_bits_be(&mut bits_be);
+                    variant.write_bits_be(&mut bits_be);
+                    U16::constant(console::U16::new(payload_bits.len() as u16)).write_bits_be(&mut bits_be);
+                    bits_be.extend(payload_bits);
+                    bits_be
+                });
+                // Extend the vector with the bits of the enum.
+                vec.extend_from_slice(bits)
+            }
+            Self::Vector(elements, length, bits_be) => {
+                // Compute the bits of the vector.
+                let bits = bits_be.get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(true)];
+                    length.write_bits_be(&mut bits_be);
+                    U32::constant(console::U32::new(elements.len() as u32)).write_bits_be(&mut bits_be);
+                    for value in elements {
+                        let value_bits = value.to_bits_be();
+                        U16::constant(console::U16::new(value_bits.len() as u16)).write_bits_be(&mut bits_be);
+                        bits_be.extend(value_bits);
+                    }
+                    bits_be
+                });
+                // Extend the vector with the bits of the vector.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
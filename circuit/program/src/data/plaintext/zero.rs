@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns `true` if the plaintext is zero, i.e. every literal in it is zero.
+    pub fn is_zero(&self) -> Boolean<A> {
+        match self {
+            Self::Literal(Literal::String(string), ..) => Boolean::constant(string.eject_value().is_empty()),
+            Self::Literal(literal, ..) => {
+                literal.to_bits_le().iter().fold(Boolean::constant(true), |is_zero, bit| is_zero & !bit)
+            }
+            Self::Struct(members, ..) => {
+                members.values().fold(Boolean::constant(true), |is_zero, member| is_zero & member.is_zero())
+            }
+            Self::Array(elements, ..) => {
+                elements.iter().fold(Boolean::constant(true), |is_zero, element| is_zero & element.is_zero())
+            }
+            Self::Enum(..) | Self::Vector(..) => Boolean::constant(false),
+        }
+    }
+
+    /// Returns `true` if every element of a vector beyond its length is zero.
+    /// Note: Vectors must be zero-padded, so that their encodings are canonical.
+    pub fn is_zero_padded(elements: &[Self], length: &U32<A>) -> Boolean<A> {
+        elements.iter().enumerate().fold(Boolean::constant(true), |is_zero_padded, (i, element)| {
+            let is_element = U32::constant(console::U32::new(i as u32)).is_less_than(length);
+            is_zero_padded & (is_element | element.is_zero())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_is_zero_padded() -> Result<()> {
+        let vector = console::Plaintext::<<Circuit as Environment>::Network>::from_str("vector[1u8, 2u8, 0u8; 2u32]")?;
+        let console::Plaintext::Vector(mut elements, length, _) = vector else { unreachable!() };
+
+        // Ensure zero padding is accepted.
+        let candidate = Plaintext::<Circuit>::new(
+            Mode::Private,
+            console::Plaintext::Vector(elements.clone(), length, Default::default()),
+        );
+        let Plaintext::Vector(elements_a, length_a, _) = candidate else { unreachable!() };
+        assert!(Plaintext::is_zero_padded(&elements_a, &length_a).eject_value());
+
+        // Ensure nonzero padding is rejected.
+        elements[2] = console::Plaintext::from_str("3u8")?;
+        let candidate =
+            Plaintext::<Circuit>::new(Mode::Private, console::Plaintext::Vector(elements, length, Default::default()));
+        let Plaintext::Vector(elements_b, length_b, _) = candidate else { unreachable!() };
+        assert!(!Plaintext::is_zero_padded(&elements_b, &length_b).eject_value());
+        Ok(())
+    }
+}
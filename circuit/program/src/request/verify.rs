@@ -15,11 +15,14 @@
 use super::*;
 
 impl<A: Aleo> Request<A> {
-    /// Returns `true` if the input IDs are derived correctly, the input records all belong to the signer,
-    /// and the signature is valid.
+    /// Returns `true` if the input IDs are derived correctly, the input records all belong to the signer
+    /// (or are held in custody by a program), and the signature is valid.
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///
+    /// Note: An input record held in custody has a public `gamma` (i.e. `H`) and tag (`Hash(owner || commitment)`),
+    /// so its serial number and tag are linkable to its commitment, unlike those of a record owned by the signer.
     pub fn verify(
         &self,
         input_types: &[console::ValueType<A::Network>],
@@ -118,6 +121,38 @@ impl<A: Aleo> Request<A> {
         tvk: &Field<A>,
         tcm: &Field<A>,
         signature: Option<&Signature<A>>,
+    ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
+        // Compute the function ID.
+        let function_id = compute_function_id(network_id, program_id, function_name);
+        // Check the input IDs for the function ID.
+        Self::check_input_ids_with_function_id::<CREATE_MESSAGE>(
+            &function_id,
+            Some(program_id),
+            input_ids,
+            inputs,
+            input_types,
+            signer,
+            sk_tag,
+            tvk,
+            tcm,
+            signature,
+        )
+    }
+
+    /// Returns `true` if the inputs match their input IDs, for the given function ID.
+    /// The program ID is only required for record inputs, as it is used to compute the record commitment.
+    /// Note: This method does **not** perform signature checks.
+    pub fn check_input_ids_with_function_id<const CREATE_MESSAGE: bool>(
+        function_id: &Field<A>,
+        program_id: Option<&ProgramID<A>>,
+        input_ids: &[InputID<A>],
+        inputs: &[Value<A>],
+        input_types: &[console::ValueType<A::Network>],
+        signer: &Address<A>,
+        sk_tag: &Field<A>,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        signature: Option<&Signature<A>>,
     ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
         // Ensure the signature response matches the `CREATE_MESSAGE` flag.
         match CREATE_MESSAGE {
@@ -125,9 +160,6 @@ impl<A: Aleo> Request<A> {
             false => assert!(signature.is_none()),
         }
 
-        // Compute the function ID.
-        let function_id = compute_function_id(network_id, program_id, function_name);
-
         // Initialize a vector for a message.
         let mut message = Vec::new();
 
@@ -224,14 +256,27 @@ impl<A: Aleo> Request<A> {
                             // Ensure the input is a record.
                             _ => A::halt(format!("Expected a record input at input {index}")),
                         };
+                        // Retrieve the program ID.
+                        let program_id = match program_id {
+                            Some(program_id) => program_id,
+                            None => A::halt(format!("Expected a program ID for the record input at input {index}")),
+                        };
                         // Compute the record commitment.
                         let candidate_commitment = record.to_commitment(program_id, &record_name);
+                        // Determine if the record belongs to the signer.
+                        // Note: A record that does not belong to the signer is held in custody by its owner program,
+                        // and the stack ensures it is only consumed in a call from the owner program.
+                        let is_signer_owned = record.owner().deref().is_equal(signer);
+                        // Compute the generator `H` as `HashToGroup(commitment)`.
+                        let h = A::hash_to_group_psd2(&[A::serial_number_domain(), candidate_commitment.clone()]);
                         // Compute the `candidate_serial_number` from `gamma`.
                         let candidate_serial_number =
                             Record::<A, Plaintext<A>>::serial_number_from_gamma(gamma, candidate_commitment.clone());
-                        // Compute the tag.
-                        let candidate_tag =
-                            Record::<A, Plaintext<A>>::tag(sk_tag.clone(), candidate_commitment.clone());
+                        // Compute the tag, as `Hash(sk_tag || commitment)` if the record belongs to the signer,
+                        // and as `Hash(owner || commitment)` if the record is held in custody.
+                        let owner_x = record.owner().deref().to_group().to_x_coordinate();
+                        let tag_key = Field::ternary(&is_signer_owned, sk_tag, &owner_x);
+                        let candidate_tag = Record::<A, Plaintext<A>>::tag(tag_key, candidate_commitment.clone());
 
                         if CREATE_MESSAGE {
                             // Ensure the signature is declared.
@@ -244,13 +289,14 @@ impl<A: Aleo> Request<A> {
                             // Retrieve the response from the signature.
                             let response = signature.response();
 
-                            // Compute the generator `H` as `HashToGroup(commitment)`.
-                            let h = A::hash_to_group_psd2(&[A::serial_number_domain(), candidate_commitment.clone()]);
                             // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
-                            let h_r = (gamma.deref() * challenge) + (&h * response);
+                            // Note: For a record held in custody, `h_r` is `H`.
+                            let h_r =
+                                Group::ternary(&is_signer_owned, &((gamma.deref() * challenge) + (&h * response)), &h);
 
                             // Add (`H`, `r * H`, `gamma`, `tag`) to the message.
-                            message.extend([h, h_r, *gamma.clone()].iter().map(|point| point.to_x_coordinate()));
+                            message
+                                .extend([h.clone(), h_r, *gamma.clone()].iter().map(|point| point.to_x_coordinate()));
                             message.push(candidate_tag.clone());
                         }
 
@@ -260,8 +306,8 @@ impl<A: Aleo> Request<A> {
                             & commitment.is_equal(&candidate_commitment)
                             // Ensure the candidate tag matches the expected tag.
                             & tag.is_equal(&candidate_tag)
-                            // Ensure the record belongs to the signer.
-                            & record.owner().deref().is_equal(signer)
+                            // Ensure the record belongs to the signer, or `gamma` is `H` for a record held in custody.
+                            & (is_signer_owned | gamma.deref().is_equal(&h))
                     }
                     // An external record input is hashed (using `tvk`) to a field element.
                     InputID::ExternalRecord(input_hash) => {
@@ -428,11 +474,11 @@ mod tests {
 
     #[test]
     fn test_sign_and_verify_public() -> Result<()> {
-        check_verify(Mode::Public, 40131, 0, 26675, 26702)
+        check_verify(Mode::Public, 40131, 0, 26684, 26711)
     }
 
     #[test]
     fn test_sign_and_verify_private() -> Result<()> {
-        check_verify(Mode::Private, 40131, 0, 26675, 26702)
+        check_verify(Mode::Private, 40131, 0, 26684, 26711)
     }
 }
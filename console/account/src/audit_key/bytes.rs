@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for AuditKey<N> {
+    /// Reads an account audit key from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let address = Address::read_le(&mut reader)?;
+        let nonce = Group::read_le(&mut reader)?;
+        let record_view_point = Group::read_le(&mut reader)?;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { address, nonce, record_view_point, challenge, response })
+    }
+}
+
+impl<N: Network> ToBytes for AuditKey<N> {
+    /// Writes an account audit key to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.address.write_le(&mut writer)?;
+        self.nonce.write_le(&mut writer)?;
+        self.record_view_point.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new audit key.
+            let expected = test_helpers::sample_audit_key(&mut rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, AuditKey::read_le(&expected_bytes[..])?);
+            assert!(AuditKey::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
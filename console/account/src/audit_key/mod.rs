@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+mod verify;
+
+use crate::{address::Address, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// An account audit key, which discloses the record view key of a single record to an auditor.
+///
+/// The audit key is scoped to the record with the given nonce, and carries a proof that the
+/// record view point `view_key * nonce` is derived from the same view key as the account address,
+/// i.e. `log_G(address) == log_nonce(record_view_point)`, without revealing the view key.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AuditKey<N: Network> {
+    /// The address of the account.
+    address: Address<N>,
+    /// The nonce of the record.
+    nonce: Group<N>,
+    /// The record view point, as `view_key * nonce`.
+    record_view_point: Group<N>,
+    /// The verifier challenge to check against.
+    challenge: Scalar<N>,
+    /// The prover response to the challenge.
+    response: Scalar<N>,
+}
+
+impl<N: Network> AuditKey<N> {
+    /// Returns an audit key for the record with the given nonce, where:
+    ///     challenge := HashToScalar(r * G, r * nonce, address, nonce, record_view_point)
+    ///     response := r - challenge * view_key
+    pub fn new<R: Rng + CryptoRng>(view_key: &ViewKey<N>, nonce: Group<N>, rng: &mut R) -> Result<Self> {
+        // Ensure the nonce is not the zero point.
+        ensure!(!nonce.is_zero(), "Cannot derive an audit key for a zero nonce");
+
+        // Derive the address from the view key.
+        let address = view_key.to_address();
+        // Compute the record view point.
+        let record_view_point = nonce * **view_key;
+
+        // Sample a random scalar.
+        let r = Scalar::rand(rng);
+        // Compute `g_r` as `r * G`.
+        let g_r = N::g_scalar_multiply(&r);
+        // Compute `h_r` as `r * nonce`.
+        let h_r = nonce * r;
+
+        // Compute the verifier challenge.
+        let challenge = Self::compute_challenge(g_r, h_r, address, nonce, record_view_point)?;
+        // Compute the prover response.
+        let response = r - (challenge * **view_key);
+
+        // Output the audit key.
+        Ok(Self { address, nonce, record_view_point, challenge, response })
+    }
+
+    /// Initializes an audit key from its parts.
+    /// Note: This method does not check the proof. Use `Self::verify` to check the audit key.
+    pub const fn from(
+        address: Address<N>,
+        nonce: Group<N>,
+        record_view_point: Group<N>,
+        challenge: Scalar<N>,
+        response: Scalar<N>,
+    ) -> Self {
+        Self { address, nonce, record_view_point, challenge, response }
+    }
+
+    /// Returns the verifier challenge as `HashToScalar(g_r, h_r, address, nonce, record_view_point)`.
+    fn compute_challenge(
+        g_r: Group<N>,
+        h_r: Group<N>,
+        address: Address<N>,
+        nonce: Group<N>,
+        record_view_point: Group<N>,
+    ) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd8(&[g_r, h_r, *address, nonce, record_view_point].map(|point| point.to_x_coordinate()))
+    }
+}
+
+impl<N: Network> AuditKey<N> {
+    /// Returns the address of the account.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the nonce of the record.
+    pub const fn nonce(&self) -> Group<N> {
+        self.nonce
+    }
+
+    /// Returns the record view point.
+    pub const fn record_view_point(&self) -> Group<N> {
+        self.record_view_point
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Returns the record view key, as the x-coordinate of the record view point.
+    pub fn to_record_view_key(&self) -> Field<N> {
+        self.record_view_point.to_x_coordinate()
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a random audit key.
+    pub(super) fn sample_audit_key(rng: &mut TestRng) -> AuditKey<CurrentNetwork> {
+        // Sample a view key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        // Derive an audit key for a random nonce.
+        let audit_key = AuditKey::new(&view_key, Group::rand(rng), rng).unwrap();
+        assert!(audit_key.verify());
+        audit_key
+    }
+}
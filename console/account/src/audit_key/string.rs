@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static AUDIT_KEY_PREFIX: &str = "audit";
+
+impl<N: Network> FromStr for AuditKey<N> {
+    type Err = Error;
+
+    /// Reads in the audit key string.
+    fn from_str(audit_key: &str) -> Result<Self, Self::Err> {
+        // Decode the audit key string from bech32m.
+        let (hrp, data, variant) = bech32::decode(audit_key)?;
+        if hrp != AUDIT_KEY_PREFIX {
+            bail!("Failed to decode audit key: '{hrp}' is an invalid prefix")
+        } else if data.is_empty() {
+            bail!("Failed to decode audit key: data field is empty")
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found an audit key that is not bech32m encoded: {audit_key}");
+        }
+        // Decode the audit key data from u5 to u8, and into the audit key.
+        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+    }
+}
+
+impl<N: Network> Debug for AuditKey<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AuditKey<N> {
+    /// Writes the audit key as a bech32m string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Convert the audit key to bytes.
+        let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
+        // Encode the bytes into bech32m.
+        let string =
+            bech32::encode(AUDIT_KEY_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        // Output the string.
+        Display::fmt(&string, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new audit key.
+            let expected = test_helpers::sample_audit_key(&mut rng);
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, AuditKey::from_str(&candidate)?);
+            assert_eq!(AUDIT_KEY_PREFIX, candidate.split('1').next().unwrap());
+        }
+        // Ensure an invalid prefix fails.
+        assert!(AuditKey::<CurrentNetwork>::from_str("sign1qqqqqq").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> AuditKey<N> {
+    /// Verifies (challenge == challenge') where:
+    ///     challenge' := HashToScalar(G^response address^challenge, nonce^response record_view_point^challenge,
+    ///                                address, nonce, record_view_point)
+    pub fn verify(&self) -> bool {
+        // Ensure the nonce is not the zero point.
+        if self.nonce.is_zero() {
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * address).
+        let g_r = N::g_scalar_multiply(&self.response) + (*self.address * self.challenge);
+        // Compute `h_r` := (response * nonce) + (challenge * record_view_point).
+        let h_r = (self.nonce * self.response) + (self.record_view_point * self.challenge);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        match Self::compute_challenge(g_r, h_r, self.address, self.nonce, self.record_view_point) {
+            // Return `true` if the candidate challenge is correct.
+            Ok(candidate_challenge) => self.challenge == candidate_challenge,
+            // Return `false` if the challenge errored.
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let nonce = Group::rand(rng);
+
+            // Derive an audit key, and ensure it verifies.
+            let audit_key = AuditKey::new(&view_key, nonce, rng)?;
+            assert!(audit_key.verify());
+            assert_eq!(audit_key.address(), Address::try_from(&private_key)?);
+            assert_eq!(audit_key.to_record_view_key(), (nonce * *view_key).to_x_coordinate());
+
+            // Ensure an audit key for a different address fails to verify.
+            let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let candidate = AuditKey::from(
+                other_address,
+                audit_key.nonce(),
+                audit_key.record_view_point(),
+                audit_key.challenge(),
+                audit_key.response(),
+            );
+            assert!(!candidate.verify());
+
+            // Ensure an audit key moved to a different record fails to verify.
+            let candidate = AuditKey::from(
+                audit_key.address(),
+                Group::rand(rng),
+                audit_key.record_view_point(),
+                audit_key.challenge(),
+                audit_key.response(),
+            );
+            assert!(!candidate.verify());
+
+            // Ensure an audit key with a forged record view point fails to verify.
+            let candidate = AuditKey::from(
+                audit_key.address(),
+                audit_key.nonce(),
+                Group::rand(rng),
+                audit_key.challenge(),
+                audit_key.response(),
+            );
+            assert!(!candidate.verify());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use bip39::Mnemonic;
+
+impl<N: Network> HDKey<N> {
+    /// Returns the master key for the given BIP39 mnemonic and (possibly empty) passphrase.
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        // Parse the mnemonic, which also verifies its checksum.
+        let mnemonic = Mnemonic::parse(mnemonic).map_err(|e| anyhow!("Invalid mnemonic: {e}"))?;
+        // Derive the seed from the mnemonic.
+        let mut seed = mnemonic.to_seed(passphrase);
+        let key = Self::from_seed(&seed);
+        seed.zeroize();
+        key
+    }
+
+    /// Samples a new BIP39 mnemonic with the given number of words (12, 15, 18, 21, or 24).
+    pub fn new_mnemonic<R: Rng + CryptoRng>(num_words: usize, rng: &mut R) -> Result<String> {
+        // Ensure the number of words is valid.
+        ensure!(matches!(num_words, 12 | 15 | 18 | 21 | 24), "Invalid number of mnemonic words: {num_words}");
+        // Sample the entropy, where every 3 words encode 32 bits of entropy.
+        let mut entropy = (0..num_words / 3 * 4).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+        let mnemonic = Mnemonic::from_entropy(&entropy).map_err(|e| anyhow!("Invalid entropy: {e}"));
+        entropy.zeroize();
+        Ok(mnemonic?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_from_mnemonic() -> Result<()> {
+        // Ensure the mnemonic derives the same key as its BIP39 seed (test vector from BIP39).
+        let seed = concat!(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553",
+            "1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+        let seed = (0..seed.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&seed[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()?;
+        let expected = HDKey::<CurrentNetwork>::from_seed(&seed)?;
+        assert_eq!(HDKey::<CurrentNetwork>::from_mnemonic(MNEMONIC, "TREZOR")?, expected);
+
+        // Ensure the passphrase changes the key.
+        assert_ne!(HDKey::<CurrentNetwork>::from_mnemonic(MNEMONIC, "")?, expected);
+
+        // Ensure the account private keys match the HD derivation.
+        for account in 0..3 {
+            let expected = expected.derive(&DerivationPath::aleo(account)?)?.to_private_key()?;
+            assert_eq!(PrivateKey::<CurrentNetwork>::from_mnemonic(MNEMONIC, "TREZOR", account)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_mnemonic() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_words in [12, 15, 18, 21, 24] {
+            let mnemonic = HDKey::<CurrentNetwork>::new_mnemonic(num_words, rng)?;
+            assert_eq!(mnemonic.split_whitespace().count(), num_words);
+            // Ensure the mnemonic can be imported.
+            let private_key = PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "", 0)?;
+            assert_eq!(PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "", 0)?, private_key);
+        }
+        for num_words in [0, 11, 13, 25] {
+            assert!(HDKey::<CurrentNetwork>::new_mnemonic(num_words, rng).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_mnemonic() {
+        // Ensure an invalid checksum is rejected.
+        let mnemonic = MNEMONIC.replace("about", "abandon");
+        assert!(HDKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "").is_err());
+        // Ensure an unknown word is rejected.
+        let mnemonic = MNEMONIC.replace("about", "aleo");
+        assert!(HDKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "").is_err());
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod mnemonic;
+mod path;
+
+pub use path::DerivationPath;
+
+use crate::PrivateKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use core::marker::PhantomData;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroize;
+
+/// The HMAC key used to derive the master key from a seed.
+static HD_KEY_MASTER_DOMAIN: &[u8] = b"Aleo seed";
+
+/// A hierarchical deterministic (HD) key, which derives many account private keys from a single seed.
+///
+/// The derivation follows SLIP-10, where only hardened child keys are supported, as Aleo accounts
+/// do not support deriving child public keys from a parent public key:
+///     master := HMAC-SHA512("Aleo seed", seed)
+///     child_i := HMAC-SHA512(chain_code, 0x00 || secret || ser32(i + 2^31))
+/// where the first 32 bytes of the output are the secret, and the last 32 bytes are the chain code.
+#[derive(Clone, PartialEq, Eq, Zeroize)]
+pub struct HDKey<N: Network> {
+    /// The secret of the key.
+    secret: [u8; 32],
+    /// The chain code of the key.
+    chain_code: [u8; 32],
+    /// PhantomData.
+    #[zeroize(skip)]
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> HDKey<N> {
+    /// Returns the master key for the given seed.
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
+        // Ensure the seed is between 128 and 512 bits.
+        ensure!((16..=64).contains(&seed.len()), "The seed must be between 16 and 64 bytes, found {}", seed.len());
+        Self::from_hmac(HD_KEY_MASTER_DOMAIN, &[seed])
+    }
+
+    /// Returns the hardened child key at the given index, where `index` is less than `2^31`.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Ensure the index is not already hardened.
+        ensure!(index < DerivationPath::HARDENED_OFFSET, "The child index {index} is out of range");
+        // Serialize the hardened index in big-endian, as in SLIP-10.
+        let index = (index + DerivationPath::HARDENED_OFFSET).to_be_bytes();
+        Self::from_hmac(&self.chain_code, &[&[0u8], &self.secret, &index])
+    }
+
+    /// Returns the key at the given derivation path from this key.
+    pub fn derive(&self, path: &DerivationPath) -> Result<Self> {
+        path.indices().iter().try_fold(self.clone(), |key, index| HDKey::<N>::derive_child(&key, *index))
+    }
+
+    /// Returns the account private key of this key.
+    pub fn to_private_key(&self) -> Result<PrivateKey<N>> {
+        // Construct the account seed from the first `Field::SIZE_IN_DATA_BITS` bits of the secret.
+        let bits_le = self.secret.to_bits_le();
+        let seed = Field::<N>::from_bits_le(&bits_le[..Field::<N>::size_in_data_bits()])?;
+        // Derive the account private key.
+        PrivateKey::try_from(seed)
+    }
+
+    /// Returns the secret of the key.
+    pub const fn secret(&self) -> &[u8; 32] {
+        &self.secret
+    }
+
+    /// Returns the chain code of the key.
+    pub const fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Returns the key as `HMAC-SHA512(key, data)`.
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Result<Self> {
+        // Compute the HMAC of the data.
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).map_err(|e| anyhow!("Invalid HMAC key: {e}"))?;
+        data.iter().for_each(|data| mac.update(data));
+        let output = mac.finalize().into_bytes();
+
+        // Split the output into the secret and chain code.
+        let mut secret = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        secret.copy_from_slice(&output[..32]);
+        chain_code.copy_from_slice(&output[32..]);
+        Ok(Self { secret, chain_code, _phantom: PhantomData })
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account private key at the given account index for the given BIP39 mnemonic and passphrase,
+    /// at the derivation path `m/44'/683'/{account}'/0'`.
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str, account: u32) -> Result<Self> {
+        HDKey::<N>::from_mnemonic(mnemonic, passphrase)?.derive(&DerivationPath::aleo(account)?)?.to_private_key()
+    }
+}
+
+impl<N: Network> Debug for HDKey<N> {
+    /// Writes the HD key without revealing its secret.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("HDKey")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// The seed of the BIP39 test mnemonic `abandon abandon ... about` with the passphrase `TREZOR`.
+    const BIP39_SEED: &str = concat!(
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553",
+        "1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+
+    /// Decodes the given hex string into bytes.
+    fn from_hex(string: &str) -> Vec<u8> {
+        (0..string.len()).step_by(2).map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_vectors() -> Result<()> {
+        // (seed, path, secret, chain code)
+        let vectors = [
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m",
+                "f17f00aacc384e27747eda10eda2216d48921f01bc2dcbd022f7e13e91627c7a",
+                "176119d872cde0f463915cfb0db3380a9c099c8d8faae8a4107a43c40b53797d",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m/0'",
+                "60493a84a8e10c94b0168f2c10822afe30a2af6af4379225813567bf4d4c37af",
+                "23c5fae458b28a74b73aa9d64a0173bf010d52c72fde79bdd9c77347d52b9372",
+            ),
+            (
+                BIP39_SEED,
+                "m",
+                "d3171fac3c22e36894e8089e9696a4de6770b02f7c3b5341fb494449e8a550f5",
+                "3fcbae6a39f447f7689019b1cfdfc7a5e98ee3d88160e3348c9d59b349240fcf",
+            ),
+            (
+                BIP39_SEED,
+                "m/44'/683'/0'/0'",
+                "d5cdb5461394d1598ba6e189a61b7b93fb676fa5f3e9fe1196de7c5505659388",
+                "191875fc6bc50c551365d45e53ca4e9154086d5b56986cc5d12db2ce25dc04d0",
+            ),
+            (
+                BIP39_SEED,
+                "m/44'/683'/1'/0'",
+                "035bbcbdad215bfc5666bf28e1f8145013f66f2dc3f9dd4884e35ea363854aef",
+                "accce97f74d2aa554269bdf286fe7317f6ff278c0db3acdbbe173f5ddc696f61",
+            ),
+        ];
+
+        for (seed, path, secret, chain_code) in vectors {
+            let key = HDKey::<CurrentNetwork>::from_seed(&from_hex(seed))?.derive(&DerivationPath::from_str(path)?)?;
+            assert_eq!(key.secret().to_vec(), from_hex(secret));
+            assert_eq!(key.chain_code().to_vec(), from_hex(chain_code));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_private_keys() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a seed.
+        let seed = (0..64).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+        let master = HDKey::<CurrentNetwork>::from_seed(&seed)?;
+
+        // Ensure the derivation is deterministic, and that each account is distinct.
+        let private_keys = (0..10)
+            .map(|account| master.derive(&DerivationPath::aleo(account)?)?.to_private_key())
+            .collect::<Result<Vec<_>>>()?;
+        for (account, private_key) in (0..).zip(&private_keys) {
+            let candidate = master.derive(&DerivationPath::aleo(account)?)?.to_private_key()?;
+            assert_eq!(*private_key, candidate);
+        }
+        assert!(private_keys.iter().map(|private_key| private_key.seed()).all_unique());
+
+        // Ensure the derivation is equivalent to deriving each child in sequence.
+        let candidate = master.derive_child(44)?.derive_child(683)?.derive_child(0)?.derive_child(0)?;
+        assert_eq!(candidate.to_private_key()?, private_keys[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_seed() {
+        assert!(HDKey::<CurrentNetwork>::from_seed(&[0u8; 15]).is_err());
+        assert!(HDKey::<CurrentNetwork>::from_seed(&[0u8; 65]).is_err());
+        assert!(HDKey::<CurrentNetwork>::from_seed(&[0u8; 16]).unwrap().derive_child(1 << 31).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The purpose of the derivation path, as in BIP44.
+const PURPOSE: u32 = 44;
+/// The coin type of Aleo, as registered in SLIP-44.
+const COIN_TYPE: u32 = 683;
+
+/// A derivation path of hardened child indices, such as `m/44'/683'/0'/0'`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// The offset added to the index of a hardened child.
+    pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+    /// Initializes a new derivation path from the given (unhardened) child indices.
+    pub fn new(indices: Vec<u32>) -> Result<Self> {
+        // Ensure each index is less than `2^31`.
+        if let Some(index) = indices.iter().find(|index| **index >= Self::HARDENED_OFFSET) {
+            bail!("The child index {index} in the derivation path is out of range")
+        }
+        Ok(Self(indices))
+    }
+
+    /// Returns the derivation path of the given Aleo account, `m/44'/683'/{account}'/0'`.
+    pub fn aleo(account: u32) -> Result<Self> {
+        Self::new(vec![PURPOSE, COIN_TYPE, account, 0])
+    }
+
+    /// Returns the (unhardened) child indices of the derivation path.
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parses a derivation path of the form `m/44'/683'/0'/0'`.
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut components = path.split('/');
+        // Ensure the path starts at the master key.
+        ensure!(components.next() == Some("m"), "The derivation path must start with 'm'");
+        // Parse the hardened child indices.
+        let indices = components
+            .map(|component| match component.strip_suffix('\'').or_else(|| component.strip_suffix('h')) {
+                Some(index) if !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit()) => {
+                    Ok(index.parse::<u32>()?)
+                }
+                Some(_) => bail!("Invalid component '{component}' in the derivation path"),
+                None => bail!("Only hardened components are supported, found '{component}'"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(indices)
+    }
+}
+
+impl Display for DerivationPath {
+    /// Writes the derivation path, marking each component as hardened.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        self.0.iter().try_for_each(|index| write!(f, "/{index}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let path = DerivationPath::aleo(5)?;
+        assert_eq!(path.to_string(), "m/44'/683'/5'/0'");
+        assert_eq!(DerivationPath::from_str("m/44'/683'/5'/0'")?, path);
+        assert_eq!(DerivationPath::from_str("m/44h/683h/5h/0h")?, path);
+        assert_eq!(DerivationPath::from_str("m")?.indices(), &[] as &[u32]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        for path in ["", "44'/683'", "m/", "m/44", "m/44'/683", "m/'", "m/+1'", "m/2147483648'", "M/44'", "m/44'/"] {
+            assert!(DerivationPath::from_str(path).is_err(), "{path}");
+        }
+    }
+}
@@ -21,6 +21,11 @@ pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group,
 
 mod address;
 
+#[cfg(feature = "audit_key")]
+pub mod audit_key;
+#[cfg(feature = "audit_key")]
+pub use audit_key::*;
+
 #[cfg(feature = "compute_key")]
 pub mod compute_key;
 #[cfg(feature = "compute_key")]
@@ -31,6 +36,11 @@ pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "hd_key")]
+pub mod hd_key;
+#[cfg(feature = "hd_key")]
+pub use hd_key::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
@@ -41,6 +51,16 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "signing_key")]
+pub mod signing_key;
+#[cfg(feature = "signing_key")]
+pub use signing_key::*;
+
+#[cfg(feature = "threshold")]
+pub mod threshold;
+#[cfg(feature = "threshold")]
+pub use threshold::*;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "private_key")]
+use crate::PrivateKey;
+use crate::{address::Address, ComputeKey, ViewKey};
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Scalar;
+
+use zeroize::Zeroize;
+
+/// The account signing key `(sk_sig, r_sig)`, which is the minimal secret held by a constrained signer,
+/// such as a hardware wallet, that does not hold the account seed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+pub struct SigningKey<N: Network> {
+    /// The signature secret key.
+    sk_sig: Scalar<N>,
+    /// The signature randomizer.
+    r_sig: Scalar<N>,
+}
+
+impl<N: Network> SigningKey<N> {
+    /// Initializes the account signing key from `(sk_sig, r_sig)`.
+    pub const fn new(sk_sig: Scalar<N>, r_sig: Scalar<N>) -> Self {
+        Self { sk_sig, r_sig }
+    }
+
+    /// Returns the signature secret key.
+    pub const fn sk_sig(&self) -> Scalar<N> {
+        self.sk_sig
+    }
+
+    /// Returns the signature randomizer.
+    pub const fn r_sig(&self) -> Scalar<N> {
+        self.r_sig
+    }
+
+    /// Returns the account compute key.
+    pub fn to_compute_key(&self) -> Result<ComputeKey<N>> {
+        // Compute pk_sig := G^sk_sig, and pr_sig := G^r_sig.
+        ComputeKey::try_from((N::g_scalar_multiply(&self.sk_sig), N::g_scalar_multiply(&self.r_sig)))
+    }
+
+    /// Returns the account view key.
+    pub fn to_view_key(&self) -> Result<ViewKey<N>> {
+        // Compute view_key := sk_sig + r_sig + sk_prf.
+        Ok(ViewKey::from_scalar(self.sk_sig + self.r_sig + self.to_compute_key()?.sk_prf()))
+    }
+
+    /// Returns the account address.
+    pub fn to_address(&self) -> Result<Address<N>> {
+        Ok(self.to_compute_key()?.to_address())
+    }
+}
+
+#[cfg(feature = "private_key")]
+impl<N: Network> From<&PrivateKey<N>> for SigningKey<N> {
+    /// Derives the account signing key from an account private key.
+    fn from(private_key: &PrivateKey<N>) -> Self {
+        Self { sk_sig: private_key.sk_sig(), r_sig: private_key.r_sig() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_from_private_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let signing_key = SigningKey::from(&private_key);
+
+            // Ensure the signing key derives the same account.
+            assert_eq!(signing_key.to_compute_key()?, ComputeKey::try_from(&private_key)?);
+            assert_eq!(signing_key.to_view_key()?, ViewKey::try_from(&private_key)?);
+            assert_eq!(signing_key.to_address()?, Address::try_from(&private_key)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for SigningCommitments<N> {
+    /// Reads the signing commitments from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let hiding = Group::read_le(&mut reader)?;
+        let binding = Group::read_le(&mut reader)?;
+        let num_generators = u16::read_le(&mut reader)?;
+        let generator_commitments = (0..num_generators)
+            .map(|_| Ok((Group::read_le(&mut reader)?, Group::read_le(&mut reader)?, Group::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(Self { index, hiding, binding, generator_commitments })
+    }
+}
+
+impl<N: Network> ToBytes for SigningCommitments<N> {
+    /// Writes the signing commitments to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)?;
+        u16::try_from(self.generator_commitments.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for (hiding, binding, gamma) in &self.generator_commitments {
+            hiding.write_le(&mut writer)?;
+            binding.write_le(&mut writer)?;
+            gamma.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for SignatureShare<N> {
+    /// Reads the signature share from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { index, response })
+    }
+}
+
+impl<N: Network> ToBytes for SignatureShare<N> {
+    /// Writes the signature share to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for RefreshCommitments<N> {
+    /// Reads the refresh commitments from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let sender = u16::read_le(&mut reader)?;
+        let num_commitments = u16::read_le(&mut reader)?;
+        let commitments = (0..num_commitments).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        Ok(Self { sender, commitments })
+    }
+}
+
+impl<N: Network> ToBytes for RefreshCommitments<N> {
+    /// Writes the refresh commitments to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.sender.write_le(&mut writer)?;
+        u16::try_from(self.commitments.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        self.commitments.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for RefreshDelta<N> {
+    /// Reads the refresh delta from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let sender = u16::read_le(&mut reader)?;
+        let recipient = u16::read_le(&mut reader)?;
+        let delta = Scalar::read_le(&mut reader)?;
+        Ok(Self { sender, recipient, delta })
+    }
+}
+
+impl<N: Network> ToBytes for RefreshDelta<N> {
+    /// Writes the refresh delta to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.sender.write_le(&mut writer)?;
+        self.recipient.write_le(&mut writer)?;
+        self.delta.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a 2-of-3 account.
+        let (_, key_shares) = test_helpers::sample_key_shares(2, 3, rng);
+
+        // Check the byte representation of the signing commitments.
+        let (_, expected) = key_shares[0].commit(&[Group::rand(rng), Group::rand(rng)], rng);
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, SigningCommitments::read_le(&expected_bytes[..])?);
+        assert!(SigningCommitments::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of the signature share.
+        let expected = SignatureShare::<CurrentNetwork>::from(2, Scalar::rand(rng));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, SignatureShare::read_le(&expected_bytes[..])?);
+        assert!(SignatureShare::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of the refresh commitments.
+        let (expected, deltas) = key_shares[1].prepare_refresh(rng);
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RefreshCommitments::read_le(&expected_bytes[..])?);
+        assert!(RefreshCommitments::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of the refresh delta.
+        let expected = deltas[2];
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RefreshDelta::read_le(&expected_bytes[..])?);
+        assert!(RefreshDelta::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> KeyShare<N> {
+    /// Samples a new `threshold`-of-`num_participants` account, and returns the key share of each participant.
+    /// Note: This method acts as a trusted dealer, and must be run on a trusted device.
+    pub fn new<R: Rng + CryptoRng>(threshold: u16, num_participants: u16, rng: &mut R) -> Result<Vec<Self>> {
+        Self::split(&PrivateKey::new(rng)?, threshold, num_participants, rng)
+    }
+
+    /// Splits the given private key into `num_participants` key shares, such that any `threshold`
+    /// of them can sign for the account, by secret-sharing `sk_sig` with Shamir's secret sharing.
+    /// Note: This method acts as a trusted dealer, and the private key must be erased afterwards.
+    pub fn split<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<Vec<Self>> {
+        // Ensure the threshold is valid.
+        ensure!(threshold > 0, "The threshold must be at least 1");
+        ensure!(
+            threshold <= num_participants,
+            "The threshold ({threshold}) cannot exceed the number of participants ({num_participants})"
+        );
+
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Derive the view key.
+        let view_key = ViewKey::try_from((private_key, &compute_key))?;
+
+        // Sample a random polynomial of degree `threshold - 1`, where `f(0) := sk_sig`.
+        let coefficients =
+            std::iter::once(private_key.sk_sig()).chain((1..threshold).map(|_| Scalar::rand(rng))).collect::<Vec<_>>();
+        // Compute the signing share of each participant `i` as `s_i := f(i)`.
+        let signing_shares =
+            (1..=num_participants).map(|index| evaluate_polynomial(&coefficients, index)).collect::<Vec<_>>();
+
+        // Construct the public key package.
+        let threshold_key = ThresholdKey {
+            threshold,
+            compute_key,
+            verifying_shares: signing_shares.iter().map(N::g_scalar_multiply).collect(),
+        };
+
+        // Return the key shares.
+        Ok((1..=num_participants)
+            .zip(signing_shares)
+            .map(|(index, signing_share)| Self { index, signing_share, view_key, threshold_key: threshold_key.clone() })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_split() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (1, 3), (2, 3), (3, 5), (5, 5)] {
+            let (private_key, key_shares) = test_helpers::sample_key_shares(threshold, num_participants, rng);
+            assert_eq!(key_shares.len(), num_participants as usize);
+
+            for (i, key_share) in key_shares.iter().enumerate() {
+                // Ensure the key share corresponds to the account.
+                assert_eq!(key_share.index() as usize, i + 1);
+                assert_eq!(key_share.to_address(), Address::try_from(&private_key)?);
+                assert_eq!(key_share.view_key(), ViewKey::try_from(&private_key)?);
+                // Ensure the verifying share corresponds to the signing share.
+                let verifying_share = key_share.threshold_key().verifying_share(key_share.index())?;
+                assert_eq!(verifying_share, CurrentNetwork::g_scalar_multiply(&key_share.signing_share()));
+            }
+
+            // Ensure the first `threshold` signing shares recover `sk_sig`.
+            let participants = (1..=threshold).collect::<Vec<_>>();
+            let candidate = key_shares[..threshold as usize]
+                .iter()
+                .map(|share| Ok(share.signing_share() * lagrange_coefficient(share.index(), &participants)?))
+                .sum::<Result<Scalar<_>>>()?;
+            assert_eq!(private_key.sk_sig(), candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_invalid_threshold() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(KeyShare::split(&private_key, 0, 3, rng).is_err());
+        assert!(KeyShare::split(&private_key, 4, 3, rng).is_err());
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod keygen;
+mod refresh;
+mod sign;
+
+pub use refresh::{RefreshCommitments, RefreshDelta};
+pub use sign::{SignatureShare, SigningCommitments, SigningNonces, SigningPackage};
+
+use crate::{address::Address, ComputeKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The public key package of a threshold account.
+///
+/// A threshold account is an ordinary account, whose signature secret key `sk_sig` is secret-shared
+/// among `n` participants, such that any `t` of them can jointly produce an ordinary `Signature`.
+/// The view key of the account is shared with every participant, as it does not authorize spending.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdKey<N: Network> {
+    /// The minimum number of participants required to sign.
+    threshold: u16,
+    /// The compute key of the account.
+    compute_key: ComputeKey<N>,
+    /// The verifying share `s_i * G` of each participant, where participant `i` is at position `i - 1`.
+    verifying_shares: Vec<Group<N>>,
+}
+
+impl<N: Network> ThresholdKey<N> {
+    /// Returns the minimum number of participants required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn num_participants(&self) -> u16 {
+        // Note: The number of participants is checked to fit in a `u16` on initialization.
+        self.verifying_shares.len() as u16
+    }
+
+    /// Returns the compute key of the account.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Returns the address of the account.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+
+    /// Returns the verifying shares of the participants.
+    pub fn verifying_shares(&self) -> &[Group<N>] {
+        &self.verifying_shares
+    }
+
+    /// Returns the verifying share of the given participant.
+    pub fn verifying_share(&self, index: u16) -> Result<Group<N>> {
+        match index {
+            0 => bail!("Participant index 0 is invalid"),
+            _ => match self.verifying_shares.get(index as usize - 1) {
+                Some(verifying_share) => Ok(*verifying_share),
+                None => bail!("Participant index {index} exceeds the number of participants"),
+            },
+        }
+    }
+}
+
+/// The key share of a participant in a threshold account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyShare<N: Network> {
+    /// The participant index, starting from 1.
+    index: u16,
+    /// The signing share `s_i` of the signature secret key.
+    signing_share: Scalar<N>,
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The public key package of the account.
+    threshold_key: ThresholdKey<N>,
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the participant index.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the signing share.
+    pub const fn signing_share(&self) -> Scalar<N> {
+        self.signing_share
+    }
+
+    /// Returns the view key of the account.
+    pub const fn view_key(&self) -> ViewKey<N> {
+        self.view_key
+    }
+
+    /// Returns the public key package of the account.
+    pub const fn threshold_key(&self) -> &ThresholdKey<N> {
+        &self.threshold_key
+    }
+
+    /// Returns the address of the account.
+    pub fn to_address(&self) -> Address<N> {
+        self.threshold_key.to_address()
+    }
+}
+
+/// Returns the given participant index as a scalar.
+fn to_scalar<N: Network>(index: u16) -> Scalar<N> {
+    Scalar::from_field_lossy(&Field::from_u16(index))
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at the given participant index.
+fn evaluate_polynomial<N: Network>(coefficients: &[Scalar<N>], index: u16) -> Scalar<N> {
+    let x = to_scalar::<N>(index);
+    coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Returns the Lagrange coefficient at zero of the given participant, over the given set of participants.
+fn lagrange_coefficient<N: Network>(index: u16, participants: &[u16]) -> Result<Scalar<N>> {
+    let x_i = to_scalar::<N>(index);
+    // Compute the numerator and denominator as `prod_{j != i} x_j` and `prod_{j != i} (x_j - x_i)`.
+    let (numerator, denominator) = participants.iter().filter(|j| **j != index).fold(
+        (Scalar::<N>::one(), Scalar::<N>::one()),
+        |(numerator, denominator), j| {
+            let x_j = to_scalar::<N>(*j);
+            (numerator * x_j, denominator * (x_j - x_i))
+        },
+    );
+    Ok(numerator * denominator.inverse()?)
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples the key shares of a random `threshold`-of-`num_participants` account.
+    pub(super) fn sample_key_shares(
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut TestRng,
+    ) -> (PrivateKey<CurrentNetwork>, Vec<KeyShare<CurrentNetwork>>) {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let key_shares = KeyShare::split(&private_key, threshold, num_participants, rng).unwrap();
+        (private_key, key_shares)
+    }
+
+    /// Returns the refreshed key shares, after every participant applies the given refresh commitments,
+    /// and the refresh deltas that their senders sent to that participant.
+    pub(super) fn refresh_key_shares(
+        key_shares: &[KeyShare<CurrentNetwork>],
+        commitments: &[RefreshCommitments<CurrentNetwork>],
+        deltas: &[Vec<RefreshDelta<CurrentNetwork>>],
+    ) -> Result<Vec<KeyShare<CurrentNetwork>>> {
+        key_shares
+            .iter()
+            .map(|share| {
+                let deltas = deltas.iter().map(|deltas| deltas[share.index() as usize - 1]).collect::<Vec<_>>();
+                share.refresh(commitments, &deltas)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_lagrange_coefficients() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a random polynomial of degree 2.
+        let coefficients = (0..3).map(|_| Scalar::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+
+        // Ensure any 3 evaluations interpolate the constant term.
+        for participants in [[1, 2, 3], [1, 3, 5], [2, 4, 5], [3, 4, 5]] {
+            let candidate = participants
+                .iter()
+                .map(|i| Ok(evaluate_polynomial(&coefficients, *i) * lagrange_coefficient(*i, &participants)?))
+                .sum::<Result<Scalar<_>>>()?;
+            assert_eq!(coefficients[0], candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_shamir_vectors() -> Result<()> {
+        // The polynomial `f(x) := sk + a_1 * x - 5 * x^2` over the scalar field.
+        let coefficients = [
+            Scalar::<CurrentNetwork>::from_str(
+                "1234567890123456789012345678901234567890123456789012345678901234567scalar",
+            )?,
+            Scalar::from_str("987654321098765432109876543210scalar")?,
+            -to_scalar::<CurrentNetwork>(5),
+        ];
+
+        // Ensure the shares `f(i)` match the test vectors.
+        let expected_shares = [
+            "1234567890123456789012345678901234568877777777887777777788777777772scalar",
+            "1234567890123456789012345678901234569865432098986543209898654320967scalar",
+            "1234567890123456789012345678901234570853086420085308642008530864152scalar",
+            "1234567890123456789012345678901234571840740741184074074118407407327scalar",
+            "1234567890123456789012345678901234572828395062282839506228283950492scalar",
+        ];
+        for (index, expected) in (1..=5).zip_eq(expected_shares) {
+            assert_eq!(evaluate_polynomial(&coefficients, index), Scalar::from_str(expected)?);
+        }
+
+        // Ensure the Lagrange coefficients of the participants `[1, 3, 5]` match the test vectors.
+        let participants = [1, 3, 5];
+        let expected_coefficients = [
+            "1847226007687456030304430455358463303734012060607357170794474590595381190337scalar",
+            "1583336578017819454546654676021539974629153194806306146395263934796041020286scalar",
+            "791668289008909727273327338010769987314576597403153073197631967398020510144scalar",
+        ];
+        for (index, expected) in participants.iter().zip_eq(expected_coefficients) {
+            assert_eq!(lagrange_coefficient::<CurrentNetwork>(*index, &participants)?, Scalar::from_str(expected)?);
+        }
+        Ok(())
+    }
+}
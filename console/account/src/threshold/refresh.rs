@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The refresh commitments of a participant of a threshold account, which are broadcast to every participant.
+///
+/// A share refresh re-randomizes the key shares of every participant, without changing the account,
+/// so that key shares leaked before the refresh are useless when combined with key shares after it.
+/// Each sender broadcasts its refresh commitments, and sends a `RefreshDelta` to each participant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshCommitments<N: Network> {
+    /// The index of the sending participant.
+    pub(super) sender: u16,
+    /// The commitments `a_k * G` to the coefficients `a_1, ..., a_{t-1}` of a polynomial where `f(0) := 0`.
+    pub(super) commitments: Vec<Group<N>>,
+}
+
+impl<N: Network> RefreshCommitments<N> {
+    /// Returns the index of the sending participant.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the commitments to the polynomial coefficients.
+    pub fn commitments(&self) -> &[Group<N>] {
+        &self.commitments
+    }
+
+    /// Returns the commitment `f(index) * G` to the delta of the given participant.
+    fn to_delta_commitment(&self, index: u16) -> Group<N> {
+        let x = to_scalar::<N>(index);
+        self.commitments.iter().rev().fold(Group::zero(), |acc, commitment| (acc + commitment) * x)
+    }
+}
+
+/// The refresh delta of a sender for a single recipient of a threshold account.
+/// Note: The delta must be sent to its recipient over a private channel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RefreshDelta<N: Network> {
+    /// The index of the sending participant.
+    pub(super) sender: u16,
+    /// The index of the receiving participant.
+    pub(super) recipient: u16,
+    /// The delta `f(recipient)`.
+    pub(super) delta: Scalar<N>,
+}
+
+impl<N: Network> RefreshDelta<N> {
+    /// Returns the index of the sending participant.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the index of the receiving participant.
+    pub const fn recipient(&self) -> u16 {
+        self.recipient
+    }
+
+    /// Returns the delta.
+    pub const fn delta(&self) -> Scalar<N> {
+        self.delta
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the refresh commitments of this participant, which must be broadcast to every participant,
+    /// and the refresh delta for each participant, which must be sent to its recipient privately.
+    pub fn prepare_refresh<R: Rng + CryptoRng>(&self, rng: &mut R) -> (RefreshCommitments<N>, Vec<RefreshDelta<N>>) {
+        // Sample a random polynomial of degree `threshold - 1`, where `f(0) := 0`.
+        let coefficients = std::iter::once(Scalar::zero())
+            .chain((1..self.threshold_key.threshold).map(|_| Scalar::rand(rng)))
+            .collect::<Vec<_>>();
+        // Commit to the coefficients `a_1, ..., a_{t-1}`.
+        let commitments = coefficients[1..].iter().map(N::g_scalar_multiply).collect();
+        // Compute the delta of each participant `j` as `f(j)`.
+        let deltas = (1..=self.threshold_key.num_participants())
+            .map(|recipient| RefreshDelta {
+                sender: self.index,
+                recipient,
+                delta: evaluate_polynomial(&coefficients, recipient),
+            })
+            .collect();
+        // Return the refresh commitments and deltas.
+        (RefreshCommitments { sender: self.index, commitments }, deltas)
+    }
+
+    /// Returns the refreshed key share, after applying the given refresh commitments,
+    /// and the refresh delta that each of their senders sent to this participant.
+    /// Note: Every participant must apply the same set of refresh commitments.
+    pub fn refresh(&self, commitments: &[RefreshCommitments<N>], deltas: &[RefreshDelta<N>]) -> Result<Self> {
+        // Retrieve the threshold and number of participants.
+        let threshold = self.threshold_key.threshold;
+        let num_participants = self.threshold_key.num_participants();
+
+        // Ensure there are at least `threshold` refresh commitments.
+        ensure!(
+            commitments.len() >= threshold as usize,
+            "Found {} refresh commitments, but expected at least {threshold}",
+            commitments.len()
+        );
+        // Ensure the refresh commitments are from distinct participants.
+        ensure!(
+            commitments.iter().map(|commitments| commitments.sender).all_unique(),
+            "Found duplicate refresh commitments from the same participant"
+        );
+        // Ensure there is exactly one refresh delta for each refresh commitments.
+        ensure!(
+            deltas.len() == commitments.len(),
+            "Found {} refresh deltas, but expected {}",
+            deltas.len(),
+            commitments.len()
+        );
+
+        let mut signing_share = self.signing_share;
+        let mut verifying_shares = self.threshold_key.verifying_shares.clone();
+
+        for refresh_commitments in commitments {
+            // Ensure the sender is a participant.
+            ensure!(
+                (1..=num_participants).contains(&refresh_commitments.sender),
+                "Refresh commitments sender {} is not a participant",
+                refresh_commitments.sender
+            );
+            // Ensure the refresh commitments are well-formed.
+            ensure!(
+                refresh_commitments.commitments.len() == threshold as usize - 1,
+                "Refresh commitments from {} have an incorrect number of commitments",
+                refresh_commitments.sender
+            );
+
+            // Retrieve the delta from the sender.
+            let Some(delta) = deltas.iter().find(|delta| delta.sender == refresh_commitments.sender) else {
+                bail!("Missing the refresh delta from {}", refresh_commitments.sender)
+            };
+            // Ensure the delta is for this participant.
+            ensure!(
+                delta.recipient == self.index,
+                "Refresh delta from {} is for participant {}",
+                delta.sender,
+                delta.recipient
+            );
+            // Ensure the delta is consistent with the commitments.
+            ensure!(
+                N::g_scalar_multiply(&delta.delta) == refresh_commitments.to_delta_commitment(self.index),
+                "Refresh delta from {} is invalid",
+                delta.sender
+            );
+
+            // Apply the delta to the signing share.
+            signing_share += delta.delta;
+            // Apply the delta commitment to each verifying share.
+            for (j, verifying_share) in (1..=num_participants).zip(verifying_shares.iter_mut()) {
+                *verifying_share += refresh_commitments.to_delta_commitment(j);
+            }
+        }
+
+        // Return the refreshed key share.
+        Ok(Self {
+            index: self.index,
+            signing_share,
+            view_key: self.view_key,
+            threshold_key: ThresholdKey { threshold, compute_key: self.threshold_key.compute_key, verifying_shares },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_refresh() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a 2-of-3 account.
+        let (private_key, key_shares) = test_helpers::sample_key_shares(2, 3, rng);
+
+        // Refresh the key shares.
+        let (commitments, deltas): (Vec<_>, Vec<_>) = key_shares.iter().map(|share| share.prepare_refresh(rng)).unzip();
+        let refreshed = test_helpers::refresh_key_shares(&key_shares, &commitments, &deltas)?;
+
+        for (key_share, refreshed_share) in key_shares.iter().zip(&refreshed) {
+            // Ensure the account is unchanged.
+            assert_eq!(refreshed_share.to_address(), Address::try_from(&private_key)?);
+            // Ensure the signing share has changed.
+            assert_ne!(refreshed_share.signing_share(), key_share.signing_share());
+            // Ensure every participant agrees on the verifying shares.
+            assert_eq!(refreshed_share.threshold_key(), refreshed[0].threshold_key());
+            // Ensure the verifying share corresponds to the signing share.
+            let verifying_share = refreshed_share.threshold_key().verifying_share(refreshed_share.index())?;
+            assert_eq!(verifying_share, CurrentNetwork::g_scalar_multiply(&refreshed_share.signing_share()));
+        }
+
+        // Ensure any 2 refreshed signing shares recover `sk_sig`.
+        let participants = [1, 3];
+        let candidate = [&refreshed[0], &refreshed[2]]
+            .iter()
+            .map(|share| Ok(share.signing_share() * lagrange_coefficient(share.index(), &participants)?))
+            .sum::<Result<Scalar<_>>>()?;
+        assert_eq!(private_key.sk_sig(), candidate);
+
+        // Retrieve the refresh deltas for participant 2.
+        let deltas_2 = deltas.iter().map(|deltas| deltas[1]).collect::<Vec<_>>();
+        assert!(key_shares[1].refresh(&commitments, &deltas_2).is_ok());
+        // Ensure a tampered refresh delta is rejected.
+        let mut tampered = deltas_2.clone();
+        tampered[0].delta += Scalar::one();
+        assert!(key_shares[1].refresh(&commitments, &tampered).is_err());
+        // Ensure a refresh delta for another participant is rejected.
+        let mut tampered = deltas_2.clone();
+        tampered[0] = deltas[0][0];
+        assert!(key_shares[1].refresh(&commitments, &tampered).is_err());
+        // Ensure a missing refresh delta is rejected.
+        assert!(key_shares[1].refresh(&commitments, &deltas_2[..2]).is_err());
+        // Ensure too few refresh commitments are rejected.
+        assert!(key_shares[1].refresh(&commitments[..1], &deltas_2[..1]).is_err());
+        // Ensure duplicate refresh commitments are rejected.
+        let duplicates = [commitments[0].clone(), commitments[0].clone()];
+        assert!(key_shares[1].refresh(&duplicates, &[deltas_2[0], deltas_2[0]]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_delta_commitment_vectors() {
+        // Commit to the polynomial `f(x) := 5x + 7x^2`.
+        let g = CurrentNetwork::g_scalar_multiply(&Scalar::one());
+        let commitments = RefreshCommitments::<CurrentNetwork> {
+            sender: 1,
+            commitments: vec![g * to_scalar::<CurrentNetwork>(5), g * to_scalar::<CurrentNetwork>(7)],
+        };
+
+        // Ensure the delta commitments are `f(j) * G`.
+        for (index, expected) in [(1, 12), (2, 38), (3, 78), (4, 132)] {
+            assert_eq!(commitments.to_delta_commitment(index), g * to_scalar::<CurrentNetwork>(expected));
+        }
+    }
+}
@@ -0,0 +1,526 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The secret nonces of a participant for a single signing session.
+/// Note: The nonces must never be reused, and are consumed when signing.
+pub struct SigningNonces<N: Network> {
+    /// The hiding nonce `d_i`.
+    hiding: Scalar<N>,
+    /// The binding nonce `e_i`.
+    binding: Scalar<N>,
+}
+
+/// The public commitments of a participant for a single signing session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningCommitments<N: Network> {
+    /// The participant index.
+    pub(super) index: u16,
+    /// The hiding commitment `d_i * G`.
+    pub(super) hiding: Group<N>,
+    /// The binding commitment `e_i * G`.
+    pub(super) binding: Group<N>,
+    /// The commitments `(d_i * H, e_i * H, s_i * H)` for each additional generator `H`.
+    pub(super) generator_commitments: Vec<(Group<N>, Group<N>, Group<N>)>,
+}
+
+impl<N: Network> SigningCommitments<N> {
+    /// Initializes the signing commitments from their parts.
+    pub const fn from(
+        index: u16,
+        hiding: Group<N>,
+        binding: Group<N>,
+        generator_commitments: Vec<(Group<N>, Group<N>, Group<N>)>,
+    ) -> Self {
+        Self { index, hiding, binding, generator_commitments }
+    }
+
+    /// Returns the participant index.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the hiding commitment.
+    pub const fn hiding(&self) -> Group<N> {
+        self.hiding
+    }
+
+    /// Returns the binding commitment.
+    pub const fn binding(&self) -> Group<N> {
+        self.binding
+    }
+
+    /// Returns the commitments for the additional generators.
+    pub fn generator_commitments(&self) -> &[(Group<N>, Group<N>, Group<N>)] {
+        &self.generator_commitments
+    }
+}
+
+/// The signature share of a participant for a single signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare<N: Network> {
+    /// The participant index.
+    pub(super) index: u16,
+    /// The response share `z_i`.
+    pub(super) response: Scalar<N>,
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Initializes the signature share from its parts.
+    pub const fn from(index: u16, response: Scalar<N>) -> Self {
+        Self { index, response }
+    }
+
+    /// Returns the participant index.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the response share.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+/// The signing package of a signing session, derived from the commitments of the signers and the message.
+///
+/// The nonce `r` of the session is never known to any participant, and is defined as
+/// `r := sum_i (d_i + rho_i * e_i)`, where `rho_i` is the binding factor of participant `i`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningPackage<N: Network> {
+    /// The additional generators `H`.
+    generators: Vec<Group<N>>,
+    /// The signing commitments, ordered by participant index.
+    commitments: Vec<SigningCommitments<N>>,
+    /// The binding factor `rho_i` of each signer.
+    binding_factors: Vec<Scalar<N>>,
+    /// The Lagrange coefficient `lambda_i` of each signer.
+    lagrange_coefficients: Vec<Scalar<N>>,
+    /// The nonce commitment `r * G`.
+    nonce_commitment: Group<N>,
+    /// The nonce commitment `r * H` for each additional generator `H`.
+    generator_commitments: Vec<Group<N>>,
+    /// The value `sk_sig * H` for each additional generator `H`.
+    gammas: Vec<Group<N>>,
+}
+
+impl<N: Network> SigningPackage<N> {
+    /// Initializes the signing package for the given additional generators, signing commitments, and message.
+    /// Note: The message must bind every value that the signers are signing, including the generators.
+    pub fn new(
+        threshold_key: &ThresholdKey<N>,
+        generators: &[Group<N>],
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure there are enough signers.
+        ensure!(
+            commitments.len() >= threshold_key.threshold as usize,
+            "Found {} signers, but expected at least {}",
+            commitments.len(),
+            threshold_key.threshold
+        );
+        // Order the commitments by participant index.
+        let commitments = commitments.iter().cloned().sorted_by_key(|commitments| commitments.index).collect_vec();
+        // Ensure the signers are distinct participants.
+        ensure!(commitments.iter().map(|commitments| commitments.index).all_unique(), "Found duplicate signers");
+        for commitments in &commitments {
+            threshold_key.verifying_share(commitments.index)?;
+        }
+        // Ensure every signer committed to each of the generators.
+        let num_generators = generators.len();
+        ensure!(
+            commitments.iter().all(|commitments| commitments.generator_commitments.len() == num_generators),
+            "Expected every signer to commit to {num_generators} generators"
+        );
+
+        // Construct the preimage as `(address, message, [index, hiding, binding, generator commitments])`.
+        let mut preimage = vec![
+            Field::new_domain_separator("AleoThresholdBindingFactor0"),
+            threshold_key.to_address().to_x_coordinate(),
+            Field::from_u64(message.len() as u64),
+        ];
+        preimage.extend(message);
+        for commitments in &commitments {
+            preimage.push(Field::from_u16(commitments.index));
+            preimage.extend([commitments.hiding, commitments.binding].map(|point| point.to_x_coordinate()));
+            for (hiding, binding, gamma) in &commitments.generator_commitments {
+                preimage.extend([hiding, binding, gamma].map(|point| point.to_x_coordinate()));
+            }
+        }
+        let commitments_hash = N::hash_psd8(&preimage)?;
+
+        // Compute the binding factor of each signer as `HashToScalar(commitments_hash || index)`.
+        let binding_factors = commitments
+            .iter()
+            .map(|commitments| N::hash_to_scalar_psd2(&[commitments_hash, Field::from_u16(commitments.index)]))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the Lagrange coefficient of each signer.
+        let participants = commitments.iter().map(|commitments| commitments.index).collect_vec();
+        let lagrange_coefficients =
+            participants.iter().map(|index| lagrange_coefficient(*index, &participants)).collect::<Result<Vec<_>>>()?;
+
+        // Compute the nonce commitment as `sum_i (D_i + rho_i * E_i)`.
+        let nonce_commitment = commitments
+            .iter()
+            .zip(&binding_factors)
+            .map(|(commitments, binding_factor)| commitments.hiding + commitments.binding * binding_factor)
+            .sum();
+        // Compute the nonce commitment and gamma for each additional generator.
+        let (generator_commitments, gammas) = (0..num_generators)
+            .map(|k| {
+                commitments.iter().zip(&binding_factors).zip(&lagrange_coefficients).fold(
+                    (Group::zero(), Group::zero()),
+                    |(h_r, gamma), ((commitments, binding_factor), lagrange_coefficient)| {
+                        let (hiding, binding, gamma_i) = commitments.generator_commitments[k];
+                        (h_r + hiding + binding * binding_factor, gamma + gamma_i * lagrange_coefficient)
+                    },
+                )
+            })
+            .unzip();
+
+        Ok(Self {
+            generators: generators.to_vec(),
+            commitments,
+            binding_factors,
+            lagrange_coefficients,
+            nonce_commitment,
+            generator_commitments,
+            gammas,
+        })
+    }
+
+    /// Returns the additional generators `H`.
+    pub fn generators(&self) -> &[Group<N>] {
+        &self.generators
+    }
+
+    /// Returns the signing commitments, ordered by participant index.
+    pub fn commitments(&self) -> &[SigningCommitments<N>] {
+        &self.commitments
+    }
+
+    /// Returns the nonce commitment `r * G`.
+    pub const fn nonce_commitment(&self) -> Group<N> {
+        self.nonce_commitment
+    }
+
+    /// Returns the nonce commitment `r * H` for each additional generator `H`.
+    pub fn generator_commitments(&self) -> &[Group<N>] {
+        &self.generator_commitments
+    }
+
+    /// Returns the value `sk_sig * H` for each additional generator `H`.
+    pub fn gammas(&self) -> &[Group<N>] {
+        &self.gammas
+    }
+
+    /// Returns the position of the given signer.
+    fn position(&self, index: u16) -> Result<usize> {
+        match self.commitments.iter().position(|commitments| commitments.index == index) {
+            Some(position) => Ok(position),
+            None => bail!("Participant {index} is not a signer in the signing package"),
+        }
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the signing nonces and commitments of this participant for a new signing session,
+    /// with commitments for each of the given additional generators.
+    pub fn commit<R: Rng + CryptoRng>(
+        &self,
+        generators: &[Group<N>],
+        rng: &mut R,
+    ) -> (SigningNonces<N>, SigningCommitments<N>) {
+        // Sample the hiding and binding nonces.
+        let nonces = SigningNonces { hiding: Scalar::rand(rng), binding: Scalar::rand(rng) };
+        // Compute the commitments.
+        let commitments = SigningCommitments {
+            index: self.index,
+            hiding: N::g_scalar_multiply(&nonces.hiding),
+            binding: N::g_scalar_multiply(&nonces.binding),
+            generator_commitments: generators
+                .iter()
+                .map(|h| (*h * nonces.hiding, *h * nonces.binding, *h * self.signing_share))
+                .collect(),
+        };
+        (nonces, commitments)
+    }
+
+    /// Returns the signature share of this participant for the given signing package and challenge, as:
+    ///     response_i := d_i + rho_i * e_i - lambda_i * challenge * s_i
+    pub fn sign_share(
+        &self,
+        nonces: SigningNonces<N>,
+        package: &SigningPackage<N>,
+        challenge: Scalar<N>,
+    ) -> Result<SignatureShare<N>> {
+        // Retrieve the commitments of this participant.
+        let position = package.position(self.index)?;
+        let commitments = &package.commitments[position];
+        // Ensure the nonces correspond to the commitments.
+        ensure!(
+            N::g_scalar_multiply(&nonces.hiding) == commitments.hiding
+                && N::g_scalar_multiply(&nonces.binding) == commitments.binding,
+            "The signing nonces do not match the commitments of participant {}",
+            self.index
+        );
+
+        // Compute the response share.
+        let response = nonces.hiding + nonces.binding * package.binding_factors[position]
+            - package.lagrange_coefficients[position] * challenge * self.signing_share;
+        Ok(SignatureShare { index: self.index, response })
+    }
+
+    /// Returns the signature share of this participant for the given message and signing commitments.
+    pub fn sign(
+        &self,
+        nonces: SigningNonces<N>,
+        message: &[Field<N>],
+        commitments: &[SigningCommitments<N>],
+    ) -> Result<SignatureShare<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Construct the signing package.
+        let package = SigningPackage::new(&self.threshold_key, &[], commitments, message)?;
+        // Compute the challenge.
+        let challenge = self.threshold_key.to_challenge(package.nonce_commitment, message)?;
+        // Compute the signature share.
+        self.sign_share(nonces, &package, challenge)
+    }
+}
+
+impl<N: Network> ThresholdKey<N> {
+    /// Returns the challenge as `HashToScalar(r * G, pk_sig, pr_sig, address, message)`.
+    fn to_challenge(&self, nonce_commitment: Group<N>, message: &[Field<N>]) -> Result<Scalar<N>> {
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend(
+            [nonce_commitment, self.compute_key.pk_sig(), self.compute_key.pr_sig(), *self.to_address()]
+                .map(|point| point.to_x_coordinate()),
+        );
+        preimage.extend(message);
+        // Compute the verifier challenge.
+        N::hash_to_scalar_psd8(&preimage)
+    }
+
+    /// Verifies the signature shares for the given signing package and challenge, and returns the response,
+    /// where each signature share is checked as:
+    ///     response_i * G == D_i + rho_i * E_i - lambda_i * challenge * Y_i
+    /// and the generator commitments `(d_i * H, e_i * H, s_i * H)` of each signer are checked as:
+    ///     response_i * H == d_i * H + rho_i * e_i * H - lambda_i * challenge * s_i * H
+    /// As the binding factor and challenge are derived after the commitments, this ensures each `s_i * H`
+    /// is consistent with the verifying share `Y_i`, and an invalid one identifies its signer.
+    pub fn aggregate_shares(
+        &self,
+        package: &SigningPackage<N>,
+        challenge: Scalar<N>,
+        shares: &[SignatureShare<N>],
+    ) -> Result<Scalar<N>> {
+        // Ensure there is a signature share from each signer.
+        ensure!(
+            shares.len() == package.commitments.len() && shares.iter().map(|share| share.index).all_unique(),
+            "Expected exactly one signature share from each of the {} signers",
+            package.commitments.len()
+        );
+
+        let mut response = Scalar::zero();
+        for share in shares {
+            // Retrieve the commitments of the signer.
+            let position = package.position(share.index)?;
+            let commitments = &package.commitments[position];
+            // Compute the expected commitment of the signature share.
+            let expected = commitments.hiding + commitments.binding * package.binding_factors[position]
+                - self.verifying_share(share.index)? * (package.lagrange_coefficients[position] * challenge);
+            // Ensure the signature share is valid.
+            ensure!(N::g_scalar_multiply(&share.response) == expected, "Invalid signature share from {}", share.index);
+            // Ensure the generator commitments of the signer are consistent with the signature share.
+            for (h, (hiding, binding, gamma)) in package.generators.iter().zip_eq(&commitments.generator_commitments) {
+                let expected = *hiding + *binding * package.binding_factors[position]
+                    - *gamma * (package.lagrange_coefficients[position] * challenge);
+                ensure!(*h * share.response == expected, "Invalid generator commitments from {}", share.index);
+            }
+            // Accumulate the response.
+            response += share.response;
+        }
+        Ok(response)
+    }
+
+    /// Aggregates the signature shares of the signers into a signature for the given message.
+    pub fn aggregate(
+        &self,
+        message: &[Field<N>],
+        commitments: &[SigningCommitments<N>],
+        shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        // Construct the signing package.
+        let package = SigningPackage::new(self, &[], commitments, message)?;
+        // Compute the challenge.
+        let challenge = self.to_challenge(package.nonce_commitment, message)?;
+        // Aggregate the signature shares.
+        let response = self.aggregate_shares(&package, challenge, shares)?;
+
+        // Construct the signature.
+        let signature = Signature::from((challenge, response, self.compute_key));
+        // Ensure the signature is valid.
+        ensure!(signature.verify(&self.to_address(), message), "Failed to aggregate a valid signature");
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Signs the given message with the given signers, and returns the aggregated signature.
+    fn threshold_sign(
+        signers: &[&KeyShare<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<Signature<CurrentNetwork>> {
+        // Round 1: Each signer commits to its nonces.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(&[], rng)).unzip();
+        // Round 2: Each signer signs the message.
+        let shares = signers
+            .iter()
+            .zip(nonces)
+            .map(|(signer, nonces)| signer.sign(nonces, message, &commitments))
+            .collect::<Result<Vec<_>>>()?;
+        // Aggregate the signature shares.
+        signers[0].threshold_key().aggregate(message, &commitments, &shares)
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let (private_key, key_shares) = test_helpers::sample_key_shares(threshold, num_participants, rng);
+            let address = Address::try_from(&private_key)?;
+
+            // Ensure every subset of `threshold` or more participants produces a valid signature.
+            for size in threshold..=num_participants {
+                for signers in key_shares.iter().combinations(size as usize) {
+                    let message = (0..size).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+                    let signature = threshold_sign(&signers, &message, rng)?;
+                    assert!(signature.verify(&address, &message));
+                    assert!(!signature.verify(&address, &[Field::rand(rng)]));
+                }
+            }
+
+            // Ensure fewer than `threshold` participants cannot sign.
+            if threshold > 1 {
+                let signers = key_shares.iter().take(threshold as usize - 1).collect::<Vec<_>>();
+                assert!(threshold_sign(&signers, &[Field::rand(rng)], rng).is_err());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_signature_share() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a 2-of-3 account.
+        let (_, key_shares) = test_helpers::sample_key_shares(2, 3, rng);
+        let message = [Field::rand(rng)];
+
+        // Round 1: Each signer commits to its nonces.
+        let (nonces_0, commitments_0) = key_shares[0].commit(&[], rng);
+        let (nonces_2, commitments_2) = key_shares[2].commit(&[], rng);
+        let commitments = [commitments_0, commitments_2];
+
+        // Ensure a signer cannot sign with the nonces of another session.
+        let (other_nonces, _) = key_shares[0].commit(&[], rng);
+        assert!(key_shares[0].sign(other_nonces, &message, &commitments).is_err());
+        // Ensure a participant that did not commit cannot sign.
+        let (other_nonces, _) = key_shares[1].commit(&[], rng);
+        assert!(key_shares[1].sign(other_nonces, &message, &commitments).is_err());
+
+        // Round 2: Each signer signs the message.
+        let share_0 = key_shares[0].sign(nonces_0, &message, &commitments)?;
+        let share_2 = key_shares[2].sign(nonces_2, &message, &commitments)?;
+
+        // Ensure a tampered signature share is rejected.
+        let tampered = SignatureShare::from(share_2.index(), share_2.response() + Scalar::one());
+        assert!(key_shares[0].threshold_key().aggregate(&message, &commitments, &[share_0, tampered]).is_err());
+        // Ensure a missing signature share is rejected.
+        assert!(key_shares[0].threshold_key().aggregate(&message, &commitments, &[share_0]).is_err());
+        // Ensure the valid signature shares aggregate.
+        assert!(key_shares[0].threshold_key().aggregate(&message, &commitments, &[share_0, share_2]).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_generator_commitments() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a 2-of-3 account.
+        let (_, key_shares) = test_helpers::sample_key_shares(2, 3, rng);
+        let threshold_key = key_shares[0].threshold_key();
+        let (message, generators) = ([Field::rand(rng)], [Group::rand(rng), Group::rand(rng)]);
+
+        // Round 1: Each signer commits to its nonces, and to the generators.
+        let (nonces_0, commitments_0) = key_shares[0].commit(&generators, rng);
+        let (nonces_2, commitments_2) = key_shares[2].commit(&generators, rng);
+        // Tamper with the gamma `s_i * H` of the second generator of signer 3.
+        let mut generator_commitments = commitments_2.generator_commitments().to_vec();
+        generator_commitments[1].2 += generators[1];
+        let tampered =
+            SigningCommitments::from(3, commitments_2.hiding(), commitments_2.binding(), generator_commitments);
+
+        // Ensure the signers must commit to each of the generators.
+        let commitments = [commitments_0.clone(), commitments_2.clone()];
+        assert!(SigningPackage::new(threshold_key, &generators[..1], &commitments, &message).is_err());
+
+        // Round 2: Each signer computes its signature share.
+        let commitments = [commitments_0, tampered];
+        let package = SigningPackage::new(threshold_key, &generators, &commitments, &message)?;
+        let challenge = Scalar::rand(rng);
+        let shares = [
+            key_shares[0].sign_share(nonces_0, &package, challenge)?,
+            key_shares[2].sign_share(nonces_2, &package, challenge)?,
+        ];
+
+        // Ensure the tampered gamma is rejected, and identifies its signer.
+        let error = threshold_key.aggregate_shares(&package, challenge, &shares).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid generator commitments from 3");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_after_refresh() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a 2-of-3 account.
+        let (private_key, key_shares) = test_helpers::sample_key_shares(2, 3, rng);
+        // Refresh the key shares.
+        let (commitments, deltas): (Vec<_>, Vec<_>) = key_shares.iter().map(|share| share.prepare_refresh(rng)).unzip();
+        let refreshed = test_helpers::refresh_key_shares(&key_shares, &commitments, &deltas)?;
+
+        // Ensure the refreshed key shares sign for the same account.
+        let message = [Field::rand(rng)];
+        let signature = threshold_sign(&[&refreshed[1], &refreshed[2]], &message, rng)?;
+        assert!(signature.verify(&Address::try_from(&private_key)?, &message));
+        // Ensure the refreshed key shares cannot be combined with the previous key shares.
+        assert!(threshold_sign(&[&key_shares[0], &refreshed[2]], &message, rng).is_err());
+        Ok(())
+    }
+}
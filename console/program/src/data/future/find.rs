@@ -51,6 +51,14 @@ impl<N: Network> Future<N> {
                         None => bail!("Index '{index}' is out of bounds"),
                     }
                 }
+                (ArgumentRefType::Plaintext(Plaintext::Enum(variant, payload, ..)), Access::Member(identifier)) => {
+                    match *variant == identifier {
+                        // Retrieve the payload and update `value` for the next iteration.
+                        true => value = ArgumentRefType::Plaintext(payload),
+                        // Halts if the enum is not of the given variant.
+                        false => bail!("Failed to locate variant '{identifier}'"),
+                    }
+                }
                 (ArgumentRefType::Future(future), Access::Index(index)) => {
                     match future.arguments.get(*index as usize) {
                         // If the argument is a future, update `value` for the next iteration.
@@ -28,6 +28,7 @@ mod size_in_bytes;
 mod to_bits;
 mod to_type;
 mod variant;
+mod zero;
 
 use crate::{LiteralType, ProgramID};
 use snarkvm_console_account::{ComputeKey, PrivateKey, Signature};
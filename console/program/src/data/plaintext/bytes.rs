@@ -65,7 +65,51 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the array.
                 Self::Array(elements, Default::default())
             }
-            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            3 => {
+                // Read the variant.
+                let variant = Identifier::<N>::read_le(&mut reader)?;
+                // Read the payload (in 2 steps to prevent infinite recursion).
+                let num_bytes = u16::read_le(&mut reader)?;
+                // Read the payload bytes.
+                let mut bytes = Vec::new();
+                (&mut reader).take(num_bytes as u64).read_to_end(&mut bytes)?;
+                // Recover the payload.
+                let payload = Plaintext::read_le(&mut bytes.as_slice())?;
+                // Return the enum.
+                Self::Enum(variant, Box::new(payload), Default::default())
+            }
+            4 => {
+                // Read the length of the vector.
+                let length = U32::<N>::read_le(&mut reader)?;
+                // Read the number of elements in the vector.
+                let num_elements = u32::read_le(&mut reader)?;
+                if num_elements as usize > N::MAX_ARRAY_ELEMENTS {
+                    return Err(error("Failed to deserialize plaintext: Vector exceeds maximum length"));
+                }
+                if *length > num_elements {
+                    return Err(error("Failed to deserialize plaintext: Vector length exceeds its capacity"));
+                }
+                // Read the elements.
+                let mut elements = Vec::with_capacity(num_elements as usize);
+                for _ in 0..num_elements {
+                    // Read the plaintext value (in 2 steps to prevent infinite recursion).
+                    let num_bytes = u16::read_le(&mut reader)?;
+                    // Read the plaintext bytes.
+                    let mut bytes = Vec::new();
+                    (&mut reader).take(num_bytes as u64).read_to_end(&mut bytes)?;
+                    // Recover the plaintext value.
+                    let plaintext = Plaintext::read_le(&mut bytes.as_slice())?;
+                    // Add the element.
+                    elements.push(plaintext);
+                }
+                // Ensure the elements beyond the length are zero.
+                if !Plaintext::is_zero_padded(&elements, *length) {
+                    return Err(error("Failed to deserialize plaintext: Vector has nonzero padding"));
+                }
+                // Return the vector.
+                Self::Vector(elements, length, Default::default())
+            }
+            5.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -116,6 +160,36 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Enum(variant, payload, ..) => {
+                3u8.write_le(&mut writer)?;
+                // Write the variant.
+                variant.write_le(&mut writer)?;
+                // Write the payload (performed in 2 steps to prevent infinite recursion).
+                let bytes = payload.to_bytes_le().map_err(error)?;
+                // Write the number of bytes.
+                u16::try_from(bytes.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the bytes.
+                bytes.write_le(&mut writer)
+            }
+            Self::Vector(elements, length, ..) => {
+                4u8.write_le(&mut writer)?;
+
+                // Write the length of the vector.
+                length.write_le(&mut writer)?;
+                // Write the number of elements in the vector.
+                u32::try_from(elements.len()).map_err(error)?.write_le(&mut writer)?;
+
+                // Write each element.
+                for element in elements {
+                    // Write the element (performed in 2 steps to prevent infinite recursion).
+                    let bytes = element.to_bytes_le().map_err(error)?;
+                    // Write the number of bytes.
+                    u16::try_from(bytes.len()).map_err(error)?.write_le(&mut writer)?;
+                    // Write the bytes.
+                    bytes.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -232,6 +306,20 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
 
+        // Check the vector manually.
+        let expected = Plaintext::<CurrentNetwork>::from_str("vector[1u8, 2u8, 0u8; 2u32]")?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+
+        // Ensure a vector with nonzero padding is rejected.
+        let Plaintext::Vector(mut elements, length, _) = expected else { unreachable!() };
+        elements[2] = Plaintext::from_str("3u8")?;
+        let candidate = Plaintext::Vector(elements, length, Default::default());
+        assert!(Plaintext::<CurrentNetwork>::read_le(&candidate.to_bytes_le()?[..]).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&candidate.to_bits_le()).is_err());
+
         Ok(())
     }
 }
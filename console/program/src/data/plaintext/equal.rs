@@ -48,7 +48,25 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(false),
+            (Self::Enum(variant_a, a, _), Self::Enum(variant_b, b, _)) => variant_a.is_equal(variant_b) & a.is_equal(b),
+            (Self::Vector(a, length_a, _), Self::Vector(b, length_b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each element within the length for equality.
+                    length_a.is_equal(length_b)
+                        & Boolean::new(
+                            a.iter()
+                                .zip_eq(b.iter())
+                                .take(**length_a as usize)
+                                .all(|(plaintext_a, plaintext_b)| *plaintext_a.is_equal(plaintext_b)),
+                        )
+                }
+                false => Boolean::new(false),
+            },
+            (Self::Literal(..), _)
+            | (Self::Struct(..), _)
+            | (Self::Array(..), _)
+            | (Self::Enum(..), _)
+            | (Self::Vector(..), _) => Boolean::new(false),
         }
     }
 
@@ -76,7 +94,27 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(true),
+            (Self::Enum(variant_a, a, _), Self::Enum(variant_b, b, _)) => {
+                variant_a.is_not_equal(variant_b) | a.is_not_equal(b)
+            }
+            (Self::Vector(a, length_a, _), Self::Vector(b, length_b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each element within the length for equality.
+                    length_a.is_not_equal(length_b)
+                        | Boolean::new(
+                            a.iter()
+                                .zip_eq(b.iter())
+                                .take(**length_a as usize)
+                                .any(|(plaintext_a, plaintext_b)| *plaintext_a.is_not_equal(plaintext_b)),
+                        )
+                }
+                false => Boolean::new(true),
+            },
+            (Self::Literal(..), _)
+            | (Self::Struct(..), _)
+            | (Self::Array(..), _)
+            | (Self::Enum(..), _)
+            | (Self::Vector(..), _) => Boolean::new(true),
         }
     }
 }
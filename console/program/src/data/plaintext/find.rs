@@ -24,7 +24,7 @@ impl<N: Network> Plaintext<N> {
             // Halts if the value is not a struct.
             Self::Literal(..) => bail!("'{self}' is not a struct"),
             // Retrieve the value of the member (from the value).
-            Self::Struct(..) | Self::Array(..) => {
+            Self::Struct(..) | Self::Array(..) | Self::Enum(..) | Self::Vector(..) => {
                 // Initialize the plaintext starting from the top-level.
                 let mut plaintext = self;
 
@@ -48,6 +48,14 @@ impl<N: Network> Plaintext<N> {
                                 None => bail!("Index '{index}' for '{self}' is out of bounds"),
                             }
                         }
+                        (Self::Enum(variant, payload, ..), Access::Member(identifier)) => {
+                            match *variant == identifier {
+                                // Retrieve the payload and update `plaintext` for the next iteration.
+                                true => plaintext = payload,
+                                // Halts if the enum is not of the given variant.
+                                false => bail!("'{self}' is not of the variant '{identifier}'"),
+                            }
+                        }
                         _ => bail!("Invalid access `{access}` for `{plaintext}`"),
                     }
                 }
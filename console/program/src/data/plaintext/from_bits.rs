@@ -90,6 +90,43 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_le.to_vec())))
         }
+        // Enum (with a trailing `false` variant bit)
+        else if variant == [true, true] && !next_bits(1)?[0] {
+            let identifier_size = u8::from_bits_le(next_bits(8)?)?;
+            let variant = Identifier::from_bits_le(next_bits(identifier_size as usize)?)?;
+
+            let payload_size = u16::from_bits_le(next_bits(16)?)?;
+            let payload = Plaintext::from_bits_le(next_bits(payload_size as usize)?)?;
+
+            // Cache the plaintext bits, and return the enum.
+            Ok(Self::Enum(variant, Box::new(payload), OnceCell::with_value(bits_le.to_vec())))
+        }
+        // Vector (with a trailing `true` variant bit)
+        else if variant == [true, true] {
+            let length = U32::new(u32::from_bits_le(next_bits(32)?)?);
+            let num_elements = u32::from_bits_le(next_bits(32)?)?;
+            if num_elements as usize > N::MAX_ARRAY_ELEMENTS {
+                bail!("Vector exceeds maximum of elements.");
+            }
+            if *length > num_elements {
+                bail!("Vector length exceeds its capacity.");
+            }
+
+            let mut elements = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = u16::from_bits_le(next_bits(16)?)?;
+                let element = Plaintext::from_bits_le(next_bits(element_size as usize)?)?;
+
+                elements.push(element);
+            }
+            // Ensure the elements beyond the length are zero.
+            if !Plaintext::is_zero_padded(&elements, *length) {
+                bail!("Vector has nonzero padding.");
+            }
+
+            // Cache the plaintext bits, and return the vector.
+            Ok(Self::Vector(elements, length, OnceCell::with_value(bits_le.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
@@ -171,6 +208,43 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_be.to_vec())))
         }
+        // Enum (with a trailing `false` variant bit)
+        else if variant == [true, true] && !next_bits(1)?[0] {
+            let identifier_size = u8::from_bits_be(next_bits(8)?)?;
+            let variant = Identifier::from_bits_be(next_bits(identifier_size as usize)?)?;
+
+            let payload_size = u16::from_bits_be(next_bits(16)?)?;
+            let payload = Plaintext::from_bits_be(next_bits(payload_size as usize)?)?;
+
+            // Cache the plaintext bits, and return the enum.
+            Ok(Self::Enum(variant, Box::new(payload), OnceCell::with_value(bits_be.to_vec())))
+        }
+        // Vector (with a trailing `true` variant bit)
+        else if variant == [true, true] {
+            let length = U32::new(u32::from_bits_be(next_bits(32)?)?);
+            let num_elements = u32::from_bits_be(next_bits(32)?)?;
+            if num_elements as usize > N::MAX_ARRAY_ELEMENTS {
+                bail!("Vector exceeds maximum of elements.");
+            }
+            if *length > num_elements {
+                bail!("Vector length exceeds its capacity.");
+            }
+
+            let mut elements = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = u16::from_bits_be(next_bits(16)?)?;
+                let element = Plaintext::from_bits_be(next_bits(element_size as usize)?)?;
+
+                elements.push(element);
+            }
+            // Ensure the elements beyond the length are zero.
+            if !Plaintext::is_zero_padded(&elements, *length) {
+                bail!("Vector has nonzero padding.");
+            }
+
+            // Cache the plaintext bits, and return the vector.
+            Ok(Self::Vector(elements, length, OnceCell::with_value(bits_be.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
@@ -24,10 +24,11 @@ mod serialize;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
+mod zero;
 
 use crate::{Access, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
-use snarkvm_console_types::prelude::*;
+use snarkvm_console_types::{prelude::*, U32};
 
 use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
@@ -40,6 +41,10 @@ pub enum Plaintext<N: Network> {
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
     /// An array.
     Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An enum variant, with its payload.
+    Enum(Identifier<N>, Box<Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// A vector, with its elements padded to the maximum length, and its length.
+    Vector(Vec<Plaintext<N>>, U32<N>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -208,6 +213,30 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test an enum with a literal payload.
+        run_test(Plaintext::<CurrentNetwork>::Enum(
+            Identifier::from_str("circle")?,
+            Box::new(Plaintext::<CurrentNetwork>::from_str("5u64")?),
+            OnceCell::new(),
+        ));
+
+        // Test an array of enums with struct payloads.
+        run_test(Plaintext::<CurrentNetwork>::Array(
+            vec![
+                Plaintext::<CurrentNetwork>::from_str("square({ width: 2u64, height: 3u64 })")?,
+                Plaintext::<CurrentNetwork>::from_str("circle(5u64)")?,
+            ],
+            OnceCell::new(),
+        ));
+
+        // Test a vector of literals.
+        run_test(Plaintext::<CurrentNetwork>::from_str("vector[1u8, 2u8, 0u8, 0u8; 2u32]")?);
+
+        // Test a struct with a vector of structs.
+        run_test(Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: 1field, points: vector[{ x: 1u8, y: 2u8 }, { x: 0u8, y: 0u8 }; 1u32] }",
+        )?);
+
         Ok(())
     }
 }
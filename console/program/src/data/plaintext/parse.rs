@@ -78,12 +78,75 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Array(members, Default::default())))
         }
 
+        /// Parses a plaintext as a literal, that is not the prefix of an enum variant (i.e. `true_case(5u64)`).
+        fn parse_literal<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the literal from the string.
+            let (remainder, literal) = Literal::parse(string)?;
+            // Ensure the literal is not followed by the remainder of an identifier.
+            if remainder.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+                return fail(string);
+            }
+            // Output the plaintext.
+            Ok((remainder, Plaintext::Literal(literal, Default::default())))
+        }
+
+        /// Parses a plaintext as an enum: `variant(plaintext)`.
+        fn parse_enum<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the variant from the string.
+            let (string, variant) = Identifier::parse(string)?;
+            // Parse the "(" from the string.
+            let (string, _) = tag("(")(string)?;
+            // Parse the payload from the string.
+            let (string, payload) = Plaintext::parse(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ")" from the string.
+            let (string, _) = tag(")")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Enum(variant, Box::new(payload), Default::default())))
+        }
+
+        /// Parses a plaintext as a vector: `vector[plaintext_0, ..., plaintext_n; length]`.
+        fn parse_vector<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the "vector[" from the string.
+            let (string, _) = tag("vector[")(string)?;
+            // Parse the elements.
+            let (string, elements) = separated_list1(tag(","), Plaintext::parse)(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ";" from the string.
+            let (string, _) = tag(";")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the length, and ensure it is within the number of elements.
+            let (string, length) = map_res(U32::parse, |length: U32<N>| {
+                if *length as usize > elements.len() || elements.len() > N::MAX_ARRAY_ELEMENTS {
+                    return Err(error(format!("Found a vector of length {length} with {} elements", elements.len())));
+                }
+                // Ensure the elements beyond the length are zero.
+                match Plaintext::is_zero_padded(&elements, *length) {
+                    true => Ok(length),
+                    false => Err(error(format!("Found a vector of length {length} with nonzero padding"))),
+                }
+            })(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Vector(elements, length, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
         alt((
+            // Parse a plaintext vector.
+            parse_vector,
             // Parse a plaintext literal.
-            map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
+            parse_literal,
+            // Parse a plaintext enum.
+            parse_enum,
             // Parse a plaintext struct.
             parse_struct,
             // Parse a plaintext array.
@@ -149,7 +212,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Enum(..) | Self::Vector(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -182,7 +245,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Enum(..) | Self::Vector(..) => {
                             // Print a newline.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -198,6 +261,38 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the enum, i.e. circle(10u64)
+            Self::Enum(variant, payload, ..) => match &**payload {
+                // Print the literal payload on the same line.
+                Self::Literal(literal, ..) => write!(f, "{variant}({literal})"),
+                _ => {
+                    // Print the variant.
+                    write!(f, "{variant}(")?;
+                    // Print the payload.
+                    payload.fmt_internal(f, depth)?;
+                    // Print the closing parenthesis.
+                    write!(f, ")")
+                }
+            },
+            // Prints the vector, i.e. vector[10u64, 198u64, 0u64; 2u32]
+            Self::Vector(elements, length, ..) => {
+                // Print the opening bracket.
+                write!(f, "vector[")?;
+                // Print the elements.
+                elements.iter().enumerate().try_for_each(|(i, plaintext)| {
+                    // Print the separator.
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match plaintext {
+                        // Print the literal element on the same line.
+                        Self::Literal(literal, ..) => write!(f, "{literal}"),
+                        _ => plaintext.fmt_internal(f, depth),
+                    }
+                })?;
+                // Print the length and the closing bracket.
+                write!(f, "; {length}]")
+            }
         }
     }
 }
@@ -264,6 +359,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_enum() -> Result<()> {
+        // Sanity check.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("circle( 5u64 )")?;
+        assert_eq!("circle(5u64)", candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Test an enum with a struct payload, nested in a struct.
+        let expected = r"{
+  shape: square({
+    width: 2u64,
+    height: 3u64
+  }),
+  color: 1u8
+}";
+        let (remainder, candidate) =
+            Plaintext::<CurrentNetwork>::parse("{ shape: square({ width: 2u64, height: 3u64 }), color: 1u8 }")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        assert_eq!(candidate, Plaintext::<CurrentNetwork>::from_str(expected)?);
+
+        // Ensure a variant that is prefixed by a literal is parsed as an enum.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("true_case(true)")?;
+        assert_eq!("true_case(true)", candidate.to_string());
+        assert_eq!("", remainder);
+        // Ensure a literal is not parsed as an enum.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("true, 5u64")?;
+        assert!(matches!(candidate, Plaintext::Literal(Literal::Boolean(..), _)));
+        assert_eq!(", 5u64", remainder);
+
+        // Ensure the payload is required.
+        assert!(Plaintext::<CurrentNetwork>::from_str("circle()").is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("circle(5u64").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_vector() -> Result<()> {
+        // Sanity check.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("vector[ 1u8, 2u8,0u8 ;2u32 ]")?;
+        assert_eq!("vector[1u8, 2u8, 0u8; 2u32]", candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure the length does not exceed the number of elements.
+        assert!(Plaintext::<CurrentNetwork>::parse("vector[1u8, 2u8; 3u32]").is_err());
+        // Ensure the length is required.
+        assert!(Plaintext::<CurrentNetwork>::from_str("vector[1u8, 2u8]").is_err());
+
+        // Ensure the elements beyond the length are zero.
+        assert!(Plaintext::<CurrentNetwork>::from_str("vector[1u8, 2u8, 3u8; 2u32]").is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("vector[{ x: 1u8 }, { x: 2u8 }; 1u32]").is_err());
+        let a = Plaintext::<CurrentNetwork>::from_str("vector[1u8, 2u8, 0u8; 2u32]")?;
+        assert_ne!(a, Plaintext::<CurrentNetwork>::from_str("vector[1u8, 2u8, 0u8; 3u32]")?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
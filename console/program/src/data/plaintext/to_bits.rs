@@ -85,6 +85,56 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Enum(variant, payload, bits_le) => {
+                // Compute the bits.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![true, true, false]; // Variant bits.
+
+                    // Write the identifier of the variant.
+                    variant.size_in_bits().write_bits_le(&mut bits_le);
+                    variant.write_bits_le(&mut bits_le);
+
+                    // Write the payload of the variant.
+                    let payload_bits = payload.to_bits_le();
+                    u16::try_from(payload_bits.len())
+                        .or_halt_with::<N>("Plaintext payload exceeds u16::MAX bits")
+                        .write_bits_le(&mut bits_le);
+                    bits_le.extend(payload_bits);
+                    bits_le
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
+            Self::Vector(elements, length, bits_le) => {
+                // Compute the bits.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![true, true, true]; // Variant bits.
+
+                    // Write the length of the vector.
+                    length.write_bits_le(&mut bits_le);
+
+                    // Write the number of elements in the vector.
+                    u32::try_from(elements.len())
+                        .or_halt_with::<N>("Plaintext vector length exceeds u32::MAX")
+                        .write_bits_le(&mut bits_le);
+
+                    // Write each element of the vector.
+                    for element in elements {
+                        let element_bits = element.to_bits_le();
+
+                        // Write the size of the element.
+                        u16::try_from(element_bits.len())
+                            .or_halt_with::<N>("Plaintext element exceeds u16::MAX bits")
+                            .write_bits_le(&mut bits_le);
+
+                        // Write the element.
+                        bits_le.extend(element_bits);
+                    }
+                    bits_le
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 
@@ -159,6 +209,56 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Enum(variant, payload, bits_be) => {
+                // Compute the bits.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![true, true, false]; // Variant bits.
+
+                    // Write the identifier of the variant.
+                    variant.size_in_bits().write_bits_be(&mut bits_be);
+                    variant.write_bits_be(&mut bits_be);
+
+                    // Write the payload of the variant.
+                    let payload_bits = payload.to_bits_be();
+                    u16::try_from(payload_bits.len())
+                        .or_halt_with::<N>("Plaintext payload exceeds u16::MAX bits")
+                        .write_bits_be(&mut bits_be);
+                    bits_be.extend(payload_bits);
+                    bits_be
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
+            Self::Vector(elements, length, bits_be) => {
+                // Compute the bits.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![true, true, true]; // Variant bits.
+
+                    // Write the length of the vector.
+                    length.write_bits_be(&mut bits_be);
+
+                    // Write the number of elements in the vector.
+                    u32::try_from(elements.len())
+                        .or_halt_with::<N>("Plaintext vector length exceeds u32::MAX")
+                        .write_bits_be(&mut bits_be);
+
+                    // Write each element of the vector.
+                    for element in elements {
+                        let element_bits = element.to_bits_be();
+
+                        // Write the size of the element.
+                        u16::try_from(element_bits.len())
+                            .or_halt_with::<N>("Plaintext element exceeds u16::MAX bits")
+                            .write_bits_be(&mut bits_be);
+
+                        // Write the element.
+                        bits_be.extend(element_bits);
+                    }
+                    bits_be
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the zero plaintext with the same shape as this plaintext, which pads vectors.
+    pub fn to_zero(&self) -> Result<Self> {
+        match self {
+            Self::Literal(literal, ..) => Ok(Self::from(Literal::zero(literal.to_type())?)),
+            Self::Struct(members, ..) => Ok(Self::Struct(
+                members.iter().map(|(name, member)| Ok((*name, member.to_zero()?))).collect::<Result<_>>()?,
+                Default::default(),
+            )),
+            Self::Array(elements, ..) => Ok(Self::Array(
+                elements.iter().map(|element| element.to_zero()).collect::<Result<_>>()?,
+                Default::default(),
+            )),
+            Self::Enum(..) => bail!("An enum does not have a zero value"),
+            Self::Vector(..) => bail!("A vector does not have a zero value"),
+        }
+    }
+
+    /// Returns `true` if the plaintext is zero, i.e. every literal in it is zero.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Self::Literal(literal, ..) => literal.is_zero(),
+            Self::Struct(members, ..) => members.values().all(|member| member.is_zero()),
+            Self::Array(elements, ..) => elements.iter().all(|element| element.is_zero()),
+            Self::Enum(..) | Self::Vector(..) => false,
+        }
+    }
+
+    /// Returns `true` if every element of a vector beyond its length is zero.
+    /// Note: Vectors must be zero-padded, so that their encodings are canonical.
+    pub fn is_zero_padded(elements: &[Self], length: u32) -> bool {
+        elements.iter().skip(length as usize).all(|element| element.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_zero() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [true, false], c: \"abc\" }")?;
+        let expected = Plaintext::<CurrentNetwork>::from_str("{ a: 0u8, b: [false, false], c: \"\" }")?;
+        assert!(!plaintext.is_zero());
+        assert_eq!(plaintext.to_zero()?, expected);
+        assert!(expected.is_zero());
+
+        // Ensure enums and vectors do not have a zero value.
+        let vector = Plaintext::<CurrentNetwork>::from_str("vector[0u8; 0u32]")?;
+        assert!(vector.to_zero().is_err());
+        assert!(!vector.is_zero());
+        Ok(())
+    }
+}
@@ -28,6 +28,24 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
     }
 
+    /// Decrypts `self` into plaintext using the given audit key, and checks that the audit key
+    /// is scoped to this record and that the owner matches the address of the audit key.
+    pub fn decrypt_with_audit_key(&self, audit_key: &AuditKey<N>) -> Result<Record<N, Plaintext<N>>> {
+        // Ensure the audit key is scoped to this record.
+        ensure!(audit_key.nonce() == self.nonce, "The audit key does not correspond to the record nonce");
+        // Ensure the audit key is derived from the view key of its address.
+        ensure!(audit_key.verify(), "The audit key is invalid");
+        // Decrypt the record.
+        let record = self.decrypt_symmetric_unchecked(&audit_key.to_record_view_key())?;
+        // Ensure the record owner matches the audit key.
+        match audit_key.address() == **record.owner() {
+            true => Ok(record),
+            false => {
+                bail!("Illegal operation: Record::decrypt_with_audit_key() address does not match the record owner.")
+            }
+        }
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::decrypt` for the checked variant.
@@ -130,6 +148,17 @@ mod tests {
         // Ensure that decrypting with the incorrect view key fails.
         assert!(ciphertext.decrypt(&incorrect_view_key).is_err());
 
+        // Derive an audit key for the record.
+        let audit_key = AuditKey::new(&view_key, *ciphertext.nonce(), rng)?;
+        // Decrypt the record with the audit key.
+        assert_eq!(record, ciphertext.decrypt_with_audit_key(&audit_key)?);
+        // Ensure that decrypting with an audit key of the incorrect view key fails.
+        let incorrect_audit_key = AuditKey::new(&incorrect_view_key, *ciphertext.nonce(), rng)?;
+        assert!(ciphertext.decrypt_with_audit_key(&incorrect_audit_key).is_err());
+        // Ensure that decrypting with an audit key for another record fails.
+        let other_audit_key = AuditKey::new(&view_key, Group::rand(rng), rng)?;
+        assert!(ciphertext.decrypt_with_audit_key(&other_audit_key).is_err());
+
         Ok(())
     }
 
@@ -40,6 +40,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Parse the plaintext and visibility from the string.
             let (string, (plaintext, mode)) = alt((
+                // Parse a vector.
+                parse_vector,
                 // Parse a literal.
                 parse_literal,
                 // Parse a struct.
@@ -62,6 +64,22 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             ))(string)
         }
 
+        /// Parses an entry as a vector: `vector[plaintext_0, ..., plaintext_n; length].visibility`.
+        fn parse_vector<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            // Parse the vector from the string.
+            let (string, plaintext) = map_res(Plaintext::parse, |plaintext| match plaintext {
+                Plaintext::Vector(..) => Ok(plaintext),
+                _ => Err(error("Expected a vector")),
+            })(string)?;
+            // Parse the visibility from the string.
+            alt((
+                map(tag(".constant"), |_| Mode::Constant),
+                map(tag(".public"), |_| Mode::Public),
+                map(tag(".private"), |_| Mode::Private),
+            ))(string)
+            .map(|(string, mode)| (string, (plaintext, mode)))
+        }
+
         /// Parses an entry as a struct: `{ identifier_0: plaintext_0.visibility, ..., identifier_n: plaintext_n.visibility }`.
         /// Observe the `visibility` is the same for all members of the plaintext value.
         fn parse_struct<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
@@ -111,7 +129,7 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             let (string, (elements, mode)) = map_res(
                 separated_list1(
                     pair(Sanitizer::parse_whitespaces, pair(tag(","), Sanitizer::parse_whitespaces)),
-                    alt((parse_literal, parse_struct, parse_array)),
+                    alt((parse_vector, parse_literal, parse_struct, parse_array)),
                 ),
                 |members: Vec<(Plaintext<N>, Mode)>| {
                     // Ensure the members all have the same visibility.
@@ -140,6 +158,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
         let (string, (plaintext, mode)) = alt((
+            // Parse a vector.
+            parse_vector,
             // Parse a literal.
             parse_literal,
             // Parse a struct.
@@ -223,7 +243,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Enum(..) | Plaintext::Vector(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -261,7 +281,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Enum(..) | Plaintext::Vector(..) => {
                             // Print a new line.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -281,6 +301,10 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                     }
                 })
             }
+            // Prints the enum, i.e. circle(10u64).public
+            Plaintext::Enum(..) => write!(f, "{plaintext}.{visibility}"),
+            // Prints the vector, i.e. vector[10u64, 0u64; 1u32].public
+            Plaintext::Vector(..) => write!(f, "{plaintext}.{visibility}"),
         }
     }
 }
@@ -153,10 +153,16 @@ impl<N: Network> Record<N, Plaintext<N>> {
             write!(f, "\n{:indent$}{identifier}: ", "", indent = (depth + 1) * INDENT)?;
             // Print the entry.
             match entry {
-                // If the entry is a literal, print the entry without indentation.
+                // If the entry is a literal or an enum, print the entry without indentation.
                 Entry::Constant(Plaintext::Literal(..))
                 | Entry::Public(Plaintext::Literal(..))
-                | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
+                | Entry::Private(Plaintext::Literal(..))
+                | Entry::Constant(Plaintext::Enum(..))
+                | Entry::Public(Plaintext::Enum(..))
+                | Entry::Private(Plaintext::Enum(..))
+                | Entry::Constant(Plaintext::Vector(..))
+                | Entry::Public(Plaintext::Vector(..))
+                | Entry::Private(Plaintext::Vector(..)) => write!(f, "{entry}")?,
                 // If the entry is a struct or an array, print the entry with indentation.
                 Entry::Constant(Plaintext::Struct(..))
                 | Entry::Public(Plaintext::Struct(..))
@@ -316,6 +322,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_vector_entry() -> Result<()> {
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public,
+  foo: vector[6u8, 7u8, 0u8; 2u32].private,
+  bar: {
+    baz: vector[1field; 1u32].public
+  },
+  _nonce: 0group.public
+}";
+        let (remainder, candidate) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(expected)?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Missing owner.
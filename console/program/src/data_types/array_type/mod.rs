@@ -37,6 +37,8 @@ impl<N: Network> ArrayType<N> {
         // Check that the number of dimensions are valid.
         ensure!(!dimensions.is_empty(), "An array must have at least one dimension");
         ensure!(dimensions.len() <= N::MAX_DATA_DEPTH, "An array can have at most {} dimensions", N::MAX_DATA_DEPTH);
+        // Check that the element type is not a vector.
+        ensure!(!matches!(plaintext_type, PlaintextType::Vector(..)), "An array cannot contain a vector");
         // Check that each dimension is valid.
         for length in &dimensions {
             ensure!(**length as usize >= N::MIN_ARRAY_ELEMENTS, "An array must have {} element", N::MIN_ARRAY_ELEMENTS);
@@ -121,6 +123,16 @@ mod tests {
         assert_eq!(array.length(), &U32::new(1));
         assert!(!array.is_empty());
 
+        // Test enum array types.
+        let array = ArrayType::<CurrentNetwork>::from_str("[shape.enum; 2u32]")?;
+        assert_eq!(array, ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("shape.enum")?, vec![U32::new(2)])?);
+        assert_eq!(
+            array.to_bytes_le()?,
+            ArrayType::<CurrentNetwork>::from_bytes_le(&array.to_bytes_le()?)?.to_bytes_le()?
+        );
+        assert_eq!(array.to_string(), "[shape.enum; 2u32]");
+        assert_eq!(array.next_element_type(), &PlaintextType::Enum(Identifier::from_str("shape")?));
+
         // Test array type with maximum length.
         let array = ArrayType::<CurrentNetwork>::from_str("[scalar; 32u32]")?;
         assert_eq!(array, ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("scalar")?, vec![U32::new(32)])?);
@@ -21,7 +21,11 @@ impl<N: Network> Parser for ArrayType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // A helper function to parse the innermost element type.
         fn parse_inner_element_type<N: Network>(string: &str) -> ParserResult<PlaintextType<N>> {
-            alt((map(LiteralType::parse, PlaintextType::from), map(Identifier::parse, PlaintextType::from)))(string)
+            alt((
+                map(LiteralType::parse, PlaintextType::from),
+                map(pair(Identifier::parse, tag(".enum")), |(identifier, _)| PlaintextType::Enum(identifier)),
+                map(Identifier::parse, PlaintextType::from),
+            ))(string)
         }
 
         // A helper function to parse the length of each dimension.
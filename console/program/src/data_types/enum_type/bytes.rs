@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for EnumType<N> {
+    /// Reads an enum type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the name of the enum type.
+        let name = Identifier::read_le(&mut reader)?;
+
+        // Read the number of variants.
+        let num_variants = u16::read_le(&mut reader)?;
+        // Ensure the number of variants is within the limits.
+        if num_variants == 0 || num_variants as usize > N::MAX_STRUCT_ENTRIES {
+            return Err(error(format!(
+                "EnumType has an invalid size: expected 1 to {}, found {num_variants}",
+                N::MAX_STRUCT_ENTRIES
+            )));
+        }
+        // Read the variants.
+        let mut variants = IndexMap::with_capacity(num_variants as usize);
+        for _ in 0..num_variants {
+            // Read the identifier.
+            let identifier = Identifier::read_le(&mut reader)?;
+            // Read the plaintext type.
+            let plaintext_type = PlaintextType::read_le(&mut reader)?;
+            // Insert the variant, and ensure the variant has no duplicate names.
+            if variants.insert(identifier, plaintext_type).is_some() {
+                return Err(error(format!("Duplicate identifier in enum '{name}'")));
+            };
+        }
+
+        Ok(Self { name, variants })
+    }
+}
+
+impl<N: Network> ToBytes for EnumType<N> {
+    /// Writes the enum type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of variants is within the limits.
+        if self.variants.is_empty() || self.variants.len() > N::MAX_STRUCT_ENTRIES {
+            return Err(error("Failed to serialize enum: invalid number of variants"));
+        }
+
+        // Write the name of the enum.
+        self.name.write_le(&mut writer)?;
+
+        // Write the number of variants.
+        u16::try_from(self.variants.len()).or_halt_with::<N>("Enum length exceeds u16").write_le(&mut writer)?;
+        // Write the variants as bytes.
+        for (identifier, plaintext_type) in &self.variants {
+            // Write the identifier.
+            identifier.write_le(&mut writer)?;
+            // Write the plaintext type to the buffer.
+            plaintext_type.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = EnumType::<CurrentNetwork>::from_str("enum shape:\n    circle as u64;\n    square as point;")?;
+        let candidate = EnumType::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+}
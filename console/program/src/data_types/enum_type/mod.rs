@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{Identifier, PlaintextType};
+use snarkvm_console_network::prelude::*;
+
+use indexmap::IndexMap;
+
+/// An enum type, with a payload type for each of its variants.
+///
+/// Note: The variant of an enum is a constant in the circuit of a function, so the circuit is fixed
+/// by the instructions that construct the enum. An enum may therefore only be constructed and consumed
+/// within a function, or passed to its finalize scope, and cannot be used as a function or closure
+/// input or output, as a struct member, or as a record entry.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EnumType<N: Network> {
+    /// The name of the enum.
+    name: Identifier<N>,
+    /// The name and payload type for the variants of the enum.
+    variants: IndexMap<Identifier<N>, PlaintextType<N>>,
+}
+
+impl<N: Network> EnumType<N> {
+    /// Returns the name of the enum type.
+    #[inline]
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the variants of the enum type.
+    #[inline]
+    pub const fn variants(&self) -> &IndexMap<Identifier<N>, PlaintextType<N>> {
+        &self.variants
+    }
+}
+
+impl<N: Network> TypeName for EnumType<N> {
+    /// Returns the type name.
+    fn type_name() -> &'static str {
+        "enum"
+    }
+}
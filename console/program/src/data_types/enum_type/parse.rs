@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for EnumType<N> {
+    /// Parses an enum as:
+    /// ```text
+    ///   enum shape:
+    ///       circle as u64;
+    ///       square as point;
+    /// ```
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a string into a tuple.
+        fn parse_tuple<N: Network>(string: &str) -> ParserResult<(Identifier<N>, PlaintextType<N>)> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the identifier from the string.
+            let (string, identifier) = Identifier::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "as" from the string.
+            let (string, _) = tag("as")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the plaintext type from the string.
+            let (string, plaintext_type) = PlaintextType::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon ';' keyword from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the identifier and plaintext type.
+            Ok((string, (identifier, plaintext_type)))
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the type name from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the enum name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the variants from the string.
+        let (string, variants) = map_res(many1(parse_tuple), |variants| {
+            // Ensure the variants has no duplicate names.
+            if has_duplicates(variants.iter().map(|(identifier, _)| identifier)) {
+                return Err(error(format!("Duplicate identifier found in enum '{name}'")));
+            }
+            // Ensure the number of variants is within the maximum limit.
+            if variants.len() > N::MAX_STRUCT_ENTRIES {
+                return Err(error("Failed to parse enum: too many variants"));
+            }
+            Ok(variants)
+        })(string)?;
+        // Return the enum.
+        Ok((string, Self { name, variants: IndexMap::from_iter(variants) }))
+    }
+}
+
+impl<N: Network> FromStr for EnumType<N> {
+    type Err = Error;
+
+    /// Returns an enum from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for EnumType<N> {
+    /// Prints the enum type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[allow(clippy::format_push_string)]
+impl<N: Network> Display for EnumType<N> {
+    /// Prints the enum type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut output = format!("{} {}:\n", Self::type_name(), self.name);
+        for (identifier, plaintext_type) in &self.variants {
+            output += &format!("    {identifier} as {plaintext_type};\n");
+        }
+        output.pop(); // trailing newline
+        write!(f, "{output}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let expected = EnumType::<CurrentNetwork> {
+            name: Identifier::from_str("shape")?,
+            variants: IndexMap::from_iter(vec![
+                (Identifier::from_str("circle")?, PlaintextType::from_str("u64")?),
+                (Identifier::from_str("square")?, PlaintextType::from_str("point")?),
+            ]),
+        };
+
+        let (remainder, candidate) = EnumType::<CurrentNetwork>::parse(
+            r"
+enum shape:
+    circle as u64;
+    square as point;
+",
+        )?;
+        assert_eq!("\n", remainder);
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Must be non-empty.
+        assert!(EnumType::<CurrentNetwork>::parse("").is_err());
+        assert!(EnumType::<CurrentNetwork>::parse("enum shape:").is_err());
+
+        // Must not be a struct.
+        assert!(EnumType::<CurrentNetwork>::parse("struct shape:\n    circle as u64;").is_err());
+
+        // Duplicate identifier.
+        assert!(EnumType::<CurrentNetwork>::parse("enum shape:\n    circle as u64;\n    circle as u32;").is_err());
+        // Visibility in plaintext type.
+        assert!(EnumType::<CurrentNetwork>::parse("enum shape:\n    circle as u64.public;").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = "enum shape:\n    circle as u64;\n    square as point;\n    line as [u64; 2u32];";
+        let shape = EnumType::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{shape}"));
+    }
+
+    #[test]
+    fn test_max_variants() {
+        let mut string = "enum shape:\n".to_string();
+        for i in 0..CurrentNetwork::MAX_STRUCT_ENTRIES {
+            string += &format!("    variant_{i} as field;\n");
+        }
+        assert!(EnumType::<CurrentNetwork>::parse(&string).is_ok());
+
+        string += &format!("    variant_{} as field;\n", CurrentNetwork::MAX_STRUCT_ENTRIES);
+        assert!(EnumType::<CurrentNetwork>::parse(&string).is_err());
+    }
+}
@@ -15,6 +15,9 @@
 mod array_type;
 pub use array_type::ArrayType;
 
+mod enum_type;
+pub use enum_type::EnumType;
+
 mod finalize_type;
 pub use finalize_type::FinalizeType;
 
@@ -35,3 +38,6 @@ pub use struct_type::StructType;
 
 mod value_type;
 pub use value_type::ValueType;
+
+mod vector_type;
+pub use vector_type::VectorType;
@@ -22,7 +22,9 @@ impl<N: Network> FromBytes for PlaintextType<N> {
             0 => Ok(Self::Literal(LiteralType::read_le(&mut reader)?)),
             1 => Ok(Self::Struct(Identifier::read_le(&mut reader)?)),
             2 => Ok(Self::Array(ArrayType::read_le(&mut reader)?)),
-            3.. => Err(error(format!("Failed to deserialize annotation variant {variant}"))),
+            3 => Ok(Self::Enum(Identifier::read_le(&mut reader)?)),
+            4 => Ok(Self::Vector(VectorType::read_le(&mut reader)?)),
+            5.. => Err(error(format!("Failed to deserialize annotation variant {variant}"))),
         }
     }
 }
@@ -43,6 +45,14 @@ impl<N: Network> ToBytes for PlaintextType<N> {
                 2u8.write_le(&mut writer)?;
                 array_type.write_le(&mut writer)
             }
+            Self::Enum(identifier) => {
+                3u8.write_le(&mut writer)?;
+                identifier.write_le(&mut writer)
+            }
+            Self::Vector(vector_type) => {
+                4u8.write_le(&mut writer)?;
+                vector_type.write_le(&mut writer)
+            }
         }
     }
 }
@@ -21,6 +21,8 @@ impl<N: Network> Parser for PlaintextType<N> {
         // Parse to determine the plaintext type (order matters).
         alt((
             map(ArrayType::parse, |type_| Self::Array(type_)),
+            map(VectorType::parse, |type_| Self::Vector(type_)),
+            map(pair(Identifier::parse, tag(".enum")), |(identifier, _)| Self::Enum(identifier)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
         ))(string)
@@ -61,6 +63,10 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Struct(struct_) => Display::fmt(struct_, f),
             // Prints the array type, i.e. [field; 2u32]
             Self::Array(array) => Display::fmt(array, f),
+            // Prints the enum, i.e. shape.enum
+            Self::Enum(enum_) => write!(f, "{enum_}.enum"),
+            // Prints the vector type, i.e. vector[field; 8u32]
+            Self::Vector(vector) => Display::fmt(vector, f),
         }
     }
 }
@@ -94,6 +100,16 @@ mod tests {
             PlaintextType::parse("[field; 1u32]"),
             Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 1u32]")?)))
         );
+        assert_eq!(
+            PlaintextType::parse("shape.enum"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Enum(Identifier::from_str("shape")?)))
+        );
+        assert_eq!(PlaintextType::<CurrentNetwork>::from_str("shape.enum")?.to_string(), "shape.enum");
+        assert_eq!(
+            PlaintextType::parse("vector[u8; 32u32]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Vector(VectorType::from_str("vector[u8; 32u32]")?)))
+        );
+        assert_eq!(PlaintextType::<CurrentNetwork>::from_str("vector[u8; 32u32]")?.to_string(), "vector[u8; 32u32]");
         Ok(())
     }
 
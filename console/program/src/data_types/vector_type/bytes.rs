@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for VectorType<N> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        // Note: The element type is read in place, as reading it as a `PlaintextType` would recurse into this method.
+        let variant = u8::read_le(&mut reader)?;
+        let element_type = match variant {
+            0 => PlaintextType::Literal(LiteralType::read_le(&mut reader)?),
+            1 => PlaintextType::Struct(Identifier::read_le(&mut reader)?),
+            2 => PlaintextType::Array(ArrayType::read_le(&mut reader)?),
+            3.. => return Err(error(format!("Failed to deserialize vector element type {variant}"))),
+        };
+        // Read the maximum length of the vector.
+        let max_length = U32::read_le(&mut reader)?;
+        // Construct the vector type.
+        VectorType::new(element_type, max_length).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network> ToBytes for VectorType<N> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        // Note: The element type is written in place, as writing it as a `PlaintextType` would recurse into this method.
+        match &*self.element_type {
+            PlaintextType::Literal(literal_type) => {
+                0u8.write_le(&mut writer)?;
+                literal_type.write_le(&mut writer)?;
+            }
+            PlaintextType::Struct(identifier) => {
+                1u8.write_le(&mut writer)?;
+                identifier.write_le(&mut writer)?;
+            }
+            PlaintextType::Array(array_type) => {
+                2u8.write_le(&mut writer)?;
+                array_type.write_le(&mut writer)?;
+            }
+            PlaintextType::Enum(..) | PlaintextType::Vector(..) => {
+                return Err(error("A vector element must be a literal, struct, or array"));
+            }
+        }
+        // Write the maximum length of the vector.
+        self.max_length.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console_network::MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["vector[u8; 1u32]", "vector[foo; 16u32]", "vector[[field; 2u32]; 32u32]"] {
+            // Serialize and deserialize the vector type.
+            let expected = VectorType::<CurrentNetwork>::from_str(string)?;
+            let candidate = VectorType::<CurrentNetwork>::read_le(&expected.to_bytes_le()?[..])?;
+            assert_eq!(expected, candidate);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{ArrayType, Identifier, LiteralType, PlaintextType, U32};
+use snarkvm_console_network::prelude::*;
+
+use core::fmt::{Debug, Display};
+
+/// A `VectorType` defines the element type and the maximum length of a vector,
+/// whose length is determined at runtime.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VectorType<N: Network> {
+    /// The element type.
+    element_type: Box<PlaintextType<N>>,
+    /// The maximum length of the vector.
+    max_length: U32<N>,
+}
+
+impl<N: Network> VectorType<N> {
+    /// Initializes a new vector type.
+    pub fn new(element_type: PlaintextType<N>, max_length: U32<N>) -> Result<Self> {
+        // Ensure the element type is a literal, struct, or array.
+        ensure!(
+            matches!(element_type, PlaintextType::Literal(..) | PlaintextType::Struct(..) | PlaintextType::Array(..)),
+            "A vector element must be a literal, struct, or array, found '{element_type}'"
+        );
+        // Ensure the maximum length is valid.
+        ensure!(*max_length as usize >= N::MIN_ARRAY_ELEMENTS, "A vector must have {} element", N::MIN_ARRAY_ELEMENTS);
+        ensure!(
+            *max_length as usize <= N::MAX_ARRAY_ELEMENTS,
+            "A vector can contain {} elements",
+            N::MAX_ARRAY_ELEMENTS
+        );
+        Ok(Self { element_type: Box::new(element_type), max_length })
+    }
+}
+
+impl<N: Network> VectorType<N> {
+    /// Returns the element type.
+    pub const fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the base element type.
+    /// In the case of a vector of arrays, this will return the element type of the **innermost** array.
+    pub fn base_element_type(&self) -> &PlaintextType<N> {
+        match self.element_type() {
+            PlaintextType::Array(array_type) => array_type.base_element_type(),
+            element_type => element_type,
+        }
+    }
+
+    /// Returns the maximum length of the vector.
+    pub const fn max_length(&self) -> &U32<N> {
+        &self.max_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, LiteralType};
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_vector_type() -> Result<()> {
+        // Test literal vector types.
+        let vector = VectorType::<CurrentNetwork>::from_str("vector[field; 4u32]")?;
+        assert_eq!(vector, VectorType::<CurrentNetwork>::new(PlaintextType::from(LiteralType::Field), U32::new(4))?);
+        assert_eq!(vector.to_string(), "vector[field; 4u32]");
+        assert_eq!(vector.element_type(), &PlaintextType::Literal(LiteralType::Field));
+        assert_eq!(vector.max_length(), &U32::new(4));
+
+        // Test struct and array vector types.
+        let vector = VectorType::<CurrentNetwork>::from_str("vector[foo; 8u32]")?;
+        assert_eq!(vector.element_type(), &PlaintextType::Struct(Identifier::from_str("foo")?));
+        let vector = VectorType::<CurrentNetwork>::from_str("vector[[u8; 2u32]; 8u32]")?;
+        assert_eq!(vector.to_string(), "vector[[u8; 2u32]; 8u32]");
+
+        // Ensure the maximum length is within the limits.
+        assert!(VectorType::<CurrentNetwork>::from_str("vector[field; 0u32]").is_err());
+        let max_length = CurrentNetwork::MAX_ARRAY_ELEMENTS + 1;
+        assert!(VectorType::<CurrentNetwork>::from_str(&format!("vector[field; {max_length}u32]")).is_err());
+
+        // Ensure enums and vectors are not vector elements.
+        assert!(VectorType::<CurrentNetwork>::from_str("vector[shape.enum; 4u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("vector[vector[u8; 2u32]; 4u32]").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for VectorType<N> {
+    /// Parses a string into a vector type.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the "vector" keyword and the opening bracket from the string.
+        let (string, _) = tag("vector")(string)?;
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespaces from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string.
+        let (string, element_type) = PlaintextType::parse(string)?;
+        // Parse the whitespaces from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Parse the whitespaces from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the maximum length and the closing bracket, and return the vector type.
+        map_res(terminated(U32::parse, pair(Sanitizer::parse_whitespaces, tag("]"))), move |max_length| {
+            VectorType::new(element_type.clone(), max_length)
+        })(string)
+    }
+}
+
+impl<N: Network> FromStr for VectorType<N> {
+    type Err = Error;
+
+    /// Returns a vector type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for VectorType<N> {
+    /// Prints the vector type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for VectorType<N> {
+    /// Prints the vector type as a string.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "vector[{}; {}]", self.element_type(), self.max_length())
+    }
+}
@@ -15,14 +15,32 @@
 mod input_id;
 pub use input_id::InputID;
 
+mod split;
+pub use split::*;
+
 mod bytes;
 mod serialize;
 mod sign;
 mod string;
+mod threshold;
 mod verify;
 
 use crate::{compute_function_id, Identifier, Plaintext, ProgramID, Record, Value, ValueType};
-use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_account::{
+    Address,
+    ComputeKey,
+    GraphKey,
+    KeyShare,
+    PrivateKey,
+    Signature,
+    SignatureShare,
+    SigningCommitments,
+    SigningKey,
+    SigningNonces,
+    SigningPackage,
+    ThresholdKey,
+    ViewKey,
+};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
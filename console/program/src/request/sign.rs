@@ -28,24 +28,11 @@ impl<N: Network> Request<N> {
         is_root: bool,
         rng: &mut R,
     ) -> Result<Self> {
-        // Ensure the number of inputs matches the number of input types.
-        if input_types.len() != inputs.len() {
-            bail!(
-                "'{program_id}/{function_name}' expects {} inputs, but {} were provided.",
-                input_types.len(),
-                inputs.len()
-            )
-        }
-
         // Retrieve `sk_sig`.
         let sk_sig = private_key.sk_sig();
 
         // Derive the compute key.
         let compute_key = ComputeKey::try_from(private_key)?;
-        // Retrieve `pk_sig`.
-        let pk_sig = compute_key.pk_sig();
-        // Retrieve `pr_sig`.
-        let pr_sig = compute_key.pr_sig();
 
         // Derive the view key.
         let view_key = ViewKey::try_from((private_key, &compute_key))?;
@@ -63,6 +50,65 @@ impl<N: Network> Request<N> {
         let signer = Address::try_from(compute_key)?;
         // Compute the transition view key `tvk` as `r * signer`.
         let tvk = (*signer * r).to_x_coordinate();
+
+        Self::sign_with(
+            compute_key,
+            sk_tag,
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            root_tvk,
+            is_root,
+            (g_r, tvk),
+            // Compute `h_r` as `r * H`, and `gamma` as `sk_sig * H`.
+            |h| Ok((h * r, h * sk_sig)),
+            // Compute `response` as `r - challenge * sk_sig`.
+            |_, challenge| Ok(r - challenge * sk_sig),
+        )
+    }
+
+    /// Returns the request for a given compute key, tag secret key, program ID, function name, inputs, and
+    /// input types, where the values derived from `sk_sig` and the nonce `r` are provided by the signer as:
+    ///     (g_r, tvk) := (r * G, r * signer)
+    ///     sign_record(H) := (r * H, sk_sig * H), for each input record that belongs to the signer
+    ///     respond(message, challenge) := r - challenge * sk_sig
+    ///
+    /// An input record that is held in custody by a program (i.e. owned by a program address) is not signed for:
+    ///     (h_r, gamma, tag) := (H, H, Hash(owner || commitment)), for each input record held in custody
+    ///
+    /// Note: Records held in custody do not have the unlinkability of records that belong to a signer.
+    /// The commitment of a record is public in the transition that outputs it, and the owner is a program address,
+    /// so anyone can compute the serial number and tag of a record held in custody, and link its spend to its output.
+    pub(crate) fn sign_with(
+        compute_key: ComputeKey<N>,
+        sk_tag: Field<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+        (g_r, tvk): (Group<N>, Field<N>),
+        mut sign_record: impl FnMut(Group<N>) -> Result<(Group<N>, Group<N>)>,
+        respond: impl FnOnce(&[Field<N>], Scalar<N>) -> Result<Scalar<N>>,
+    ) -> Result<Self> {
+        // Ensure the number of inputs matches the number of input types.
+        if input_types.len() != inputs.len() {
+            bail!(
+                "'{program_id}/{function_name}' expects {} inputs, but {} were provided.",
+                input_types.len(),
+                inputs.len()
+            )
+        }
+
+        // Retrieve `pk_sig`.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve `pr_sig`.
+        let pr_sig = compute_key.pr_sig();
+
+        // Derive the signer from the compute key.
+        let signer = Address::try_from(compute_key)?;
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
         let tcm = N::hash_psd2(&[tvk])?;
         // Compute the signer commitment `scm` as `Hash(signer || root_tvk)`.
@@ -171,23 +217,38 @@ impl<N: Network> Request<N> {
                         Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
                         Value::Future(..) => bail!("Expected a record input, found a future input"),
                     };
-                    // Ensure the record belongs to the signer.
-                    ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
+                    // Determine if the record belongs to the signer.
+                    // Note: A record that does not belong to the signer is held in custody by its owner program,
+                    // and may only be consumed in a call from the owner program, which is checked by the stack.
+                    let is_signer_owned = **record.owner() == signer;
+                    // Ensure the record belongs to the signer, or is consumed in a call from another program.
+                    ensure!(
+                        is_signer_owned || is_root.is_zero(),
+                        "Input record for '{program_id}' must belong to the signer or be consumed by its owner program"
+                    );
 
                     // Compute the record commitment.
                     let commitment = record.to_commitment(&program_id, record_name)?;
 
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
-                    // Compute `h_r` as `r * H`.
-                    let h_r = h * r;
-                    // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
+                    let (h_r, gamma, tag) = match is_signer_owned {
+                        // If the record belongs to the signer, then:
+                        //  - compute `h_r` as `r * H`,
+                        //  - compute `gamma` as `sk_sig * H`,
+                        //  - compute the tag as `Hash(sk_tag || commitment)`.
+                        true => {
+                            let (h_r, gamma) = sign_record(h)?;
+                            (h_r, gamma, Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?)
+                        }
+                        // If the record is held in custody by a program, then:
+                        //  - set `h_r` and `gamma` to `H`, so the serial number does not depend on the signer,
+                        //  - compute the tag as `Hash(owner || commitment)`.
+                        false => (h, h, Record::<N, Plaintext<N>>::tag(record.owner().to_x_coordinate(), commitment)?),
+                    };
 
                     // Compute the `serial_number` from `gamma`.
                     let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
-                    // Compute the tag.
-                    let tag = Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?;
 
                     // Add (`H`, `r * H`, `gamma`, `tag`) to the preimage.
                     message.extend([h, h_r, gamma].iter().map(|point| point.to_x_coordinate()));
@@ -225,7 +286,7 @@ impl<N: Network> Request<N> {
         // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
         let challenge = N::hash_to_scalar_psd8(&message)?;
         // Compute `response` as `r - challenge * sk_sig`.
-        let response = r - challenge * sk_sig;
+        let response = respond(&message, challenge)?;
 
         Ok(Self {
             signer,
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for RequestCommitPayload<N> {
+    /// Reads the commit payload from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid request commit payload version"));
+        }
+        // Read the number of generators.
+        let num_generators = u16::read_le(&mut reader)?;
+        // Read the generators.
+        let generators = (0..num_generators).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { generators })
+    }
+}
+
+impl<N: Network> ToBytes for RequestCommitPayload<N> {
+    /// Writes the commit payload to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of generators.
+        u16::try_from(self.generators.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the generators.
+        self.generators.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for RequestCommitment<N> {
+    /// Reads the request commitment from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid request commitment version"));
+        }
+        // Read the transition public key.
+        let tpk = FromBytes::read_le(&mut reader)?;
+        // Read the number of record commitments.
+        let num_record_commitments = u16::read_le(&mut reader)?;
+        // Read the record commitments.
+        let record_commitments = (0..num_record_commitments)
+            .map(|_| Ok((FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(Self { tpk, record_commitments })
+    }
+}
+
+impl<N: Network> ToBytes for RequestCommitment<N> {
+    /// Writes the request commitment to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the transition public key.
+        self.tpk.write_le(&mut writer)?;
+        // Write the number of record commitments.
+        u16::try_from(self.record_commitments.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the record commitments.
+        for (h_r, gamma) in &self.record_commitments {
+            h_r.write_le(&mut writer)?;
+            gamma.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for RequestSignPayload<N> {
+    /// Reads the sign payload from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid request sign payload version"));
+        }
+        // Read the number of message elements.
+        let num_elements = u16::read_le(&mut reader)?;
+        // Read the message.
+        let message = (0..num_elements).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { message })
+    }
+}
+
+impl<N: Network> ToBytes for RequestSignPayload<N> {
+    /// Writes the sign payload to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of message elements.
+        u16::try_from(self.message.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the message.
+        self.message.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a signing key and a split request.
+        let (signing_key, split_request) = test_helpers::sample_split_request(rng);
+
+        // Check the byte representation of the commit payload.
+        let expected = split_request.to_commit_payload()?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RequestCommitPayload::read_le(&expected_bytes[..])?);
+        assert!(RequestCommitPayload::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of the request commitment.
+        let (_, expected) = split_request.to_commit_payload()?.commit(&signing_key, rng)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RequestCommitment::read_le(&expected_bytes[..])?);
+        assert!(RequestCommitment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of the sign payload.
+        let expected = split_request.to_sign_payload(&expected)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RequestSignPayload::read_le(&expected_bytes[..])?);
+        assert!(RequestSignPayload::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use super::*;
+
+/// The first payload from the host to the signer of a split request,
+/// with the generator `H` of each input record that belongs to the signer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestCommitPayload<N: Network> {
+    /// The generators `H` of the input records that belong to the signer.
+    generators: Vec<Group<N>>,
+}
+
+impl<N: Network> RequestCommitPayload<N> {
+    /// Returns the generators of the input records that belong to the signer.
+    pub fn generators(&self) -> &[Group<N>] {
+        &self.generators
+    }
+}
+
+/// The commitment from the signer of a split request, with the values derived from the nonce `r` and `sk_sig`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestCommitment<N: Network> {
+    /// The transition public key `tpk` as `r * G`.
+    tpk: Group<N>,
+    /// The values `(r * H, sk_sig * H)` for each generator `H`.
+    record_commitments: Vec<(Group<N>, Group<N>)>,
+}
+
+impl<N: Network> RequestCommitment<N> {
+    /// Returns the transition public key.
+    pub const fn tpk(&self) -> Group<N> {
+        self.tpk
+    }
+
+    /// Returns the values `(r * H, sk_sig * H)` for each generator `H`.
+    pub fn record_commitments(&self) -> &[(Group<N>, Group<N>)] {
+        &self.record_commitments
+    }
+}
+
+/// The second payload from the host to the signer of a split request, with the message to sign, as
+/// `(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, is_root, input IDs])`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestSignPayload<N: Network> {
+    /// The message to sign.
+    message: Vec<Field<N>>,
+}
+
+impl<N: Network> RequestSignPayload<N> {
+    /// Returns the message to sign.
+    pub fn message(&self) -> &[Field<N>] {
+        &self.message
+    }
+
+    /// Returns the function ID of the request.
+    pub fn function_id(&self) -> Result<Field<N>> {
+        self.message.get(6).copied().ok_or_else(|| anyhow!("The request message is malformed"))
+    }
+}
+
+/// The secret nonce of the signer for a single split request.
+/// Note: The nonce must never be reused, and is consumed when signing.
+pub struct RequestNonce<N: Network> {
+    /// The nonce `r`. Note: This is the transition secret key `tsk`.
+    r: Scalar<N>,
+}
+
+impl<N: Network> RequestCommitPayload<N> {
+    /// Returns the nonce and commitment of the signer for a split request.
+    pub fn commit<R: Rng + CryptoRng>(
+        &self,
+        signing_key: &SigningKey<N>,
+        rng: &mut R,
+    ) -> Result<(RequestNonce<N>, RequestCommitment<N>)> {
+        // Retrieve `sk_sig`.
+        let sk_sig = signing_key.sk_sig();
+        // Sample a random nonce.
+        let nonce = Field::<N>::rand(rng);
+        // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
+        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+
+        // Compute the commitment.
+        let commitment = RequestCommitment {
+            tpk: N::g_scalar_multiply(&r),
+            record_commitments: self.generators.iter().map(|h| (*h * r, *h * sk_sig)).collect(),
+        };
+        Ok((RequestNonce { r }, commitment))
+    }
+}
+
+impl<N: Network> RequestSignPayload<N> {
+    /// Returns the signature of the signer for a split request, after checking that the message
+    /// commits to the account of the signer, and to the transition keys derived from the nonce.
+    pub fn sign(&self, signing_key: &SigningKey<N>, nonce: RequestNonce<N>) -> Result<Signature<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        ensure!(
+            self.message.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize,
+            "Cannot sign the request: the message exceeds maximum allowed size"
+        );
+        // Ensure the message contains `(r * G, pk_sig, pr_sig, signer, tvk, tcm, function ID, is_root)`.
+        ensure!(self.message.len() >= 8, "Cannot sign the request: the message is malformed");
+
+        // Derive the compute key.
+        let compute_key = signing_key.to_compute_key()?;
+        // Derive the signer from the compute key.
+        let signer = compute_key.to_address();
+
+        // Ensure the message commits to `(r * G, pk_sig, pr_sig, signer)`.
+        let expected = [N::g_scalar_multiply(&nonce.r), compute_key.pk_sig(), compute_key.pr_sig(), *signer]
+            .map(|point| point.to_x_coordinate());
+        ensure!(self.message[..4] == expected, "Cannot sign the request: the message is for another signer or nonce");
+        // Ensure the message commits to `tvk` as `r * signer`, and to `tcm` as `Hash(tvk)`.
+        let tvk = (*signer * nonce.r).to_x_coordinate();
+        ensure!(
+            self.message[4] == tvk && self.message[5] == N::hash_psd2(&[tvk])?,
+            "Cannot sign the request: the message has an invalid transition view key"
+        );
+
+        // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
+        let challenge = N::hash_to_scalar_psd8(&self.message)?;
+        // Compute `response` as `r - challenge * sk_sig`.
+        let response = nonce.r - challenge * signing_key.sk_sig();
+        Ok(Signature::from((challenge, response, compute_key)))
+    }
+}
+
+/// The host of a split request, which prepares every part of a request that does not require `sk_sig`,
+/// and requests the values derived from `sk_sig` from a constrained signer, such as a hardware wallet:
+///  1. The host sends `RequestCommitPayload` to the signer, which returns a `RequestCommitment`.
+///  2. The host sends `RequestSignPayload` to the signer, which returns a `Signature`.
+///  3. The host combines the commitment and signature into the `Request`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitRequest<N: Network> {
+    /// The compute key of the signer.
+    compute_key: ComputeKey<N>,
+    /// The view key of the signer.
+    view_key: ViewKey<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The function input types.
+    input_types: Vec<ValueType<N>>,
+    /// The root transition view key.
+    root_tvk: Option<Field<N>>,
+    /// Whether the request is for the root transition.
+    is_root: bool,
+}
+
+impl<N: Network> SplitRequest<N> {
+    /// Initializes a split request for the given compute key, view key, program ID, function name, inputs,
+    /// and input types.
+    pub fn new(
+        compute_key: ComputeKey<N>,
+        view_key: ViewKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        input_types: Vec<ValueType<N>>,
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+    ) -> Result<Self> {
+        // Ensure the number of inputs matches the number of input types.
+        ensure!(
+            input_types.len() == inputs.len(),
+            "'{program_id}/{function_name}' expects {} inputs, but {} were provided.",
+            input_types.len(),
+            inputs.len()
+        );
+        // Ensure the view key corresponds to the compute key.
+        ensure!(view_key.to_address() == compute_key.to_address(), "The view key does not correspond to the signer");
+        Ok(Self { compute_key, view_key, program_id, function_name, inputs, input_types, root_tvk, is_root })
+    }
+
+    /// Returns the first payload for the signer.
+    pub fn to_commit_payload(&self) -> Result<RequestCommitPayload<N>> {
+        let generators = Request::threshold_generators(
+            &self.compute_key.to_address(),
+            &self.program_id,
+            &self.inputs,
+            &self.input_types,
+        )?;
+        Ok(RequestCommitPayload { generators })
+    }
+
+    /// Returns the second payload for the signer, given the commitment of the signer.
+    pub fn to_sign_payload(&self, commitment: &RequestCommitment<N>) -> Result<RequestSignPayload<N>> {
+        let mut message = None;
+        self.sign_with(commitment, |candidate, _| {
+            message = Some(candidate.to_vec());
+            // Note: The response is computed by the signer.
+            Ok(Scalar::zero())
+        })?;
+        match message {
+            Some(message) => Ok(RequestSignPayload { message }),
+            None => bail!("Failed to construct the request message"),
+        }
+    }
+
+    /// Returns the request, given the commitment and signature of the signer.
+    pub fn into_request(self, commitment: &RequestCommitment<N>, signature: &Signature<N>) -> Result<Request<N>> {
+        // Ensure the signature is from the signer.
+        ensure!(signature.compute_key() == self.compute_key, "The signature is not from the signer");
+        // Construct the request.
+        let request = self.sign_with(commitment, |_, challenge| {
+            // Ensure the signature is for the request.
+            ensure!(signature.challenge() == challenge, "The signature does not correspond to the request");
+            Ok(signature.response())
+        })?;
+        // Ensure the request is valid.
+        ensure!(request.verify(&self.input_types, self.is_root), "Failed to construct a valid request");
+        Ok(request)
+    }
+
+    /// Returns the request for the given commitment of the signer, and function to compute the response.
+    fn sign_with(
+        &self,
+        commitment: &RequestCommitment<N>,
+        respond: impl FnOnce(&[Field<N>], Scalar<N>) -> Result<Scalar<N>>,
+    ) -> Result<Request<N>> {
+        // Retrieve `g_r` as `r * G`.
+        let g_r = commitment.tpk;
+        // Compute the transition view key `tvk` as `r * signer`, which is equivalent to `view_key * r * G`.
+        let tvk = (g_r * *self.view_key).to_x_coordinate();
+        // Derive `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(self.view_key)?.sk_tag();
+
+        // Retrieve the record commitments of the signer, in input order.
+        let mut record_commitments = commitment.record_commitments.iter();
+        let request = Request::sign_with(
+            self.compute_key,
+            sk_tag,
+            self.program_id,
+            self.function_name,
+            self.inputs.iter().cloned(),
+            &self.input_types,
+            self.root_tvk,
+            self.is_root,
+            (g_r, tvk),
+            |_| {
+                record_commitments.next().copied().ok_or_else(|| anyhow!("Missing a record commitment from the signer"))
+            },
+            respond,
+        )?;
+        // Ensure every record commitment was used.
+        ensure!(record_commitments.next().is_none(), "Found more record commitments than input records");
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a signing key and a split request with an input record that belongs to the signer.
+    pub(super) fn sample_split_request(
+        rng: &mut TestRng,
+    ) -> (SigningKey<CurrentNetwork>, SplitRequest<CurrentNetwork>) {
+        // Sample a signing key.
+        let signing_key = SigningKey::from(&PrivateKey::new(rng).unwrap());
+        let address = signing_key.to_address().unwrap();
+
+        // Prepare a record belonging to the signer.
+        let record_string = format!(
+            "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+
+        // Construct the inputs and input types.
+        let inputs = vec![
+            Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+            Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+            Value::from_str(&record_string).unwrap(),
+            Value::from_str(&record_string).unwrap(),
+        ];
+        let input_types = vec![
+            ValueType::from_str("amount.public").unwrap(),
+            ValueType::from_str("amount.private").unwrap(),
+            ValueType::from_str("token.record").unwrap(),
+            ValueType::from_str("token.aleo/token.record").unwrap(),
+        ];
+
+        // Construct the split request.
+        let split_request = SplitRequest::new(
+            signing_key.to_compute_key().unwrap(),
+            signing_key.to_view_key().unwrap(),
+            ProgramID::from_str("token.aleo").unwrap(),
+            Identifier::from_str("transfer").unwrap(),
+            inputs,
+            input_types,
+            None,
+            true,
+        )
+        .unwrap();
+        (signing_key, split_request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_split_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a signing key and a split request.
+        let (signing_key, split_request) = test_helpers::sample_split_request(rng);
+
+        // Phase 1: The signer commits to a nonce.
+        let commit_payload = split_request.to_commit_payload()?;
+        assert_eq!(commit_payload.generators().len(), 1);
+        let (nonce, commitment) = commit_payload.commit(&signing_key, rng)?;
+
+        // Phase 2: The signer signs the request message.
+        let sign_payload = split_request.to_sign_payload(&commitment)?;
+        let function_id = compute_function_id(
+            &U16::new(CurrentNetwork::ID),
+            &split_request.program_id,
+            &split_request.function_name,
+        )?;
+        assert_eq!(sign_payload.function_id()?, function_id);
+        let signature = sign_payload.sign(&signing_key, nonce)?;
+
+        // Ensure a signature for another request is rejected.
+        let mut other_request = split_request.clone();
+        other_request.inputs[0] = Value::from_str("{ token_amount: 1u128 }")?;
+        assert!(other_request.into_request(&commitment, &signature).is_err());
+
+        // Construct the request.
+        let input_types = split_request.input_types.clone();
+        let request = split_request.into_request(&commitment, &signature)?;
+        assert_eq!(request.signer(), &signing_key.to_address()?);
+        assert!(request.verify(&input_types, true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_sign_rejects_invalid_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a signing key and a split request.
+        let (signing_key, split_request) = test_helpers::sample_split_request(rng);
+        let (nonce, commitment) = split_request.to_commit_payload()?.commit(&signing_key, rng)?;
+        let sign_payload = split_request.to_sign_payload(&commitment)?;
+
+        // Ensure the signer rejects a message for another nonce.
+        let (other_nonce, _) = split_request.to_commit_payload()?.commit(&signing_key, rng)?;
+        assert!(sign_payload.sign(&signing_key, other_nonce).is_err());
+
+        // Ensure the signer rejects a message with an invalid transition view key.
+        let mut invalid_payload = sign_payload.clone();
+        invalid_payload.message[4] = Field::rand(rng);
+        assert!(invalid_payload.sign(&signing_key, nonce).is_err());
+
+        // Ensure the signer rejects a message for another signer.
+        let other_signing_key = SigningKey::<CurrentNetwork>::from(&PrivateKey::new(rng)?);
+        let (other_nonce, _) = split_request.to_commit_payload()?.commit(&other_signing_key, rng)?;
+        assert!(sign_payload.sign(&other_signing_key, other_nonce).is_err());
+        Ok(())
+    }
+}
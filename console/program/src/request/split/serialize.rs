@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for RequestCommitPayload<N> {
+    /// Serializes the commit payload into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut payload = serializer.serialize_struct("RequestCommitPayload", 1)?;
+                payload.serialize_field("generators", &self.generators)?;
+                payload.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RequestCommitPayload<N> {
+    /// Deserializes the commit payload from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the payload from a string into a value.
+                let mut payload = serde_json::Value::deserialize(deserializer)?;
+                // Recover the payload.
+                Ok(Self { generators: DeserializeExt::take_from_value::<D>(&mut payload, "generators")? })
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request commit payload")
+            }
+        }
+    }
+}
+
+impl<N: Network> Serialize for RequestCommitment<N> {
+    /// Serializes the request commitment into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut commitment = serializer.serialize_struct("RequestCommitment", 2)?;
+                commitment.serialize_field("tpk", &self.tpk)?;
+                commitment.serialize_field("record_commitments", &self.record_commitments)?;
+                commitment.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RequestCommitment<N> {
+    /// Deserializes the request commitment from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the commitment from a string into a value.
+                let mut commitment = serde_json::Value::deserialize(deserializer)?;
+                // Recover the commitment.
+                Ok(Self {
+                    // Retrieve the transition public key.
+                    tpk: DeserializeExt::take_from_value::<D>(&mut commitment, "tpk")?,
+                    // Retrieve the record commitments.
+                    record_commitments: DeserializeExt::take_from_value::<D>(&mut commitment, "record_commitments")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request commitment"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for RequestSignPayload<N> {
+    /// Serializes the sign payload into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut payload = serializer.serialize_struct("RequestSignPayload", 1)?;
+                payload.serialize_field("message", &self.message)?;
+                payload.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RequestSignPayload<N> {
+    /// Deserializes the sign payload from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the payload from a string into a value.
+                let mut payload = serde_json::Value::deserialize(deserializer)?;
+                // Recover the payload.
+                Ok(Self { message: DeserializeExt::take_from_value::<D>(&mut payload, "message")? })
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request sign payload")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the JSON and bincode round trips of the given value.
+    fn check_serde<T: Serialize + DeserializeOwned + ToBytes + PartialEq + Debug>(expected: T) -> Result<()> {
+        // Check the JSON round trip.
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        // Check the bincode round trip.
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a signing key and a split request.
+        let (signing_key, split_request) = test_helpers::sample_split_request(rng);
+
+        // Check the commit payload.
+        let commit_payload = split_request.to_commit_payload()?;
+        check_serde(commit_payload.clone())?;
+        // Check the request commitment.
+        let (_, commitment) = commit_payload.commit(&signing_key, rng)?;
+        check_serde(commitment.clone())?;
+        // Check the sign payload.
+        check_serde(split_request.to_sign_payload(&commitment)?)?;
+        Ok(())
+    }
+}
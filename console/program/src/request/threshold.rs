@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Request<N> {
+    /// Returns the generators `H` of the input records that belong to the signer, in input order.
+    /// Note: Each signer of a threshold account must commit to these generators in the first round.
+    pub fn threshold_generators(
+        signer: &Address<N>,
+        program_id: &ProgramID<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+    ) -> Result<Vec<Group<N>>> {
+        let mut generators = Vec::new();
+        for (input, input_type) in inputs.iter().zip_eq(input_types) {
+            if let (Value::Record(record), ValueType::Record(record_name)) = (input, input_type) {
+                // Only the input records that belong to the signer are signed for.
+                if **record.owner() == *signer {
+                    // Compute the record commitment.
+                    let commitment = record.to_commitment(program_id, record_name)?;
+                    // Compute the generator `H` as `HashToGroup(commitment)`.
+                    generators.push(N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?);
+                }
+            }
+        }
+        Ok(generators)
+    }
+
+    /// Returns the signature share of a participant of a threshold account, for the request with the given
+    /// program ID, function name, inputs, and input types, and the signing commitments of all signers.
+    pub fn sign_threshold_share(
+        key_share: &KeyShare<N>,
+        nonces: SigningNonces<N>,
+        commitments: &[SigningCommitments<N>],
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+    ) -> Result<SignatureShare<N>> {
+        let mut share = None;
+        Self::sign_threshold(
+            key_share.threshold_key(),
+            &key_share.view_key(),
+            commitments,
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            root_tvk,
+            is_root,
+            |package, challenge| {
+                // Compute the signature share.
+                let signature_share = key_share.sign_share(nonces, package, challenge)?;
+                share = Some(signature_share);
+                Ok(signature_share.response())
+            },
+        )?;
+        share.ok_or_else(|| anyhow!("Failed to compute the signature share"))
+    }
+
+    /// Returns the request of a threshold account, by aggregating the signature shares of all signers
+    /// for the request with the given program ID, function name, inputs, and input types.
+    pub fn aggregate_threshold(
+        threshold_key: &ThresholdKey<N>,
+        view_key: &ViewKey<N>,
+        commitments: &[SigningCommitments<N>],
+        shares: &[SignatureShare<N>],
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+    ) -> Result<Self> {
+        // Aggregate the signature shares into the request.
+        let request = Self::sign_threshold(
+            threshold_key,
+            view_key,
+            commitments,
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            root_tvk,
+            is_root,
+            |package, challenge| threshold_key.aggregate_shares(package, challenge, shares),
+        )?;
+        // Ensure the request is valid.
+        ensure!(request.verify(input_types, is_root), "Failed to aggregate a valid request");
+        Ok(request)
+    }
+
+    /// Returns the request of a threshold account, where the nonce `r` is jointly committed to by the signers,
+    /// and the response is computed from the signing package and challenge with the given function.
+    fn sign_threshold(
+        threshold_key: &ThresholdKey<N>,
+        view_key: &ViewKey<N>,
+        commitments: &[SigningCommitments<N>],
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+        respond: impl FnOnce(&SigningPackage<N>, Scalar<N>) -> Result<Scalar<N>>,
+    ) -> Result<Self> {
+        // Ensure the number of inputs matches the number of input types.
+        ensure!(input_types.len() == inputs.len(), "Expected {} inputs, found {}", input_types.len(), inputs.len());
+        // Ensure the view key corresponds to the threshold account.
+        let signer = threshold_key.to_address();
+        ensure!(view_key.to_address() == signer, "The view key does not correspond to the threshold account");
+
+        // Construct the message that the binding factors commit to.
+        let message = Self::to_threshold_message(&program_id, &function_name, inputs, input_types, root_tvk, is_root)?;
+        // Retrieve the generators of the input records that belong to the signer.
+        let generators = Self::threshold_generators(&signer, &program_id, inputs, input_types)?;
+        // Construct the signing package, which ensures the signers committed to each of the generators.
+        let package = SigningPackage::new(threshold_key, &generators, commitments, &message)?;
+
+        // Retrieve `g_r` as `r * G`.
+        let g_r = package.nonce_commitment();
+        // Compute the transition view key `tvk` as `r * signer`, which is equivalent to `view_key * r * G`.
+        let tvk = (g_r * **view_key).to_x_coordinate();
+        // Derive `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(*view_key)?.sk_tag();
+
+        // Initialize a counter for the input records that belong to the signer.
+        let mut counter = 0;
+        Self::sign_with(
+            threshold_key.compute_key(),
+            sk_tag,
+            program_id,
+            function_name,
+            inputs.iter().cloned(),
+            input_types,
+            root_tvk,
+            is_root,
+            (g_r, tvk),
+            |h| {
+                // Ensure the generator matches the commitments of the signers.
+                ensure!(generators.get(counter) == Some(&h), "Mismatching generator for input record {counter}");
+                // Retrieve `h_r` as `r * H`, and `gamma` as `sk_sig * H`.
+                let output = (package.generator_commitments()[counter], package.gammas()[counter]);
+                counter += 1;
+                Ok(output)
+            },
+            |_, challenge| respond(&package, challenge),
+        )
+    }
+
+    /// Returns the message that the binding factors of a threshold request commit to, as
+    /// `(function ID, is_root, root_tvk, [input type, input])`.
+    fn to_threshold_message(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+    ) -> Result<Vec<Field<N>>> {
+        // Compute the function ID.
+        let function_id = compute_function_id(&U16::new(N::ID), program_id, function_name)?;
+
+        // Construct the message.
+        let mut message = vec![
+            function_id,
+            Field::from_u8(is_root as u8),
+            Field::from_u8(root_tvk.is_some() as u8),
+            root_tvk.unwrap_or_else(Field::zero),
+        ];
+        for (input, input_type) in inputs.iter().zip_eq(input_types) {
+            // Pack the input type into field elements.
+            let input_type_bits = input_type.to_bytes_le()?.to_bits_le();
+            for chunk in input_type_bits.chunks(Field::<N>::size_in_data_bits()) {
+                message.push(Field::from_bits_le(chunk)?);
+            }
+            // Add the input to the message.
+            message.extend(input.to_fields()?);
+        }
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_sign_threshold_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a 2-of-3 account.
+        let key_shares = KeyShare::<CurrentNetwork>::new(2, 3, rng)?;
+        let address = key_shares[0].to_address();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+
+        // Prepare a record belonging to the account.
+        let record_string = format!(
+            "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+
+        // Construct the inputs and input types.
+        let inputs = vec![
+            Value::from_str("{ token_amount: 9876543210u128 }")?,
+            Value::from_str("{ token_amount: 9876543210u128 }")?,
+            Value::from_str(&record_string)?,
+            Value::from_str(&record_string)?,
+        ];
+        let input_types = vec![
+            ValueType::from_str("amount.public")?,
+            ValueType::from_str("amount.private")?,
+            ValueType::from_str("token.record")?,
+            ValueType::from_str("token.aleo/token.record")?,
+        ];
+
+        // Select the signers.
+        let signers = [&key_shares[0], &key_shares[2]];
+
+        // Round 1: Each signer commits to its nonces, and to the generators of its input records.
+        let generators = Request::threshold_generators(&address, &program_id, &inputs, &input_types)?;
+        assert_eq!(generators.len(), 1);
+        let (nonces, commitments): (Vec<_>, Vec<_>) =
+            signers.iter().map(|signer| signer.commit(&generators, rng)).unzip();
+
+        // Round 2: Each signer computes its signature share.
+        let shares = signers
+            .iter()
+            .zip(nonces)
+            .map(|(signer, nonces)| {
+                Request::sign_threshold_share(
+                    signer,
+                    nonces,
+                    &commitments,
+                    program_id,
+                    function_name,
+                    &inputs,
+                    &input_types,
+                    None,
+                    true,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Aggregate the signature shares into the request.
+        let request = Request::aggregate_threshold(
+            key_shares[0].threshold_key(),
+            &key_shares[0].view_key(),
+            &commitments,
+            &shares,
+            program_id,
+            function_name,
+            &inputs,
+            &input_types,
+            None,
+            true,
+        )?;
+        assert_eq!(request.signer(), &address);
+        assert!(request.verify(&input_types, true));
+
+        // Ensure the request fails to aggregate with a signature share for another request.
+        let mut other_inputs = inputs.clone();
+        other_inputs[1] = Value::from_str("{ token_amount: 1u128 }")?;
+        assert!(Request::aggregate_threshold(
+            key_shares[0].threshold_key(),
+            &key_shares[0].view_key(),
+            &commitments,
+            &shares,
+            program_id,
+            function_name,
+            &other_inputs,
+            &input_types,
+            None,
+            true,
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -19,6 +19,9 @@ impl<N: Network> Request<N> {
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///
+    /// For an input record held in custody by a program, `gamma` is `H`, and the tag is `Hash(owner || commitment)`.
+    /// As both are derived from public values, the spend of a record held in custody is linkable (see `sign_with`).
     pub fn verify(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
@@ -146,8 +149,14 @@ impl<N: Network> Request<N> {
                             // Ensure the input type is a record.
                             _ => bail!("Expected a record type at input {index}"),
                         };
-                        // Ensure the record belongs to the signer.
-                        ensure!(**record.owner() == self.signer, "Input record does not belong to the signer");
+                        // Determine if the record belongs to the signer.
+                        let is_signer_owned = **record.owner() == self.signer;
+                        // Ensure the record belongs to the signer, or is consumed in a call from another program.
+                        // Note: The stack ensures a record held in custody is only consumed by its owner program.
+                        ensure!(
+                            is_signer_owned || is_root.is_zero(),
+                            "Input record does not belong to the signer or a calling program"
+                        );
 
                         // Compute the record commitment.
                         let candidate_cm = record.to_commitment(&self.program_id, record_name)?;
@@ -161,11 +170,17 @@ impl<N: Network> Request<N> {
 
                         // Compute the generator `H` as `HashToGroup(commitment)`.
                         let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
-                        // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
-                        let h_r = (*gamma * challenge) + (h * response);
-
-                        // Compute the tag as `Hash(sk_tag || commitment)`.
-                        let candidate_tag = N::hash_psd2(&[self.sk_tag, *commitment])?;
+                        let (h_r, candidate_tag) = match is_signer_owned {
+                            // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`,
+                            // and compute the tag as `Hash(sk_tag || commitment)`.
+                            true => ((*gamma * challenge) + (h * response), N::hash_psd2(&[self.sk_tag, *commitment])?),
+                            // Ensure `gamma` is `H` for a record held in custody, so the serial number is unique,
+                            // and compute the tag as `Hash(owner || commitment)`.
+                            false => {
+                                ensure!(*gamma == h, "Expected a custody record input with a deterministic gamma");
+                                (h, N::hash_psd2(&[record.owner().to_x_coordinate(), *commitment])?)
+                            }
+                        };
                         // Ensure the tag matches.
                         ensure!(*tag == candidate_tag, "Expected a record input with the same tag");
 
@@ -272,4 +287,55 @@ mod tests {
             assert!(request.verify(&input_types, is_root));
         }
     }
+
+    #[test]
+    fn test_sign_and_verify_program_owned_record() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        // Retrieve the address of the program that holds the record in custody.
+        let owner = ProgramID::<CurrentNetwork>::from_str("escrow.aleo").unwrap().to_address().unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Prepare a record belonging to the program.
+        let record_string = format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+        let inputs = [Value::from_str(&record_string).unwrap()];
+        let input_types = [ValueType::from_str("token.record").unwrap()];
+
+        // Ensure the record cannot be consumed in a root request.
+        let request =
+            Request::sign(&private_key, program_id, function_name, inputs.iter(), &input_types, None, true, rng);
+        assert!(request.is_err());
+
+        // Ensure the record can be consumed in a call from another program, with a deterministic serial number.
+        let mut serial_numbers = Vec::new();
+        for _ in 0..2 {
+            let root_tvk = Some(Uniform::rand(rng));
+            let request = Request::sign(
+                &private_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                &input_types,
+                root_tvk,
+                false,
+                rng,
+            )
+            .unwrap();
+            assert!(request.verify(&input_types, false));
+            assert!(!request.verify(&input_types, true));
+            serial_numbers.extend(request.input_ids().iter().filter_map(|input_id| match input_id {
+                InputID::Record(_, _, serial_number, _) => Some(*serial_number),
+                _ => None,
+            }));
+        }
+        assert_eq!(serial_numbers.len(), 2);
+        assert_eq!(serial_numbers[0], serial_numbers[1]);
+    }
 }
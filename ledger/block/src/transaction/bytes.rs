@@ -20,11 +20,28 @@ impl<N: Network> FromBytes for Transaction<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transaction version"));
+        // Read the transaction, using the layout of the given version.
+        match version {
+            1 => Self::read_v1_le(&mut reader),
+            _ => Err(error(format!("Invalid transaction version ({version})"))),
         }
+    }
+}
+
+impl<N: Network> ToBytes for Transaction<N> {
+    /// Writes the transaction to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the transaction, using the layout of the current version.
+        self.write_v1_le(&mut writer)
+    }
+}
 
+impl<N: Network> Transaction<N> {
+    /// Reads the transaction from the buffer, using version 1 of the layout.
+    fn read_v1_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the variant.
         let variant = u8::read_le(&mut reader)?;
         // Match the variant.
@@ -85,15 +102,9 @@ impl<N: Network> FromBytes for Transaction<N> {
             false => Err(error("Transaction ID mismatch")),
         }
     }
-}
-
-impl<N: Network> ToBytes for Transaction<N> {
-    /// Writes the transaction to the buffer.
-    #[inline]
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
 
+    /// Writes the transaction to the buffer, using version 1 of the layout.
+    fn write_v1_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the transaction.
         match self {
             Self::Deploy(id, owner, deployment, fee) => {
@@ -139,6 +150,9 @@ impl<N: Network> ToBytes for Transaction<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -158,4 +172,28 @@ mod tests {
         }
         Ok(())
     }
+    #[test]
+    fn test_bytes_fixtures() -> Result<()> {
+        // Each fixture holds a transaction from the genesis block, encoded with the given version.
+        for (version, fixture) in [(1u8, &include_bytes!("./resources/execution_transaction.v1")[..])] {
+            assert_eq!(version, fixture[0]);
+            // Ensure the fixture is still readable (this also checks the transaction ID).
+            let candidate = Transaction::<CurrentNetwork>::read_le(fixture)?;
+            // Ensure the fixture of the current version is re-encoded byte-for-byte.
+            if version == Transaction::<CurrentNetwork>::VERSION {
+                assert_eq!(fixture, &candidate.to_bytes_le()?[..]);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_invalid_version() -> Result<()> {
+        // Load the fixture, and overwrite the version with an unknown one.
+        let mut bytes = include_bytes!("./resources/execution_transaction.v1").to_vec();
+        bytes[0] = Transaction::<CurrentNetwork>::VERSION + 1;
+        // Ensure the unknown version is rejected.
+        assert!(Transaction::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
 }
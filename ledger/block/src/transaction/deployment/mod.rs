@@ -56,10 +56,10 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is not below the network edition (as a later edition is an upgrade).
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition >= N::EDITION,
+            "Deployed the wrong edition (expected at least '{}', found '{}').",
             N::EDITION,
             self.edition
         );
@@ -109,6 +109,11 @@ impl<N: Network> Deployment<N> {
         self.edition
     }
 
+    /// Returns `true` if the deployment is an upgrade of a deployed program.
+    pub const fn is_upgrade(&self) -> bool {
+        self.edition > N::EDITION
+    }
+
     /// Returns the program.
     pub const fn program(&self) -> &Program<N> {
         &self.program
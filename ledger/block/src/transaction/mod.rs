@@ -44,6 +44,9 @@ pub enum Transaction<N: Network> {
 }
 
 impl<N: Network> Transaction<N> {
+    /// The serialization version of the transaction.
+    pub const VERSION: u8 = 1;
+
     /// Initializes a new deployment transaction.
     pub fn from_deployment(owner: ProgramOwner<N>, deployment: Deployment<N>, fee: Fee<N>) -> Result<Self> {
         // Ensure the transaction is not empty.
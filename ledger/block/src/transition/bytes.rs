@@ -20,7 +20,8 @@ impl<N: Network> FromBytes for Transition<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        // Note: Version 2 transitions contain encrypted memos.
+        if version != 1 && version != 2 {
             return Err(error("Invalid transition version"));
         }
 
@@ -56,9 +57,33 @@ impl<N: Network> FromBytes for Transition<N> {
         // Read the signer commitment.
         let scm = FromBytes::read_le(&mut reader)?;
 
+        // Read the memos.
+        let memos = match version {
+            1 => vec![],
+            _ => {
+                // Read the number of memos.
+                let num_memos: u8 = FromBytes::read_le(&mut reader)?;
+                // Ensure there is at least one memo.
+                if num_memos == 0 {
+                    return Err(error("A version 2 transition must contain memos"));
+                }
+                // Read the memos.
+                let mut memos = Vec::with_capacity(num_memos as usize);
+                for _ in 0..num_memos {
+                    // Read the memo.
+                    memos.push(FromBytes::read_le(&mut reader)?);
+                }
+                memos
+            }
+        };
+
         // Construct the candidate transition.
-        let transition =
-            Self::new(program_id, function_name, inputs, outputs, tpk, tcm, scm).map_err(|e| error(e.to_string()))?;
+        let transition = Self::new(program_id, function_name, inputs, outputs, tpk, tcm, scm)
+            .and_then(|transition| match memos.is_empty() {
+                true => Ok(transition),
+                false => transition.with_memos(memos),
+            })
+            .map_err(|e| error(e.to_string()))?;
         // Ensure the transition ID matches the expected ID.
         match transition_id == *transition.id() {
             true => Ok(transition),
@@ -71,7 +96,11 @@ impl<N: Network> ToBytes for Transition<N> {
     /// Writes the literal to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Version 2 transitions contain encrypted memos.
+        match self.memos.is_empty() {
+            true => 1u8.write_le(&mut writer)?,
+            false => 2u8.write_le(&mut writer)?,
+        }
 
         // Write the transition ID.
         self.id.write_le(&mut writer)?;
@@ -95,7 +124,16 @@ impl<N: Network> ToBytes for Transition<N> {
         // Write the transition commitment.
         self.tcm.write_le(&mut writer)?;
         // Write the signer commitment.
-        self.scm.write_le(&mut writer)
+        self.scm.write_le(&mut writer)?;
+
+        // Write the memos, if they exist.
+        if !self.memos.is_empty() {
+            // Write the number of memos.
+            (u8::try_from(self.memos.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+            // Write the memos.
+            self.memos.write_le(&mut writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -114,6 +152,13 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
 
+        // Sample the transition with memos.
+        let expected = crate::transition::test_helpers::sample_transition_with_memos(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
+
         Ok(())
     }
 }
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Memo<N> {
+    /// Reads the memo from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid memo version"));
+        }
+        // Read the output index.
+        let index = FromBytes::read_le(&mut reader)?;
+        // Read the nonce.
+        let nonce = FromBytes::read_le(&mut reader)?;
+        // Read the ciphertext.
+        let ciphertext = FromBytes::read_le(&mut reader)?;
+        // Return the memo.
+        Self::from(index, nonce, ciphertext).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for Memo<N> {
+    /// Writes the memo to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the output index.
+        self.index.write_le(&mut writer)?;
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the ciphertext.
+        self.ciphertext.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the memo.
+        let expected = crate::transition::memo::test_helpers::sample_memo(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Memo::read_le(&expected_bytes[..])?);
+
+        Ok(())
+    }
+}
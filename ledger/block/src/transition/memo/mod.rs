@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    account::{Address, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Plaintext},
+    types::{Field, Group, Scalar},
+};
+
+/// An encrypted memo, attached to a transition output for the recipient.
+///
+/// Note: Memos are not signed or proven, and are only bound through the transition ID (see `Transition::with_memos`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct Memo<N: Network> {
+    /// The index of the transition output.
+    index: u8,
+    /// The nonce of the memo.
+    nonce: Group<N>,
+    /// The memo ciphertext.
+    ciphertext: Ciphertext<N>,
+}
+
+impl<N: Network> Memo<N> {
+    /// The maximum number of field elements in a memo ciphertext.
+    pub const MAX_SIZE_IN_FIELDS: usize = 16;
+
+    /// Initializes a new memo for the transition output at the given index, by encrypting
+    /// the given plaintext to the given recipient address.
+    pub fn new<R: Rng + CryptoRng>(
+        index: u8,
+        recipient: &Address<N>,
+        plaintext: &Plaintext<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a randomizer.
+        let randomizer = Scalar::rand(rng);
+        // Compute the nonce as `randomizer * G`.
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Encrypt the plaintext to the recipient.
+        let ciphertext = plaintext.encrypt(recipient, randomizer)?;
+        // Return the memo.
+        Self::from(index, nonce, ciphertext)
+    }
+
+    /// Initializes a memo from its output index, nonce, and ciphertext.
+    pub fn from(index: u8, nonce: Group<N>, ciphertext: Ciphertext<N>) -> Result<Self> {
+        // Ensure the ciphertext does not exceed the maximum size.
+        ensure!(
+            ciphertext.size_in_fields()? as usize <= Self::MAX_SIZE_IN_FIELDS,
+            "A memo cannot exceed {} field elements",
+            Self::MAX_SIZE_IN_FIELDS
+        );
+        // Return the memo.
+        Ok(Self { index, nonce, ciphertext })
+    }
+}
+
+impl<N: Network> Memo<N> {
+    /// Returns the index of the transition output.
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the nonce of the memo.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns the memo ciphertext.
+    pub const fn ciphertext(&self) -> &Ciphertext<N> {
+        &self.ciphertext
+    }
+
+    /// Decrypts the memo with the given view key of the recipient.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Plaintext<N>> {
+        self.ciphertext.decrypt(*view_key, self.nonce)
+    }
+
+    /// Returns the hash of the memo, as `Hash(index || nonce || ciphertext)`.
+    pub fn to_hash(&self) -> Result<Field<N>> {
+        // Construct the preimage.
+        let mut preimage = vec![Field::from_u8(self.index), self.nonce.to_x_coordinate()];
+        preimage.extend(self.ciphertext.to_fields()?);
+        // Hash the preimage.
+        N::hash_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a random memo.
+    pub(crate) fn sample_memo(rng: &mut TestRng) -> Memo<CurrentNetwork> {
+        // Sample a recipient.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient = Address::try_from(&private_key).unwrap();
+        // Sample a plaintext.
+        let plaintext = Plaintext::from_str("{ invoice: 1234field, reference: 5u64 }").unwrap();
+        // Return the memo.
+        Memo::new(0, &recipient, &plaintext, rng).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            // Sample a recipient.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let view_key = ViewKey::try_from(&private_key).unwrap();
+            let recipient = Address::try_from(&private_key).unwrap();
+
+            // Encrypt a memo to the recipient.
+            let plaintext = Plaintext::from_str("{ invoice: 1234field, reference: 5u64 }").unwrap();
+            let memo = Memo::new(1, &recipient, &plaintext, rng).unwrap();
+            assert_eq!(memo.index(), 1);
+
+            // Ensure the recipient can decrypt the memo.
+            assert_eq!(memo.decrypt(&view_key).unwrap(), plaintext);
+            // Ensure another view key does not recover the plaintext.
+            let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+            assert_ne!(memo.decrypt(&other_view_key).ok(), Some(plaintext));
+        }
+    }
+
+    #[test]
+    fn test_memo_exceeds_max_size() {
+        let rng = &mut TestRng::default();
+
+        // Sample a recipient.
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        // Ensure a memo that exceeds the maximum size is rejected.
+        let plaintext = Plaintext::from_str(&format!("[{}]", vec!["0field"; 17].join(", "))).unwrap();
+        assert!(Memo::new(0, &recipient, &plaintext, rng).is_err());
+    }
+}
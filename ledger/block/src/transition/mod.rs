@@ -15,6 +15,9 @@
 pub mod input;
 pub use input::Input;
 
+pub mod memo;
+pub use memo::Memo;
+
 pub mod output;
 pub use output::Output;
 
@@ -64,6 +67,8 @@ pub struct Transition<N: Network> {
     tcm: Field<N>,
     /// The transition signer commitment.
     scm: Field<N>,
+    /// The encrypted memos attached to the transition outputs.
+    memos: Vec<Memo<N>>,
 }
 
 impl<N: Network> Transition<N> {
@@ -80,9 +85,45 @@ impl<N: Network> Transition<N> {
     ) -> Result<Self> {
         // Compute the transition ID.
         let function_tree = Self::function_tree(&inputs, &outputs)?;
-        let id = N::hash_bhp512(&(*function_tree.root(), tcm).to_bits_le())?;
+        let id = Self::compute_id(*function_tree.root(), tcm, &[])?;
         // Return the transition.
-        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm, scm })
+        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm, scm, memos: vec![] })
+    }
+
+    /// Returns the transition with the given encrypted memos attached to its outputs.
+    ///
+    /// Note: The memos are not covered by the request signature or the transition proof, so anyone
+    /// holding the transition may replace them. They are only committed to in the transition ID,
+    /// which the fee binds through the execution ID. Memos must therefore be attached before the
+    /// fee is authorized, as changing them afterwards invalidates the fee. Fee transitions and
+    /// fee-less executions are rejected if they contain memos, as nothing binds their IDs.
+    pub fn with_memos(self, memos: Vec<Memo<N>>) -> Result<Self> {
+        // Ensure the memos are ordered by output index, with at most one memo per output.
+        ensure!(
+            memos.windows(2).all(|pair| pair[0].index() < pair[1].index()),
+            "Transition memos must be unique and ordered by output index"
+        );
+        // Ensure each memo is attached to an output.
+        if let Some(memo) = memos.last() {
+            ensure!((memo.index() as usize) < self.outputs.len(), "Transition memo index {} is invalid", memo.index());
+        }
+        // Compute the transition ID.
+        let id = Self::compute_id(self.to_root()?, self.tcm, &memos)?;
+        // Return the transition.
+        Ok(Self { id: id.into(), memos, ..self })
+    }
+
+    /// Returns the transition ID, as `Hash(transition root || tcm)`, or
+    /// as `Hash(transition root || tcm || Hash(memo hashes))` if there are memos.
+    pub fn compute_id(root: Field<N>, tcm: Field<N>, memos: &[Memo<N>]) -> Result<Field<N>> {
+        match memos.is_empty() {
+            true => N::hash_bhp512(&(root, tcm).to_bits_le()),
+            false => {
+                // Compute the hash of the memos.
+                let memos_hash = N::hash_psd8(&memos.iter().map(Memo::to_hash).collect::<Result<Vec<_>>>()?)?;
+                N::hash_bhp512(&(root, tcm, memos_hash).to_bits_le())
+            }
+        }
     }
 
     /// Initializes a new transition from a request and response.
@@ -305,6 +346,11 @@ impl<N: Network> Transition<N> {
     pub const fn scm(&self) -> &Field<N> {
         &self.scm
     }
+
+    /// Returns the encrypted memos attached to the transition outputs.
+    pub fn memos(&self) -> &[Memo<N>] {
+        &self.memos
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -502,4 +548,57 @@ pub mod test_helpers {
             unreachable!()
         }
     }
+
+    /// Samples a random transition with an encrypted memo attached to its first output.
+    pub(crate) fn sample_transition_with_memos(rng: &mut TestRng) -> Transition<CurrentNetwork> {
+        // Sample a transition with an output.
+        let transition = sample_transition(rng);
+        assert!(!transition.outputs().is_empty());
+        // Attach a memo to the first output.
+        transition.with_memos(vec![crate::transition::memo::test_helpers::sample_memo(rng)]).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey, ViewKey},
+        program::Plaintext,
+    };
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_transition_memos() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transition = test_helpers::sample_transition(rng);
+        assert!(transition.memos().is_empty());
+
+        // Sample a recipient.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let recipient = Address::try_from(&private_key)?;
+        // Encrypt a memo to the recipient.
+        let plaintext = Plaintext::from_str("{ invoice: 1234field }")?;
+        let memo = Memo::new(0, &recipient, &plaintext, rng)?;
+
+        // Attach the memo to the transition.
+        let candidate = transition.clone().with_memos(vec![memo.clone()])?;
+        // Ensure the memo is committed to in the transition ID.
+        assert_ne!(candidate.id(), transition.id());
+        assert_eq!(candidate.clone().with_memos(vec![])?.id(), transition.id());
+        // Ensure the recipient can decrypt the memo.
+        assert_eq!(candidate.memos()[0].decrypt(&view_key)?, plaintext);
+
+        // Ensure a memo must be attached to an output.
+        let index = u8::try_from(transition.outputs().len())?;
+        assert!(transition.clone().with_memos(vec![Memo::new(index, &recipient, &plaintext, rng)?]).is_err());
+        // Ensure there is at most one memo per output.
+        assert!(transition.with_memos(vec![memo.clone(), memo]).is_err());
+
+        Ok(())
+    }
 }
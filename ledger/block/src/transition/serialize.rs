@@ -19,7 +19,7 @@ impl<N: Network> Serialize for Transition<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Transition", 8)?;
+                let mut transition = serializer.serialize_struct("Transition", 8 + !self.memos.is_empty() as usize)?;
                 transition.serialize_field("id", &self.id)?;
                 transition.serialize_field("program", &self.program_id)?;
                 transition.serialize_field("function", &self.function_name)?;
@@ -28,6 +28,9 @@ impl<N: Network> Serialize for Transition<N> {
                 transition.serialize_field("tpk", &self.tpk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
                 transition.serialize_field("scm", &self.scm)?;
+                if !self.memos.is_empty() {
+                    transition.serialize_field("memos", &self.memos)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -44,6 +47,10 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                 let mut transition = serde_json::Value::deserialize(deserializer)?;
                 // Retrieve the ID.
                 let id: N::TransitionID = DeserializeExt::take_from_value::<D>(&mut transition, "id")?;
+                // Retrieve the memos, if they exist.
+                let memos: Option<Vec<Memo<N>>> =
+                    serde_json::from_value(transition.get_mut("memos").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
 
                 // Recover the transition.
                 let transition = Self::new(
@@ -64,6 +71,12 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                 )
                 .map_err(de::Error::custom)?;
 
+                // Attach the memos to the transition.
+                let transition = match memos {
+                    Some(memos) => transition.with_memos(memos).map_err(de::Error::custom)?,
+                    None => transition,
+                };
+
                 // Ensure the transition ID is correct.
                 match id == *transition.id() {
                     true => Ok(transition),
@@ -86,14 +99,17 @@ mod tests {
         // Sample the transition.
         let expected = crate::transition::test_helpers::sample_transition(rng);
 
-        // Serialize
-        let expected_string = &expected.to_string();
-        let candidate_string = serde_json::to_string(&expected)?;
-        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        // Sample the transition with memos.
+        for expected in [expected, crate::transition::test_helpers::sample_transition_with_memos(rng)] {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
 
-        // Deserialize
-        assert_eq!(expected, Transition::from_str(expected_string)?);
-        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+            // Deserialize
+            assert_eq!(expected, Transition::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
 
         Ok(())
     }
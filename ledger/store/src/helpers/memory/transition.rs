@@ -18,6 +18,7 @@ use console::{
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
+use ledger_block::Memo;
 
 use aleo_std_storage::StorageMode;
 
@@ -40,6 +41,8 @@ pub struct TransitionMemory<N: Network> {
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: MemoryMap<N::TransitionID, Field<N>>,
+    /// The transition memos.
+    memo_map: MemoryMap<N::TransitionID, Vec<Memo<N>>>,
 }
 
 #[rustfmt::skip]
@@ -52,6 +55,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
     type SCMMap = MemoryMap<N::TransitionID, Field<N>>;
+    type MemoMap = MemoryMap<N::TransitionID, Vec<Memo<N>>>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -64,6 +68,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
             scm_map: MemoryMap::default(),
+            memo_map: MemoryMap::default(),
         })
     }
 
@@ -106,6 +111,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the transition memos.
+    fn memo_map(&self) -> &Self::MemoMap {
+        &self.memo_map
+    }
 }
 
 /// An in-memory transition input storage.
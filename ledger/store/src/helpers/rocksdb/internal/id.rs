@@ -188,6 +188,7 @@ pub enum TransitionMap {
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
     SCM = DataID::TransitionSCMMap as u16,
+    Memo = DataID::TransitionMemoMap as u16,
 }
 
 /// The RocksDB map prefix for program-related entries.
@@ -292,6 +293,8 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Transition
+    TransitionMemoMap,
 
     // Testing
     #[cfg(test)]
@@ -25,6 +25,7 @@ use console::{
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
+use ledger_block::Memo;
 
 use aleo_std_storage::StorageMode;
 
@@ -47,6 +48,8 @@ pub struct TransitionDB<N: Network> {
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: DataMap<N::TransitionID, Field<N>>,
+    /// The transition memos.
+    memo_map: DataMap<N::TransitionID, Vec<Memo<N>>>,
 }
 
 #[rustfmt::skip]
@@ -59,6 +62,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
     type SCMMap = DataMap<N::TransitionID, Field<N>>;
+    type MemoMap = DataMap<N::TransitionID, Vec<Memo<N>>>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -71,6 +75,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(),  MapID::Transition(TransitionMap::ReverseTCM))?,
             scm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::SCM))?,
+            memo_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::Memo))?,
         })
     }
 
@@ -113,6 +118,11 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the transition memos.
+    fn memo_map(&self) -> &Self::MemoMap {
+        &self.memo_map
+    }
 }
 
 /// An database transition input storage.
@@ -218,10 +218,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.find_edition_from_transaction_id(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the deployment is the latest edition of the program.
+        if self.get_edition(&program_id)? != Some(edition) {
+            bail!("Failed to remove transaction '{transaction_id}', as it is not the latest edition of '{program_id}'")
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -231,8 +235,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Remove the edition, restoring the previous edition if the deployment is an upgrade.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -294,6 +301,29 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition of the deployment in the given `transaction ID`.
+    fn find_edition_from_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Find the edition that was deployed by the transaction ID, starting from the latest edition.
+        for edition in (N::EDITION..=latest_edition).rev() {
+            if let Some(candidate) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if cow_to_copied!(candidate) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        bail!("Failed to find the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the edition for the given `program ID`.
     fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -395,7 +425,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.find_edition_from_transaction_id(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -442,7 +472,6 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
         // Retrieve the edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
@@ -469,9 +498,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
         };
 
-        // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        // Retrieve the owner of the deployed edition.
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -580,6 +609,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program_id(transaction_id)
     }
 
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.get_program(program_id)
@@ -639,13 +673,13 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Returns an iterator over the program IDs, for all deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values_confirmed().map(|id| match id {
+        self.storage.edition_map().keys_confirmed().map(|id| match id {
             Cow::Borrowed(id) => Cow::Borrowed(id),
             Cow::Owned(id) => Cow::Owned(id),
         })
     }
 
-    /// Returns an iterator over the programs, for all deployments.
+    /// Returns an iterator over the programs, for all deployments (including every edition).
     pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
         self.storage.program_map().values_confirmed().map(|program| match program {
             Cow::Borrowed(program) => Cow::Borrowed(program),
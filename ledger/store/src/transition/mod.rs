@@ -29,7 +29,7 @@ use console::{
     program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
-use ledger_block::{Input, Output, Transition};
+use ledger_block::{Input, Memo, Output, Transition};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
@@ -53,6 +53,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
     /// The signer commitments.
     type SCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
+    /// The transition memos.
+    type MemoMap: for<'a> Map<'a, N::TransitionID, Vec<Memo<N>>>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -73,6 +75,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
     /// Returns the signer commitments map.
     fn scm_map(&self) -> &Self::SCMMap;
+    /// Returns the transition memos map.
+    fn memo_map(&self) -> &Self::MemoMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
@@ -90,6 +94,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
         self.scm_map().start_atomic();
+        self.memo_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -102,6 +107,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
             || self.scm_map().is_atomic_in_progress()
+            || self.memo_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +120,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
         self.scm_map().atomic_checkpoint();
+        self.memo_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -126,6 +133,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
         self.scm_map().clear_latest_checkpoint();
+        self.memo_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -138,6 +146,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
         self.scm_map().atomic_rewind();
+        self.memo_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -150,6 +159,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
         self.scm_map().abort_atomic();
+        self.memo_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -161,7 +171,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
         self.reverse_tcm_map().finish_atomic()?;
-        self.scm_map().finish_atomic()
+        self.scm_map().finish_atomic()?;
+        self.memo_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -185,6 +196,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
             // Store `scm`.
             self.scm_map().insert(transition_id, *transition.scm())?;
+            // Store the memos, if they exist.
+            if !transition.memos().is_empty() {
+                self.memo_map().insert(transition_id, transition.memos().to_vec())?;
+            }
 
             Ok(())
         })
@@ -220,6 +235,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.reverse_tcm_map().remove(&tcm)?;
             // Remove `scm`.
             self.scm_map().remove(transition_id)?;
+            // Remove the memos.
+            self.memo_map().remove(transition_id)?;
 
             Ok(())
         })
@@ -242,6 +259,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         let tcm = self.tcm_map().get_confirmed(transition_id)?;
         // Retrieve `scm`.
         let scm = self.scm_map().get_confirmed(transition_id)?;
+        // Retrieve the memos.
+        let memos = self.memo_map().get_confirmed(transition_id)?;
 
         match (tpk, tcm, scm) {
             (Some(tpk), Some(tcm), Some(scm)) => {
@@ -255,6 +274,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
                     cow_to_cloned!(tcm),
                     cow_to_cloned!(scm),
                 )?;
+                // Attach the memos, if they exist.
+                let transition = match memos {
+                    Some(memos) => transition.with_memos(cow_to_cloned!(memos))?,
+                    None => transition,
+                };
                 // Ensure the transition ID matches.
                 match transition.id() == transition_id {
                     true => Ok(Some(transition)),
@@ -733,4 +757,38 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_insert_get_remove_with_memos() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transition = transaction.transitions().next().unwrap().clone();
+
+        // Sample a recipient.
+        let private_key = console::account::PrivateKey::new(rng).unwrap();
+        let recipient = console::account::Address::try_from(&private_key).unwrap();
+        // Attach a memo to the first output of the transition.
+        let plaintext = Plaintext::from_str("{ invoice: 1234field, reference: 5u64 }").unwrap();
+        let memo = Memo::new(0, &recipient, &plaintext, rng).unwrap();
+        let transition = transition.with_memos(vec![memo]).unwrap();
+        let transition_id = *transition.id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionMemory::open(None).unwrap();
+
+        // Insert the transition.
+        transition_store.insert(&transition).unwrap();
+        // Ensure the transition is retrieved with its memos.
+        let candidate = transition_store.get(&transition_id).unwrap();
+        assert_eq!(Some(transition.clone()), candidate);
+        assert_eq!(candidate.unwrap().memos().len(), 1);
+
+        // Remove the transition.
+        transition_store.remove(&transition_id).unwrap();
+        // Ensure the transition and its memos do not exist.
+        assert_eq!(None, transition_store.get(&transition_id).unwrap());
+        assert!(!transition_store.memo_map().contains_key_confirmed(&transition_id).unwrap());
+    }
 }
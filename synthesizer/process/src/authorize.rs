@@ -29,6 +29,27 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
 
+    /// Authorizes a call to the function of an external circuit, given the inputs of its R1CS.
+    /// The witness inputs of the function are computed with the witness generator of the external circuit.
+    #[inline]
+    pub fn authorize_external_circuit<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: &[N::Field],
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Compute the inputs of the function.
+        let inputs = stack.get_external_circuit(&function_name)?.to_function_inputs(inputs)?;
+        // Authorize the call.
+        stack.authorize::<A, R>(private_key, function_name, inputs.into_iter(), rng)
+    }
+
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
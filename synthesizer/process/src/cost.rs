@@ -133,6 +133,27 @@ fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &Plaint
             // Return the size of the array.
             Ok(num_elements.saturating_mul(size_of_element))
         }
+        PlaintextType::Enum(enum_name) => {
+            // Retrieve the enum from the stack.
+            let enum_ = stack.program().get_enum(enum_name)?;
+            // Retrieve the size of the largest variant, as its name and payload.
+            let size_of_variant = enum_.variants().iter().try_fold(0u64, |acc, (variant, payload_type)| {
+                let size_of_name = variant.to_bytes_le()?.len() as u64;
+                Ok::<_, Error>(acc.max(size_of_name.saturating_add(plaintext_size_in_bytes(stack, payload_type)?)))
+            })?;
+            // Return the size of the enum.
+            Ok(size_of_variant)
+        }
+        PlaintextType::Vector(vector_type) => {
+            // Retrieve the maximum number of elements in the vector.
+            let num_elements = **vector_type.max_length() as u64;
+            // Compute the size of a vector element.
+            let size_of_element = plaintext_size_in_bytes(stack, vector_type.element_type())?;
+            // Compute the size of the vector length.
+            let size_of_length = LiteralType::U32.size_in_bytes::<N>() as u64;
+            // Return the size of the vector, as its padded elements and its length.
+            Ok(num_elements.saturating_mul(size_of_element).saturating_add(size_of_length))
+        }
     }
 }
 
@@ -178,11 +199,17 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
+        Command::Instruction(Instruction::CallDynamic(_)) => bail!("'call.dynamic' is not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
             CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
             CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
                 .saturating_mul(CAST_PER_BYTE_COST)
                 .saturating_add(CAST_BASE_COST)),
+            CastType::EnumVariant(enum_name, _) => {
+                Ok(plaintext_size_in_bytes(stack, &PlaintextType::Enum(*enum_name))?
+                    .saturating_mul(CAST_PER_BYTE_COST)
+                    .saturating_add(CAST_BASE_COST))
+            }
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
             | CastType::Record(_)
@@ -193,6 +220,11 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
             CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
                 .saturating_mul(CAST_PER_BYTE_COST)
                 .saturating_add(CAST_BASE_COST)),
+            CastType::EnumVariant(enum_name, _) => {
+                Ok(plaintext_size_in_bytes(stack, &PlaintextType::Enum(*enum_name))?
+                    .saturating_mul(CAST_PER_BYTE_COST)
+                    .saturating_add(CAST_BASE_COST))
+            }
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
             | CastType::Record(_)
@@ -227,6 +259,8 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'div' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'div' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Enum(_)) => bail!("'div' does not support enums"),
+                FinalizeType::Plaintext(PlaintextType::Vector(_)) => bail!("'div' does not support vectors"),
                 FinalizeType::Future(_) => bail!("'div' does not support futures"),
             }
         }
@@ -306,6 +340,8 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'mul' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'mul' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Enum(_)) => bail!("'mul' does not support enums"),
+                FinalizeType::Plaintext(PlaintextType::Vector(_)) => bail!("'mul' does not support vectors"),
                 FinalizeType::Future(_) => bail!("'mul' does not support futures"),
             }
         }
@@ -326,6 +362,8 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'pow' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'pow' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Enum(_)) => bail!("'pow' does not support enums"),
+                FinalizeType::Plaintext(PlaintextType::Vector(_)) => bail!("'pow' does not support vectors"),
                 FinalizeType::Future(_) => bail!("'pow' does not support futures"),
             }
         }
@@ -345,6 +383,17 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Ternary(_)) => Ok(500),
         Command::Instruction(Instruction::Xor(_)) => Ok(500),
+        Command::Instruction(Instruction::IsVariant(_)) => Ok(500),
+        Command::Instruction(Instruction::VectorPush(vector_push)) => {
+            cost_in_size(stack, finalize, vector_push.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::VectorLen(_)) => Ok(500),
+        Command::Instruction(Instruction::VectorGet(vector_get)) => {
+            cost_in_size(stack, finalize, vector_get.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::VectorSet(vector_set)) => {
+            cost_in_size(stack, finalize, vector_set.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
         Command::Await(_) => Ok(500),
         Command::Contains(command) => {
             cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
@@ -37,14 +37,47 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys the given upgrade of a deployed program, with the given edition.
+    #[inline]
+    pub fn deploy_upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::deploy_upgrade");
+
+        // Ensure the edition is an upgrade.
+        ensure!(edition > N::EDITION, "The edition of an upgrade must be greater than {}", N::EDITION);
+
+        // Compute the stack, ensuring the upgrade is compatible with the deployed program.
+        let stack = Stack::upgrade(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Compute the deployment.
+        let deployment = stack.deploy::<A, R>(rng)?;
+        lap!(timer, "Construct the deployment");
+
+        finish!(timer);
+
+        // Return the deployment, with the given edition.
+        Deployment::new(edition, deployment.program().clone(), deployment.verifying_keys().clone())
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
     pub fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         let timer = timer!("Process::load_deployment");
 
+        // Determine if the deployment upgrades a program in the process.
+        let is_upgrade = self.contains_program(deployment.program_id());
+
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match is_upgrade {
+            true => Stack::upgrade(self, deployment.program())?,
+            false => Stack::new(self, deployment.program())?,
+        };
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,7 +87,10 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Add the stack to the process.
-        self.add_stack(stack);
+        match is_upgrade {
+            true => self.upgrade_stack(stack)?,
+            false => self.add_stack(stack),
+        }
 
         finish!(timer);
 
@@ -0,0 +1,205 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    account::Address,
+    program::{Request, ValueType},
+};
+
+impl<N: Network> Process<N> {
+    /// Returns the constraints of the given function in the iden3 `.r1cs` binary format.
+    ///
+    /// Note that only the circuit of the given function is exported, and not those of the functions it calls.
+    #[inline]
+    pub fn export_r1cs<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        rng: &mut R,
+    ) -> Result<Vec<u8>> {
+        self.synthesize_assignment::<A, R>(program_id, function_name, rng)?.to_r1cs_bytes()
+    }
+
+    /// Returns the constraints of the given function as JSON, in the layout of `snarkjs r1cs export json`.
+    ///
+    /// Note that only the circuit of the given function is exported, and not those of the functions it calls.
+    #[inline]
+    pub fn export_r1cs_json<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        rng: &mut R,
+    ) -> Result<String> {
+        let json = self.synthesize_assignment::<A, R>(program_id, function_name, rng)?.to_r1cs_json()?;
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+
+    /// Returns the witness of the given function on the given inputs in the iden3 `.wtns` binary format.
+    /// The wires of the witness match those of `Process::export_r1cs` for the same function.
+    #[inline]
+    pub fn export_witness<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<Vec<u8>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Authorize the function call.
+        let authorization = stack.authorize::<A, R>(private_key, function_name, inputs, rng)?;
+        // Retrieve the main request.
+        let request = authorization.next()?;
+        // Retrieve the function name.
+        let function_name = *request.function_name();
+
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::PackageRun(vec![request], *private_key, assignments.clone());
+        // Synthesize the circuit, ensuring it is satisfied on the given inputs.
+        stack.execute_function::<A, R>(call_stack, None, None, rng)?;
+
+        // The assignment of the main function is saved after those of the functions it calls.
+        let assignments = assignments.read();
+        match assignments.last() {
+            Some((assignment, _)) => assignment.to_wtns_bytes(),
+            None => bail!("The assignment for function '{function_name}' is missing"),
+        }
+    }
+
+    /// Returns the circuit assignment of the given function, synthesized on sampled inputs.
+    #[inline]
+    pub fn synthesize_assignment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        rng: &mut R,
+    ) -> Result<circuit::Assignment<N::Field>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function input types.
+        let input_types = stack.get_function(&function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = stack.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => stack.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with a burner private key.
+        let request = Request::sign(
+            &burner_private_key,
+            *stack.program_id(),
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            None,
+            true,
+            rng,
+        )?;
+
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack, without constraint or variable limits.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone(), None, None);
+        // Synthesize the circuit.
+        stack.execute_function::<A, R>(call_stack, None, None, rng)?;
+
+        // The assignment of the main function is saved after those of the functions it calls.
+        let assignment = assignments.read().last().map(|(assignment, _)| assignment.clone());
+        assignment.ok_or_else(|| anyhow!("The assignment for function '{function_name}' is missing"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Reads a little-endian `u32` at the given offset.
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_export_r1cs_and_witness() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program export.aleo;
+
+function compute:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;",
+        )
+        .unwrap();
+        let function_name = Identifier::<CurrentNetwork>::from_str("compute").unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Export the constraints.
+        let r1cs = process.export_r1cs::<CurrentAleo, _>(program.id(), function_name, rng).unwrap();
+        assert_eq!(&r1cs[0..4], b"r1cs");
+        // Retrieve the number of wires and constraints from the header section.
+        let num_wires = read_u32(&r1cs, 24 + 36);
+        let num_constraints = read_u32(&r1cs, 24 + 60);
+        assert!(num_constraints > 0);
+
+        // Ensure the JSON export agrees with the binary export.
+        let json = process.export_r1cs_json::<CurrentAleo, _>(program.id(), function_name, rng).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json["nVars"], num_wires);
+        assert_eq!(json["nConstraints"], num_constraints);
+
+        // Export the witness, and ensure it has a value for every wire.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let inputs = ["1u32", "2u32"].into_iter();
+        let wtns =
+            process.export_witness::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs, rng).unwrap();
+        assert_eq!(&wtns[0..4], b"wtns");
+        assert_eq!(read_u32(&wtns, 24 + 36), num_wires);
+
+        // Ensure the witness is rejected when the inputs do not satisfy the function.
+        let inputs = ["4294967295u32", "1u32"].into_iter();
+        assert!(
+            process.export_witness::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs, rng).is_err()
+        );
+    }
+}
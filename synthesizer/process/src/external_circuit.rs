@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use circuit::environment::{R1CSFile, R1CSFileLC};
+use synthesizer_program::Function;
+
+use core::{fmt::Write as _, ops::Range};
+
+/// Computes the values of every wire of an external R1CS, given the values of its inputs.
+pub trait WitnessGenerator<F>: Send + Sync {
+    /// Returns the values of every wire, in wire order, given the values of the input wires.
+    fn generate_witness(&self, inputs: &[F]) -> Result<Vec<F>>;
+}
+
+impl<F, G: Fn(&[F]) -> Result<Vec<F>> + Send + Sync> WitnessGenerator<F> for G {
+    fn generate_witness(&self, inputs: &[F]) -> Result<Vec<F>> {
+        self(inputs)
+    }
+}
+
+/// An externally supplied R1CS (e.g. compiled by circom), which is compiled into an Aleo function.
+///
+/// The function enforces every constraint of the R1CS with `mul`, `add`, and `assert.eq` instructions,
+/// so the deployed program is the circuit that is proven, and its keys are synthesized like any other function.
+/// The public and private inputs of the R1CS are the leading inputs of the function, and the remaining wires
+/// (the outputs, followed by the internal wires) are private witness inputs, packed in `[[field; 32u32]; 32u32]` arrays.
+/// The outputs of the R1CS are the public outputs of the function.
+///
+/// Once the program is added to the process, the external circuit is registered with `insert_external_circuit`,
+/// and `Process::authorize_external_circuit` authorizes a call given only the inputs of the R1CS.
+#[derive(Clone)]
+pub struct ExternalCircuit<N: Network> {
+    /// The R1CS.
+    r1cs: Arc<R1CSFile<N::Field>>,
+    /// The witness generator of the R1CS.
+    witness_generator: Arc<dyn WitnessGenerator<N::Field>>,
+}
+
+impl<N: Network> ExternalCircuit<N> {
+    /// Initializes a new external circuit from the given R1CS and its witness generator.
+    pub fn new(r1cs: R1CSFile<N::Field>, witness_generator: impl WitnessGenerator<N::Field> + 'static) -> Self {
+        Self { r1cs: Arc::new(r1cs), witness_generator: Arc::new(witness_generator) }
+    }
+
+    /// Returns the R1CS.
+    pub fn r1cs(&self) -> &R1CSFile<N::Field> {
+        &self.r1cs
+    }
+
+    /// Returns the number of inputs, which includes the public and private inputs.
+    pub fn num_inputs(&self) -> usize {
+        self.input_wires().len()
+    }
+
+    /// Returns the number of outputs.
+    pub fn num_outputs(&self) -> usize {
+        self.output_wires().len()
+    }
+
+    /// Returns the values of every wire, given the values of the inputs.
+    ///
+    /// # Errors
+    /// This method will halt if the witness does not extend the inputs, or does not satisfy the R1CS.
+    pub fn generate_witness(&self, inputs: &[N::Field]) -> Result<Vec<N::Field>> {
+        ensure!(inputs.len() == self.num_inputs(), "Expected {} inputs, found {}", self.num_inputs(), inputs.len());
+        // Generate the witness.
+        let witness = self.witness_generator.generate_witness(inputs)?;
+        // Ensure the witness has a value for every wire.
+        let num_wires = self.r1cs.num_wires() as usize;
+        ensure!(witness.len() == num_wires, "Expected a witness for {num_wires} wires, found {}", witness.len());
+        // Ensure the witness assigns the inputs to the input wires.
+        ensure!(witness[self.input_wires()] == *inputs, "The witness does not match the inputs");
+        // Ensure the witness satisfies the R1CS.
+        ensure!(self.r1cs.is_satisfied(&witness), "The witness does not satisfy the external R1CS");
+        Ok(witness)
+    }
+
+    /// Returns the function with the given name, which enforces the R1CS.
+    pub fn to_function(&self, name: Identifier<N>) -> Result<Function<N>> {
+        // Ensure the inputs of the function do not exceed the maximum.
+        let num_function_inputs = self.num_inputs() + self.num_witness_inputs();
+        ensure!(
+            num_function_inputs <= N::MAX_INPUTS,
+            "The external circuit requires {num_function_inputs} inputs, which exceeds the maximum of {}",
+            N::MAX_INPUTS
+        );
+        // Ensure the outputs of the function do not exceed the maximum.
+        ensure!(self.num_outputs() <= N::MAX_OUTPUTS, "The external circuit has too many outputs");
+
+        // Declare the inputs.
+        let mut function = format!("function {name}:\n");
+        let num_public_inputs = self.r1cs.num_public_inputs() as usize;
+        for index in 0..self.num_inputs() {
+            let mode = if index < num_public_inputs { "public" } else { "private" };
+            writeln!(function, "    input r{index} as field.{mode};")?;
+        }
+        for index in self.num_inputs()..num_function_inputs {
+            writeln!(function, "    input r{index} as {}.private;", Self::witness_type())?;
+        }
+
+        // Enforce the constraints, using the registers that follow the inputs.
+        let mut next_register = num_function_inputs;
+        for (a, b, c) in self.r1cs.constraints() {
+            let a = self.to_linear_combination(a, &mut next_register, &mut function)?;
+            let b = self.to_linear_combination(b, &mut next_register, &mut function)?;
+            let c = self.to_linear_combination(c, &mut next_register, &mut function)?;
+            writeln!(function, "    mul {a} {b} into r{next_register};")?;
+            writeln!(function, "    assert.eq r{next_register} {c};")?;
+            next_register += 1;
+        }
+
+        // Declare the outputs.
+        for wire in self.output_wires() {
+            writeln!(function, "    output {} as field.public;", self.to_operand(wire))?;
+        }
+        Function::from_str(function.trim_end())
+    }
+
+    /// Returns the program with the given ID, which contains the function with the given name that enforces the R1CS.
+    pub fn to_program(&self, program_id: ProgramID<N>, function_name: Identifier<N>) -> Result<Program<N>> {
+        Program::from_str(&format!("program {program_id};\n\n{}", self.to_function(function_name)?))
+    }
+
+    /// Returns the inputs of the function from `ExternalCircuit::to_function`, given the inputs of the R1CS.
+    pub fn to_function_inputs(&self, inputs: &[N::Field]) -> Result<Vec<Value<N>>> {
+        // Generate the witness.
+        let witness = self.generate_witness(inputs)?;
+        // Returns the given wire value as a plaintext.
+        let to_plaintext = |value: N::Field| Plaintext::from(Literal::Field(Field::new(value)));
+
+        // Prepare the inputs of the R1CS.
+        let inputs = inputs.iter().map(|input| Value::Plaintext(to_plaintext(*input)));
+        // Prepare the witness inputs, from the remaining wires, padded with zeros.
+        let remaining = witness[self.output_wires()].iter().chain(&witness[self.input_wires().end..]).copied();
+        let mut remaining = remaining.chain(core::iter::repeat(N::Field::zero()));
+        let witness_inputs = (0..self.num_witness_inputs()).map(|_| {
+            let rows = (0..Self::WITNESS_ROWS).map(|_| {
+                let row = remaining.by_ref().take(Self::WITNESS_COLUMNS).map(to_plaintext).collect();
+                Plaintext::Array(row, Default::default())
+            });
+            Value::Plaintext(Plaintext::Array(rows.collect(), Default::default()))
+        });
+        Ok(inputs.chain(witness_inputs).collect())
+    }
+}
+
+impl<N: Network> ExternalCircuit<N> {
+    /// The number of columns in a witness input.
+    const WITNESS_COLUMNS: usize = 32;
+    /// The number of entries in a witness input.
+    const WITNESS_ENTRIES: usize = Self::WITNESS_COLUMNS * Self::WITNESS_ROWS;
+    /// The number of rows in a witness input.
+    const WITNESS_ROWS: usize = 32;
+
+    /// Returns the type of a witness input.
+    fn witness_type() -> String {
+        format!("[[field; {}u32]; {}u32]", Self::WITNESS_COLUMNS, Self::WITNESS_ROWS)
+    }
+
+    /// Returns the number of witness inputs, which hold the outputs and the internal wires.
+    fn num_witness_inputs(&self) -> usize {
+        let num_witness_wires = self.r1cs.num_wires() as usize - self.input_wires().len() - 1;
+        num_witness_wires.div_ceil(Self::WITNESS_ENTRIES)
+    }
+
+    /// Returns the operand of the given wire, which must not be the constant wire.
+    fn to_operand(&self, wire: usize) -> String {
+        // If the wire is an input of the R1CS, return its input register.
+        if self.input_wires().contains(&wire) {
+            return format!("r{}", wire - self.input_wires().start);
+        }
+        // Otherwise, return its entry in the witness inputs.
+        let index = match self.output_wires().contains(&wire) {
+            true => wire - self.output_wires().start,
+            false => self.num_outputs() + wire - self.input_wires().end,
+        };
+        let (input, entry) = (index / Self::WITNESS_ENTRIES, index % Self::WITNESS_ENTRIES);
+        format!(
+            "r{}[{}u32][{}u32]",
+            self.num_inputs() + input,
+            entry / Self::WITNESS_COLUMNS,
+            entry % Self::WITNESS_COLUMNS
+        )
+    }
+
+    /// Writes the instructions that compute the given linear combination, and returns its operand.
+    fn to_linear_combination(
+        &self,
+        terms: &R1CSFileLC<N::Field>,
+        next_register: &mut usize,
+        function: &mut String,
+    ) -> Result<String> {
+        // Sum the terms of the constant wire.
+        let constant =
+            terms.iter().filter(|(wire, _)| *wire == 0).map(|(_, coefficient)| *coefficient).sum::<N::Field>();
+        // Scale the remaining terms.
+        let mut operands = Vec::new();
+        for (wire, coefficient) in terms.iter().filter(|(wire, _)| *wire != 0) {
+            let operand = self.to_operand(*wire as usize);
+            match coefficient.is_one() {
+                true => operands.push(operand),
+                false => {
+                    writeln!(function, "    mul {operand} {} into r{next_register};", Field::<N>::new(*coefficient))?;
+                    operands.push(format!("r{next_register}"));
+                    *next_register += 1;
+                }
+            }
+        }
+        // Include the constant term, if it is nonzero or the only term.
+        if !constant.is_zero() || operands.is_empty() {
+            operands.push(Field::<N>::new(constant).to_string());
+        }
+        // Sum the operands.
+        let mut sum = operands.remove(0);
+        for operand in operands {
+            writeln!(function, "    add {sum} {operand} into r{next_register};")?;
+            sum = format!("r{next_register}");
+            *next_register += 1;
+        }
+        Ok(sum)
+    }
+
+    /// Returns the range of the output wires, which follow the constant wire.
+    fn output_wires(&self) -> Range<usize> {
+        1..1 + self.r1cs.num_public_outputs() as usize
+    }
+
+    /// Returns the range of the input wires, which follow the output wires.
+    fn input_wires(&self) -> Range<usize> {
+        let start = self.output_wires().end;
+        start..start + self.r1cs.num_public_inputs() as usize + self.r1cs.num_private_inputs() as usize
+    }
+}
@@ -14,7 +14,7 @@
 
 use super::*;
 use console::program::{FinalizeType, Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use synthesizer_program::{Await, FinalizeRegistersState, FinalizeStoreTrait, Operand};
 use utilities::try_vm_runtime;
 
 use std::collections::HashSet;
@@ -34,7 +34,10 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match deployment.is_upgrade() {
+            true => Stack::upgrade(self, deployment.program())?,
+            false => Stack::new(self, deployment.program())?,
+        };
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -43,6 +46,12 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Insert the verifying keys");
 
+        // Ensure the programs that import an upgraded program can be recomputed.
+        if deployment.is_upgrade() {
+            self.clone().upgrade_stack(stack.clone())?;
+            lap!(timer, "Recompute the dependent stacks");
+        }
+
         // Initialize the mappings, and store their finalize operations.
         atomic_batch_scope!(store, {
             // Initialize a list for the finalize operations.
@@ -62,6 +71,10 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // Skip the mappings of a previous edition, as an upgrade preserves their contents.
+                if deployment.is_upgrade() && store.contains_mapping_confirmed(program_id, mapping.name())? {
+                    continue;
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
@@ -21,6 +21,11 @@
 mod cost;
 pub use cost::*;
 
+mod external_circuit;
+pub use external_circuit::*;
+
+pub mod lint;
+
 mod stack;
 pub use stack::*;
 
@@ -34,6 +39,7 @@ mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
+mod export;
 mod finalize;
 mod verify_deployment;
 mod verify_execution;
@@ -52,6 +58,7 @@ use ledger_block::{Deployment, Execution, Fee, Input, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
+    CallDynamic,
     Closure,
     Command,
     Finalize,
@@ -79,6 +86,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of program addresses to stacks, for dynamic calls.
+    dynamic_stacks: DynamicStacks<N>,
 }
 
 impl<N: Network> Process<N> {
@@ -88,7 +97,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            dynamic_stacks: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -131,8 +144,55 @@ impl<N: Network> Process<N> {
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_stack(&mut self, stack: Stack<N>) {
+        let stack = Arc::new(stack);
+        // Register the stack for dynamic calls.
+        self.register_dynamic_stack(&stack);
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        self.stacks.insert(*stack.program_id(), stack);
+    }
+
+    /// Replaces the stack of a program with the stack of its upgraded edition,
+    /// and recomputes the stacks of the programs that (directly or indirectly) import it.
+    #[inline]
+    pub fn upgrade_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Ensure the program exists in the process.
+        ensure!(self.contains_program(&program_id), "Program '{program_id}' does not exist");
+
+        // Recompute the stacks on a copy of the process, so that the process is unchanged on failure.
+        let mut process = self.clone();
+        // Note: The upgraded stack is recomputed as well, in case one of its imports was upgraded after it was computed.
+        process.stacks.insert(program_id, Arc::new(stack.reinitialize(self)?));
+
+        // Initialize the list of upgraded program IDs.
+        let mut upgraded = vec![program_id];
+        // Recompute the stacks that import an upgraded program.
+        // Note: As an upgrade does not change the imports of a program, every program still follows its imports.
+        for index in 0..process.stacks.len() {
+            let dependent = process.stacks[index].clone();
+            if dependent.program().imports().keys().any(|import| upgraded.contains(import)) {
+                upgraded.push(*dependent.program_id());
+                process.stacks.insert(*dependent.program_id(), Arc::new(dependent.reinitialize(&process)?));
+            }
+        }
+
+        // Update the process.
+        *self = process;
+        // Register the upgraded stacks for dynamic calls.
+        for program_id in upgraded {
+            self.register_dynamic_stack(self.get_stack(program_id)?);
+        }
+        Ok(())
+    }
+
+    /// Registers the given stack by its program address, so that it can be the callee of a dynamic call.
+    #[inline]
+    fn register_dynamic_stack(&self, stack: &Arc<Stack<N>>) {
+        // Note: A program ID always has a program address, as it is a hash of the program ID.
+        if let Ok(address) = stack.program_id().to_address() {
+            self.dynamic_stacks.write().insert(address, Arc::downgrade(stack));
+        }
     }
 }
 
@@ -143,7 +203,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            dynamic_stacks: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -181,7 +245,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            dynamic_stacks: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -202,6 +270,12 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the mapping of program addresses to stacks, for dynamic calls.
+    #[inline]
+    pub(crate) const fn dynamic_stacks(&self) -> &DynamicStacks<N> {
+        &self.dynamic_stacks
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -275,6 +349,17 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.insert_verifying_key(function_name, verifying_key)
     }
 
+    /// Inserts the given external circuit, for the given program ID and function name.
+    #[inline]
+    pub fn insert_external_circuit(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        external_circuit: ExternalCircuit<N>,
+    ) -> Result<()> {
+        self.get_stack(program_id)?.insert_external_circuit(function_name, external_circuit)
+    }
+
     /// Synthesizes the proving and verifying key for the given program ID and function name.
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::Literal;
+
+impl<N: Network> Linter<'_, N> {
+    /// Reports the checked arithmetic instructions that always halt, given the instructions (and their index).
+    /// An instruction always halts if it divides by a zero literal, or if it overflows on two literals.
+    pub(super) fn check_arithmetic<'b>(
+        &mut self,
+        scope: Scope,
+        name: &Identifier<N>,
+        instructions: impl Iterator<Item = (usize, &'b Instruction<N>)>,
+    ) {
+        for (index, instruction) in instructions {
+            // Ensure the instruction is checked arithmetic.
+            if !matches!(
+                instruction,
+                Instruction::Add(_)
+                    | Instruction::Sub(_)
+                    | Instruction::Mul(_)
+                    | Instruction::Div(_)
+                    | Instruction::Rem(_)
+            ) {
+                continue;
+            }
+
+            let opcode = instruction.opcode();
+            match instruction.operands() {
+                // Check for a division by zero.
+                [_, Operand::Literal(second)]
+                    if matches!(instruction, Instruction::Div(_) | Instruction::Rem(_)) && is_zero(second) =>
+                {
+                    let message = format!("'{opcode}' always halts, as it divides by zero");
+                    self.report(Rule::OverflowingArithmetic, scope, name, Some(index), message);
+                }
+                // Check for an overflow.
+                [Operand::Literal(first), Operand::Literal(second)] if overflows(instruction, first, second) => {
+                    let message = format!("'{opcode}' always halts, as '{first}' and '{second}' overflow");
+                    self.report(Rule::OverflowingArithmetic, scope, name, Some(index), message);
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Returns `true` if the given literal is an integer zero.
+fn is_zero<N: Network>(literal: &Literal<N>) -> bool {
+    match literal {
+        Literal::I8(integer) => **integer == 0,
+        Literal::I16(integer) => **integer == 0,
+        Literal::I32(integer) => **integer == 0,
+        Literal::I64(integer) => **integer == 0,
+        Literal::I128(integer) => **integer == 0,
+        Literal::U8(integer) => **integer == 0,
+        Literal::U16(integer) => **integer == 0,
+        Literal::U32(integer) => **integer == 0,
+        Literal::U64(integer) => **integer == 0,
+        Literal::U128(integer) => **integer == 0,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the given checked arithmetic instruction overflows on the given integer literals.
+fn overflows<N: Network>(instruction: &Instruction<N>, first: &Literal<N>, second: &Literal<N>) -> bool {
+    macro_rules! checked {
+        ($first:expr, $second:expr) => {
+            match instruction {
+                Instruction::Add(_) => $first.checked_add($second).is_none(),
+                Instruction::Sub(_) => $first.checked_sub($second).is_none(),
+                Instruction::Mul(_) => $first.checked_mul($second).is_none(),
+                // Note: A signed division (or remainder) overflows on `MIN / -1`.
+                Instruction::Div(_) => $first.checked_div($second).is_none(),
+                Instruction::Rem(_) => $first.checked_rem($second).is_none(),
+                _ => false,
+            }
+        };
+    }
+
+    match (first, second) {
+        (Literal::I8(first), Literal::I8(second)) => checked!(**first, **second),
+        (Literal::I16(first), Literal::I16(second)) => checked!(**first, **second),
+        (Literal::I32(first), Literal::I32(second)) => checked!(**first, **second),
+        (Literal::I64(first), Literal::I64(second)) => checked!(**first, **second),
+        (Literal::I128(first), Literal::I128(second)) => checked!(**first, **second),
+        (Literal::U8(first), Literal::U8(second)) => checked!(**first, **second),
+        (Literal::U16(first), Literal::U16(second)) => checked!(**first, **second),
+        (Literal::U32(first), Literal::U32(second)) => checked!(**first, **second),
+        (Literal::U64(first), Literal::U64(second)) => checked!(**first, **second),
+        (Literal::U128(first), Literal::U128(second)) => checked!(**first, **second),
+        _ => false,
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_program::CastType;
+
+impl<N: Network> Linter<'_, N> {
+    /// Reports the casts of an operand into the type that it already has, given the instructions (and their index)
+    /// in a block, and a lookup for the plaintext type of an operand.
+    pub(super) fn check_casts<'b>(
+        &mut self,
+        scope: Scope,
+        name: &Identifier<N>,
+        instructions: impl Iterator<Item = (usize, &'b Instruction<N>)>,
+        get_type: impl Fn(&Operand<N>) -> Result<Option<PlaintextType<N>>>,
+    ) -> Result<()> {
+        for (index, instruction) in instructions {
+            // Retrieve the cast type.
+            let cast_type = match instruction {
+                Instruction::Cast(cast) => cast.cast_type(),
+                Instruction::CastLossy(cast_lossy) => cast_lossy.cast_type(),
+                _ => continue,
+            };
+            // Check if a single operand is cast into its own plaintext type.
+            if let (CastType::Plaintext(plaintext_type), [operand]) = (cast_type, instruction.operands()) {
+                if get_type(operand)?.as_ref() == Some(plaintext_type) {
+                    let message = format!("Casting '{operand}' into '{plaintext_type}' is redundant");
+                    self.report(Rule::RedundantCast, scope, name, Some(index), message);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Linter<'_, N> {
+    /// Reports the finalize block of the function with the given name, if its cost exceeds the configured maximum.
+    pub(super) fn check_finalize_cost(&mut self, name: &Identifier<N>) -> Result<()> {
+        // Retrieve the finalize cost.
+        let finalize_cost = self.stack.get_finalize_cost(name)?;
+        // Retrieve the maximum finalize cost.
+        let max_finalize_cost = self.config.max_finalize_cost();
+
+        if finalize_cost > max_finalize_cost {
+            let message = format!(
+                "The finalize cost of '{name}' ({finalize_cost} microcredits) exceeds {max_finalize_cost} microcredits"
+            );
+            self.report(Rule::FinalizeCost, Scope::Finalize, name, None, message);
+        }
+        Ok(())
+    }
+}
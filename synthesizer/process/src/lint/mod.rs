@@ -0,0 +1,568 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod arithmetic;
+mod casts;
+mod cost;
+mod positions;
+mod registers;
+
+use crate::{Process, Stack, StackProgramTypes};
+
+use console::{
+    network::prelude::*,
+    program::{FinalizeType, Identifier, PlaintextType, ProgramID, Register, RegisterType, ValueType},
+};
+use synthesizer_program::{
+    Closure,
+    Command,
+    CommandTrait,
+    Finalize,
+    Function,
+    Instruction,
+    InstructionTrait,
+    Operand,
+    StackProgram,
+};
+
+use indexmap::IndexMap;
+
+/// A lint rule, which may be allowed, or reported as a warning or an error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// A register that is declared as an input or assigned, but is never used.
+    UnusedRegister,
+    /// A finalize position that is never branched to, or commands that are skipped by a branch that is always taken.
+    UnreachablePosition,
+    /// Checked arithmetic on literals that always halts, such as an overflow or a division by zero.
+    OverflowingArithmetic,
+    /// A cast of an operand into the type that it already has.
+    RedundantCast,
+    /// A finalize block whose cost exceeds the configured maximum.
+    FinalizeCost,
+}
+
+impl Rule {
+    /// The list of all lint rules.
+    pub const ALL: [Rule; 5] = [
+        Rule::UnusedRegister,
+        Rule::UnreachablePosition,
+        Rule::OverflowingArithmetic,
+        Rule::RedundantCast,
+        Rule::FinalizeCost,
+    ];
+
+    /// Returns the name of the rule.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Rule::UnusedRegister => "unused-register",
+            Rule::UnreachablePosition => "unreachable-position",
+            Rule::OverflowingArithmetic => "overflowing-arithmetic",
+            Rule::RedundantCast => "redundant-cast",
+            Rule::FinalizeCost => "finalize-cost",
+        }
+    }
+
+    /// Returns the severity of the rule, if it is not configured.
+    pub const fn default_severity(&self) -> Severity {
+        match self {
+            // Note: Arithmetic that always halts makes the function (or finalize block) unusable.
+            Rule::OverflowingArithmetic => Severity::Deny,
+            _ => Severity::Warn,
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Returns the rule with the given name.
+    fn from_str(name: &str) -> Result<Self> {
+        match Rule::ALL.into_iter().find(|rule| rule.name() == name) {
+            Some(rule) => Ok(rule),
+            None => bail!("Unknown lint rule '{name}'"),
+        }
+    }
+}
+
+impl Display for Rule {
+    /// Prints the rule name.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The severity of a lint rule.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// The rule is not checked.
+    Allow,
+    /// A violation of the rule is reported as a warning.
+    Warn,
+    /// A violation of the rule is reported as an error.
+    Deny,
+}
+
+impl Display for Severity {
+    /// Prints the severity, as it is reported in a diagnostic.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Severity::Allow => write!(f, "allow"),
+            Severity::Warn => write!(f, "warning"),
+            Severity::Deny => write!(f, "error"),
+        }
+    }
+}
+
+/// The configuration of the lint rules.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The severities of the configured rules.
+    severities: IndexMap<Rule, Severity>,
+    /// The maximum finalize cost (in microcredits), for the `finalize-cost` rule.
+    max_finalize_cost: u64,
+}
+
+impl Default for Config {
+    /// Returns the default configuration, which uses the default severity of every rule.
+    fn default() -> Self {
+        Self { severities: IndexMap::new(), max_finalize_cost: Self::DEFAULT_MAX_FINALIZE_COST }
+    }
+}
+
+impl Config {
+    /// The default maximum finalize cost (in microcredits), which is 10 credits.
+    pub const DEFAULT_MAX_FINALIZE_COST: u64 = 10_000_000;
+
+    /// Returns the configuration, with the given severity for the given rule.
+    pub fn with_severity(mut self, rule: Rule, severity: Severity) -> Self {
+        self.severities.insert(rule, severity);
+        self
+    }
+
+    /// Returns the configuration, with the given maximum finalize cost (in microcredits).
+    pub fn with_max_finalize_cost(mut self, max_finalize_cost: u64) -> Self {
+        self.max_finalize_cost = max_finalize_cost;
+        self
+    }
+
+    /// Returns the severity of the given rule.
+    pub fn severity(&self, rule: Rule) -> Severity {
+        self.severities.get(&rule).copied().unwrap_or_else(|| rule.default_severity())
+    }
+
+    /// Returns the maximum finalize cost (in microcredits).
+    pub const fn max_finalize_cost(&self) -> u64 {
+        self.max_finalize_cost
+    }
+}
+
+/// The kind of block that a diagnostic is located in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// A closure.
+    Closure,
+    /// A function.
+    Function,
+    /// The finalize block of a function.
+    Finalize,
+}
+
+impl Display for Scope {
+    /// Prints the scope.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Scope::Closure => write!(f, "closure"),
+            Scope::Function => write!(f, "function"),
+            Scope::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// The location of a diagnostic in a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The name of the closure or function.
+    name: Identifier<N>,
+    /// The kind of block.
+    scope: Scope,
+    /// The index of the instruction or command in the block, if the location is not the block itself.
+    index: Option<usize>,
+}
+
+impl<N: Network> Location<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the closure or function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the kind of block.
+    pub const fn scope(&self) -> Scope {
+        self.scope
+    }
+
+    /// Returns the index of the instruction or command in the block, if the location is not the block itself.
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl<N: Network> Display for Location<N> {
+    /// Prints the location, such as `token.aleo/mint (finalize, command 2)`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match (self.scope, self.index) {
+            (_, None) => write!(f, "{}/{} ({})", self.program_id, self.name, self.scope),
+            (Scope::Finalize, Some(index)) => {
+                write!(f, "{}/{} (finalize, command {index})", self.program_id, self.name)
+            }
+            (scope, Some(index)) => write!(f, "{}/{} ({scope}, instruction {index})", self.program_id, self.name),
+        }
+    }
+}
+
+impl<N: Network> Serialize for Location<N> {
+    /// Serializes the location into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut location = serializer.serialize_struct("Location", 4)?;
+        location.serialize_field("program", &self.program_id.to_string())?;
+        location.serialize_field("name", &self.name.to_string())?;
+        location.serialize_field("scope", &self.scope.to_string())?;
+        location.serialize_field("index", &self.index)?;
+        location.end()
+    }
+}
+
+/// A violation of a lint rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic<N: Network> {
+    /// The violated rule.
+    rule: Rule,
+    /// The configured severity of the rule.
+    severity: Severity,
+    /// The location of the violation.
+    location: Location<N>,
+    /// The message describing the violation.
+    message: String,
+}
+
+impl<N: Network> Diagnostic<N> {
+    /// Returns the violated rule.
+    pub const fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Returns the configured severity of the rule.
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the location of the violation.
+    pub const fn location(&self) -> &Location<N> {
+        &self.location
+    }
+
+    /// Returns the message describing the violation.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns `true` if the diagnostic is reported as an error.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Deny
+    }
+}
+
+impl<N: Network> Display for Diagnostic<N> {
+    /// Prints the diagnostic, such as `warning[unused-register]: ... (at token.aleo/mint (function))`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}[{}]: {} (at {})", self.severity, self.rule, self.message, self.location)
+    }
+}
+
+impl<N: Network> Serialize for Diagnostic<N> {
+    /// Serializes the diagnostic into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut diagnostic = serializer.serialize_struct("Diagnostic", 4)?;
+        diagnostic.serialize_field("rule", self.rule.name())?;
+        diagnostic.serialize_field("severity", &self.severity.to_string())?;
+        diagnostic.serialize_field("location", &self.location)?;
+        diagnostic.serialize_field("message", &self.message)?;
+        diagnostic.end()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Lints the program with the given program ID, and returns the diagnostics in program order.
+    #[inline]
+    pub fn lint(&self, program_id: &ProgramID<N>, config: &Config) -> Result<Vec<Diagnostic<N>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(*program_id)?;
+        // Lint the program.
+        let mut linter = Linter { stack, config, diagnostics: Vec::new() };
+        linter.lint_program()?;
+        Ok(linter.diagnostics)
+    }
+}
+
+/// A linter for the program of a stack.
+struct Linter<'a, N: Network> {
+    /// The stack of the program.
+    stack: &'a Stack<N>,
+    /// The configuration of the lint rules.
+    config: &'a Config,
+    /// The diagnostics reported so far.
+    diagnostics: Vec<Diagnostic<N>>,
+}
+
+impl<N: Network> Linter<'_, N> {
+    /// Lints the closures and functions of the program, in program order.
+    fn lint_program(&mut self) -> Result<()> {
+        let program = self.stack.program();
+        for closure in program.closures().values() {
+            self.lint_closure(closure)?;
+        }
+        for function in program.functions().values() {
+            self.lint_function(function)?;
+            if let Some(finalize) = function.finalize_logic() {
+                self.lint_finalize(function.name(), finalize)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Lints the given closure.
+    fn lint_closure(&mut self, closure: &Closure<N>) -> Result<()> {
+        let (stack, name) = (self.stack, closure.name());
+        // Retrieve the register types.
+        let register_types = stack.get_register_types(name)?;
+
+        self.check_unused_registers(
+            Scope::Closure,
+            name,
+            closure.inputs().iter().map(|input| input.register().clone()).collect(),
+            closure
+                .instructions()
+                .iter()
+                .map(|instruction| (instruction.operands().to_vec(), instruction.destinations())),
+            closure.outputs().iter().map(|output| output.operand()),
+        );
+        self.check_arithmetic(Scope::Closure, name, closure.instructions().iter().enumerate());
+        self.check_casts(
+            Scope::Closure,
+            name,
+            closure.instructions().iter().enumerate(),
+            |operand| match register_types.get_type_from_operand(stack, operand)? {
+                RegisterType::Plaintext(plaintext_type) => Ok(Some(plaintext_type)),
+                _ => Ok(None),
+            },
+        )
+    }
+
+    /// Lints the given function.
+    fn lint_function(&mut self, function: &Function<N>) -> Result<()> {
+        let (stack, name) = (self.stack, function.name());
+        // Retrieve the register types.
+        let register_types = stack.get_register_types(name)?;
+
+        self.check_unused_registers(
+            Scope::Function,
+            name,
+            function
+                .inputs()
+                .iter()
+                // Note: A record input may be left unused on purpose, as consuming it burns the record.
+                .filter(|input| !matches!(input.value_type(), ValueType::Record(_) | ValueType::ExternalRecord(_)))
+                .map(|input| input.register().clone())
+                .collect(),
+            function
+                .instructions()
+                .iter()
+                .map(|instruction| (instruction.operands().to_vec(), instruction.destinations())),
+            function.outputs().iter().map(|output| output.operand()),
+        );
+        self.check_arithmetic(Scope::Function, name, function.instructions().iter().enumerate());
+        self.check_casts(Scope::Function, name, function.instructions().iter().enumerate(), |operand| {
+            match register_types.get_type_from_operand(stack, operand)? {
+                RegisterType::Plaintext(plaintext_type) => Ok(Some(plaintext_type)),
+                _ => Ok(None),
+            }
+        })
+    }
+
+    /// Lints the given finalize block, of the function with the given name.
+    fn lint_finalize(&mut self, name: &Identifier<N>, finalize: &Finalize<N>) -> Result<()> {
+        let stack = self.stack;
+        // Retrieve the finalize types.
+        let finalize_types = stack.get_finalize_types(name)?;
+        // Retrieve the instructions in the finalize block, with their index.
+        let instructions = || {
+            finalize.commands().iter().enumerate().filter_map(|(index, command)| match command {
+                Command::Instruction(instruction) => Some((index, instruction)),
+                _ => None,
+            })
+        };
+
+        self.check_unused_registers(
+            Scope::Finalize,
+            name,
+            finalize.inputs().iter().map(|input| input.register().clone()).collect(),
+            finalize.commands().iter().map(|command| (command.operands(), command.destinations())),
+            std::iter::empty(),
+        );
+        self.check_positions(name, finalize);
+        self.check_arithmetic(Scope::Finalize, name, instructions());
+        self.check_casts(Scope::Finalize, name, instructions(), |operand| {
+            match finalize_types.get_type_from_operand(stack, operand)? {
+                FinalizeType::Plaintext(plaintext_type) => Ok(Some(plaintext_type)),
+                _ => Ok(None),
+            }
+        })?;
+        self.check_finalize_cost(name)
+    }
+
+    /// Reports a violation of the given rule, if the rule is not allowed.
+    fn report(&mut self, rule: Rule, scope: Scope, name: &Identifier<N>, index: Option<usize>, message: String) {
+        let severity = self.config.severity(rule);
+        if severity != Severity::Allow {
+            let location = Location { program_id: *self.stack.program_id(), name: *name, scope, index };
+            self.diagnostics.push(Diagnostic { rule, severity, location, message });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the diagnostics of the given program, as (rule, location) pairs.
+    fn lint(program: &str, config: &Config) -> Vec<(Rule, String)> {
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let diagnostics = process.lint(program.id(), config).unwrap();
+        diagnostics.iter().map(|diagnostic| (diagnostic.rule(), diagnostic.location().to_string())).collect()
+    }
+
+    #[test]
+    fn test_lint() {
+        let program = r"
+program lint.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+closure helper:
+    input r0 as u64;
+    input r1 as u64;
+    cast r0 into r2 as u64;
+    output r2 as u64;
+
+function clean:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.public;
+
+function noisy:
+    input r0 as u64.public;
+    input r1 as u8.public;
+    add 255u8 1u8 into r2;
+    div r0 0u64 into r3;
+    output r3 as u64.public;
+
+function bump:
+    input r0 as u64.public;
+    async bump r0 into r1;
+    output r1 as lint.aleo/bump.future;
+
+finalize bump:
+    input r0 as u64.public;
+    branch.eq true true to end;
+    get.or_use counter[0u8] 0u64 into r2;
+    add r2 r0 into r3;
+    set r3 into counter[0u8];
+    position end;
+    position unused;";
+        let diagnostics = lint(program, &Config::default());
+        assert_eq!(diagnostics, vec![
+            (Rule::UnusedRegister, "lint.aleo/helper (closure)".to_string()),
+            (Rule::RedundantCast, "lint.aleo/helper (closure, instruction 0)".to_string()),
+            (Rule::UnusedRegister, "lint.aleo/noisy (function)".to_string()),
+            (Rule::UnusedRegister, "lint.aleo/noisy (function, instruction 0)".to_string()),
+            (Rule::OverflowingArithmetic, "lint.aleo/noisy (function, instruction 0)".to_string()),
+            (Rule::OverflowingArithmetic, "lint.aleo/noisy (function, instruction 1)".to_string()),
+            (Rule::UnreachablePosition, "lint.aleo/bump (finalize, command 0)".to_string()),
+            (Rule::UnreachablePosition, "lint.aleo/bump (finalize, command 5)".to_string()),
+        ]);
+
+        // Ensure the rules can be allowed, and the finalize cost can be limited.
+        let config = Config::default()
+            .with_severity(Rule::UnusedRegister, Severity::Allow)
+            .with_severity(Rule::OverflowingArithmetic, Severity::Allow)
+            .with_severity(Rule::UnreachablePosition, Severity::Allow)
+            .with_severity(Rule::RedundantCast, Severity::Allow)
+            .with_max_finalize_cost(0);
+        assert_eq!(lint(program, &config), vec![(Rule::FinalizeCost, "lint.aleo/bump (finalize)".to_string())]);
+    }
+
+    #[test]
+    fn test_diagnostic_json() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program json.aleo;
+
+function main:
+    input r0 as u8.public;
+    add 255u8 1u8 into r1;
+    output r1 as u8.public;",
+        )
+        .unwrap();
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure the diagnostics are reported with the configured severity.
+        let config = Config::default().with_severity(Rule::UnusedRegister, Severity::Deny);
+        let diagnostics = process.lint(program.id(), &config).unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.is_error()));
+
+        // Ensure the diagnostics serialize into machine-readable JSON.
+        let json = serde_json::to_value(&diagnostics[0]).unwrap();
+        assert_eq!(json["rule"], "unused-register");
+        assert_eq!(json["severity"], "error");
+        assert_eq!(json["location"]["program"], "json.aleo");
+        assert_eq!(json["location"]["name"], "main");
+        assert_eq!(json["location"]["scope"], "function");
+        assert_eq!(json["location"]["index"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_rule_from_str() {
+        for rule in Rule::ALL {
+            assert_eq!(Rule::from_str(rule.name()).unwrap(), rule);
+        }
+        assert!(Rule::from_str("unknown-rule").is_err());
+    }
+}
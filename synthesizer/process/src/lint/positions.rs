@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> Linter<'_, N> {
+    /// Reports the positions in the given finalize block that are never branched to,
+    /// and the branches on literals that are either always or never taken.
+    pub(super) fn check_positions(&mut self, name: &Identifier<N>, finalize: &Finalize<N>) {
+        // Collect the positions that are branched to.
+        let targets = finalize.commands().iter().filter_map(|command| command.branch_to()).collect::<HashSet<_>>();
+
+        for (index, command) in finalize.commands().iter().enumerate() {
+            // Retrieve the operands and the position of a branch, and whether it is taken when the operands are equal.
+            let (first, second, position, is_eq) = match command {
+                Command::Position(position) => {
+                    if !targets.contains(position.name()) {
+                        let message = format!("Position '{}' is never branched to", position.name());
+                        self.report(Rule::UnreachablePosition, Scope::Finalize, name, Some(index), message);
+                    }
+                    continue;
+                }
+                Command::BranchEq(branch) => (branch.first(), branch.second(), branch.position(), true),
+                Command::BranchNeq(branch) => (branch.first(), branch.second(), branch.position(), false),
+                _ => continue,
+            };
+
+            // Check if the branch is decided by literals.
+            if let (Operand::Literal(first), Operand::Literal(second)) = (first, second) {
+                // If the branch is always taken, the commands up to the position are unreachable.
+                if (first == second) == is_eq {
+                    if let Some(target) = finalize.positions().get(position).filter(|target| **target > index + 1) {
+                        let message = format!(
+                            "Commands {} to {} are unreachable, as the branch to '{position}' is always taken",
+                            index + 1,
+                            target - 1
+                        );
+                        self.report(Rule::UnreachablePosition, Scope::Finalize, name, Some(index), message);
+                    }
+                } else {
+                    let message = format!("The branch to '{position}' is never taken");
+                    self.report(Rule::UnreachablePosition, Scope::Finalize, name, Some(index), message);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> Linter<'_, N> {
+    /// Reports the input registers and destination registers in a block that are never used,
+    /// given the inputs, the (operands, destinations) of each statement, and the outputs of the block.
+    pub(super) fn check_unused_registers<'b>(
+        &mut self,
+        scope: Scope,
+        name: &Identifier<N>,
+        inputs: Vec<Register<N>>,
+        statements: impl Iterator<Item = (Vec<Operand<N>>, Vec<Register<N>>)>,
+        outputs: impl Iterator<Item = &'b Operand<N>>,
+    ) {
+        let statements = statements.collect::<Vec<_>>();
+
+        // Collect the locators of the registers that are used as an operand.
+        let mut used = HashSet::new();
+        let mut mark_used = |operand: &Operand<N>| {
+            if let Operand::Register(register) = operand {
+                used.insert(register.locator());
+            }
+        };
+        statements.iter().flat_map(|(operands, _)| operands).for_each(&mut mark_used);
+        outputs.for_each(mark_used);
+
+        // Report the input registers that are never used.
+        for register in inputs {
+            if !used.contains(&register.locator()) {
+                self.report(
+                    Rule::UnusedRegister,
+                    scope,
+                    name,
+                    None,
+                    format!("Input register '{register}' is never used"),
+                );
+            }
+        }
+        // Report the destination registers that are never used.
+        for (index, (_, destinations)) in statements.iter().enumerate() {
+            for register in destinations.iter().filter(|register| !used.contains(&register.locator())) {
+                let message = format!("Register '{register}' is assigned, but is never used");
+                self.report(Rule::UnusedRegister, scope, name, Some(index), message);
+            }
+        }
+    }
+}
@@ -0,0 +1,412 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    program::{compute_function_id, Literal, Plaintext, PlaintextType},
+    types::U16,
+};
+use synthesizer_program::{CallDynamic, Program};
+
+impl<N: Network> CallTrait<N> for CallDynamic<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::evaluate");
+
+        // Load the operands values.
+        let inputs: Vec<_> = self.operands().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Retrieve the callee stack from the program address.
+        let substack = stack.get_dynamic_stack(&program_address(&inputs[0])?)?;
+        // Ensure the callee implements the interface.
+        check_callee(stack, substack.as_ref(), self)?;
+        // Retrieve the function.
+        let function = substack.program().get_function_ref(self.function())?;
+        // Ensure the number of inputs matches the number of input statements.
+        if function.inputs().len() != inputs.len() - 1 {
+            bail!("Expected {} inputs, found {}", function.inputs().len(), inputs.len() - 1)
+        }
+        lap!(timer, "Retrieved the substack and function");
+
+        // Set the (console) caller.
+        let console_caller = Some(*stack.program_id());
+        // Evaluate the function.
+        let response = substack.evaluate_function::<A>(registers.call_stack(), console_caller)?;
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in response.outputs().iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output.clone())?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::execute");
+
+        // Load the operands values.
+        let inputs: Vec<_> =
+            self.operands().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        // Retrieve the program address, and the inputs of the function.
+        let (address, inputs) = match inputs.split_first() {
+            Some((
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Address(address), _)),
+                inputs,
+            )) => (address.clone(), inputs.to_vec()),
+            _ => bail!("Expected the program address of '{}' to be an address", Self::opcode()),
+        };
+
+        // Retrieve the function signature.
+        let signature = stack.program().get_interface(self.interface())?.get_function(self.function())?;
+        // Retrieve the number of inputs.
+        let num_inputs = signature.inputs().len();
+        // Ensure the number of inputs matches the number of input statements.
+        if num_inputs != inputs.len() {
+            bail!("Expected {} inputs, found {}", num_inputs, inputs.len())
+        }
+        lap!(timer, "Retrieve the function signature");
+
+        // If we are not handling the root request, retrieve the root request's tvk
+        let root_tvk = registers.root_tvk().ok();
+
+        // Retrieve the number of public variables in the circuit.
+        let num_public = A::num_public();
+
+        // Indicate that external calls are never a root request.
+        let is_root = false;
+
+        use circuit::Eject;
+        // Eject the existing circuit.
+        let r1cs = A::eject_r1cs_and_reset();
+        let (program_id, request, response) = {
+            // Eject the program address.
+            let console_address = address.eject_value();
+            // Eject the circuit inputs.
+            let inputs = inputs.eject_value();
+
+            // Set the (console) caller.
+            let console_caller = Some(*stack.program_id());
+
+            match registers.call_stack() {
+                // If the circuit is in authorize mode, then add the call to the stack.
+                CallStack::Authorize(_, private_key, authorization) => {
+                    // Retrieve the callee stack, and ensure the callee implements the interface.
+                    let substack = stack.get_dynamic_stack(&console_address)?;
+                    check_callee(stack, substack.as_ref(), self)?;
+
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *substack.program_id(),
+                        *self.function(),
+                        inputs.iter(),
+                        signature.inputs(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Add the request to the authorization.
+                    authorization.push(request.clone());
+
+                    // Execute the request.
+                    let response = substack.execute_function::<A, R>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the program ID, request, and response.
+                    (*substack.program_id(), request, response)
+                }
+                CallStack::PackageRun(_, private_key, ..) => {
+                    // Retrieve the callee stack, and ensure the callee implements the interface.
+                    let substack = stack.get_dynamic_stack(&console_address)?;
+                    check_callee(stack, substack.as_ref(), self)?;
+
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *substack.program_id(),
+                        *self.function(),
+                        inputs.iter(),
+                        signature.inputs(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Evaluate the request.
+                    let response = substack.execute_function::<A, _>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the program ID, request, and response.
+                    (*substack.program_id(), request, response)
+                }
+                // If the circuit is in synthesize or check deployment mode, then sample the call.
+                // Note: The callee is only known at runtime, so the call is made to this program as a placeholder.
+                // The circuit is the same for every callee, as the callee is determined by the public inputs.
+                CallStack::Synthesize(_, private_key, _) | CallStack::CheckDeployment(_, private_key, ..) => {
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *stack.program_id(),
+                        *self.function(),
+                        inputs.iter(),
+                        signature.inputs(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Compute the address.
+                    let address = Address::try_from(&private_key)?;
+                    // Sample dummy outputs.
+                    let outputs = signature
+                        .outputs()
+                        .iter()
+                        .map(|output_type| stack.sample_value(&address, output_type, rng))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    // Compute the response.
+                    let response = crate::Response::new(
+                        request.network_id(),
+                        stack.program_id(),
+                        self.function(),
+                        request.inputs().len(),
+                        request.tvk(),
+                        request.tcm(),
+                        outputs,
+                        signature.outputs(),
+                        &vec![None; signature.outputs().len()],
+                    )?;
+
+                    // Return the program ID, request, and response.
+                    (*stack.program_id(), request, response)
+                }
+                // If the circuit is in evaluate mode, then throw an error.
+                CallStack::Evaluate(..) => {
+                    bail!("Cannot 'execute' a function in 'evaluate' mode.")
+                }
+                // If the circuit is in execute mode, then evaluate and execute the instructions.
+                CallStack::Execute(authorization, ..) => {
+                    // Retrieve the callee stack, and ensure the callee implements the interface.
+                    let substack = stack.get_dynamic_stack(&console_address)?;
+                    check_callee(stack, substack.as_ref(), self)?;
+
+                    // Retrieve the next request (without popping it).
+                    let request = authorization.peek_next()?;
+                    // Ensure the request is for the callee.
+                    ensure!(
+                        request.program_id() == substack.program_id() && request.function_name() == self.function(),
+                        "The request for '{}/{}' does not match the 'call.dynamic' to '{}'",
+                        request.program_id(),
+                        request.function_name(),
+                        substack.program_id()
+                    );
+                    // Ensure the inputs match the original inputs.
+                    request.inputs().iter().zip_eq(&inputs).try_for_each(|(request_input, input)| {
+                        ensure!(request_input == input, "Inputs do not match in a 'call.dynamic' instruction.");
+                        Ok(())
+                    })?;
+
+                    // Evaluate the function, and load the outputs.
+                    let console_response =
+                        substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
+                    // Execute the request.
+                    let response =
+                        substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
+                    // Ensure the values are equal.
+                    if console_response.outputs() != response.outputs() {
+                        #[cfg(debug_assertions)]
+                        eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
+                        bail!("Function '{}' outputs do not match in a 'call.dynamic' instruction.", self.function())
+                    }
+                    // Return the program ID, request, and response.
+                    (*substack.program_id(), request, response)
+                }
+            }
+        };
+        lap!(timer, "Computed the request and response");
+
+        // Compute the function ID of the callee.
+        let function_id = compute_function_id(&U16::new(N::ID), &program_id, self.function())?;
+
+        // Inject the existing circuit.
+        A::inject_r1cs(r1cs);
+
+        use circuit::Inject;
+
+        // Ensure the number of public variables remains the same.
+        ensure!(A::num_public() == num_public, "Forbidden: 'call.dynamic' injected excess public variables");
+
+        // Inject the program address of the callee as `Mode::Public`.
+        // Note: The verifier derives the program address and function ID from the transition of the callee.
+        let program_address = circuit::Address::new(circuit::Mode::Public, program_id.to_address()?);
+        // Ensure the program address of the callee matches the program address operand.
+        A::assert(program_address.is_equal(&address));
+        // Inject the function ID of the callee as `Mode::Public`.
+        let function_id = circuit::Field::new(circuit::Mode::Public, function_id);
+
+        // Inject the `signer` (from the request) as `Mode::Private`.
+        let signer = circuit::Address::new(circuit::Mode::Private, *request.signer());
+        // Inject the `sk_tag` (from the request) as `Mode::Private`.
+        let sk_tag = circuit::Field::new(circuit::Mode::Private, *request.sk_tag());
+        // Inject the `tvk` (from the request) as `Mode::Private`.
+        let tvk = circuit::Field::new(circuit::Mode::Private, *request.tvk());
+        // Inject the `tcm` (from the request) as `Mode::Public`.
+        let tcm = circuit::Field::new(circuit::Mode::Public, *request.tcm());
+        // Compute the transition commitment as `Hash(tvk)`.
+        let candidate_tcm = A::hash_psd2(&[tvk.clone()]);
+        // Ensure the transition commitment matches the computed transition commitment.
+        A::assert_eq(&tcm, candidate_tcm);
+        // Inject the input IDs (from the request) as `Mode::Public`.
+        let input_ids = request
+            .input_ids()
+            .iter()
+            .map(|input_id| circuit::InputID::new(circuit::Mode::Public, *input_id))
+            .collect::<Vec<_>>();
+
+        // Ensure the candidate input IDs match their computed inputs.
+        // Note: The inputs of a dynamic call are plaintexts, so the program ID of the callee is not required.
+        let (check_input_ids, _) = circuit::Request::check_input_ids_with_function_id::<false>(
+            &function_id,
+            None,
+            &input_ids,
+            &inputs,
+            signature.inputs(),
+            &signer,
+            &sk_tag,
+            &tvk,
+            &tcm,
+            None,
+        );
+        A::assert(check_input_ids);
+        lap!(timer, "Checked the input ids");
+
+        // Inject the outputs as `Mode::Private` (with the 'tcm' and output IDs as `Mode::Public`).
+        let outputs = circuit::Response::process_outputs_from_callback_with_function_id(
+            &function_id,
+            None,
+            num_inputs,
+            &tvk,
+            &tcm,
+            response.outputs().to_vec(),
+            signature.outputs(),
+        );
+        lap!(timer, "Checked the outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store_circuit(stack, register, output)?;
+        }
+        lap!(timer, "Assigned the outputs to registers");
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+/// Returns the program address in the given operand value of a dynamic call.
+fn program_address<N: Network>(value: &Value<N>) -> Result<Address<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Address(address), _)) => Ok(*address),
+        _ => bail!("Expected the program address of 'call.dynamic' to be an address, found '{value}'"),
+    }
+}
+
+/// Ensures the callee of the given dynamic call implements the interface of the call,
+/// with the same struct definitions as the caller.
+pub(crate) fn check_callee<N: Network>(
+    caller: &impl StackProgram<N>,
+    callee: &impl StackProgram<N>,
+    call: &CallDynamic<N>,
+) -> Result<()> {
+    // Ensure the callee is not the caller.
+    ensure!(caller.program_id() != callee.program_id(), "'{}' cannot dynamically call itself", caller.program_id());
+    // Retrieve the interface.
+    let interface = caller.program().get_interface(call.interface())?;
+    // Ensure the callee implements the interface.
+    ensure!(
+        callee.program().implements_interface(interface),
+        "'{}' does not implement interface '{}' of '{}'",
+        callee.program_id(),
+        interface.name(),
+        caller.program_id()
+    );
+    // Ensure the callee defines the structs in the function signature as the caller does.
+    let signature = interface.get_function(call.function())?;
+    for value_type in signature.inputs().iter().chain(signature.outputs()) {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => check_structs(caller.program(), callee.program(), plaintext_type)?,
+            _ => bail!("Interface '{}' cannot contain the type '{value_type}'", interface.name()),
+        }
+    }
+    Ok(())
+}
+
+/// Ensures the callee program defines the structs in the given plaintext type as the caller program does.
+fn check_structs<N: Network>(
+    caller: &Program<N>,
+    callee: &Program<N>,
+    plaintext_type: &PlaintextType<N>,
+) -> Result<()> {
+    match plaintext_type {
+        PlaintextType::Literal(..) => Ok(()),
+        PlaintextType::Struct(struct_name) => {
+            // Retrieve the struct of the caller.
+            let struct_ = caller.get_struct(struct_name)?;
+            // Ensure the struct of the callee matches.
+            ensure!(
+                callee.get_struct(struct_name).ok() == Some(struct_),
+                "Struct '{struct_name}' in '{}' does not match the struct in '{}'",
+                callee.id(),
+                caller.id()
+            );
+            // Ensure the structs in the members match.
+            struct_.members().values().try_for_each(|member_type| check_structs(caller, callee, member_type))
+        }
+        PlaintextType::Array(array_type) => check_structs(caller, callee, array_type.base_element_type()),
+        PlaintextType::Vector(vector_type) => check_structs(caller, callee, vector_type.base_element_type()),
+        PlaintextType::Enum(enum_name) => bail!("Enum '{enum_name}' is not supported in a dynamic call"),
+    }
+}
@@ -84,6 +84,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
                     // If the operand is the network id, throw an error.
                     Operand::NetworkID => bail!("Cannot retrieve the network ID from a closure scope."),
+                    // If the operand is the block timestamp, previous block hash, or block round, throw an error.
+                    operand @ (Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound) => {
+                        bail!("Cannot retrieve '{operand}' from a closure scope.")
+                    }
                 }
             })
             .collect();
@@ -166,6 +170,15 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Ensure the request is well-formed.
         ensure!(request.verify(&function.input_types(), is_root), "Request is invalid");
+        // Ensure each input record belongs to the signer, or is held in custody by the calling program.
+        for (input, input_type) in inputs.iter().zip_eq(&function.input_types()) {
+            if let (Value::Record(record), ValueType::Record(..)) = (input, input_type) {
+                ensure!(
+                    **record.owner() == signer || **record.owner() == caller,
+                    "Input record must belong to the signer or to the calling program"
+                );
+            }
+        }
         lap!(timer, "Verify the request");
 
         // Store the inputs.
@@ -182,6 +195,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                Instruction::CallDynamic(call) => CallTrait::evaluate(call, self, &mut registers),
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
@@ -217,6 +232,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
                     // If the operand is the network id, throw an error.
                     Operand::NetworkID => bail!("Cannot retrieve the network ID from a function scope."),
+                    // If the operand is the block timestamp, previous block hash, or block round, throw an error.
+                    operand @ (Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound) => {
+                        bail!("Cannot retrieve '{operand}' from a function scope.")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -119,6 +119,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a closure scope")
                     }
+                    // If the operand is the block timestamp, previous block hash, or block round, throw an error.
+                    operand @ (Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound) => {
+                        bail!("Illegal operation: cannot retrieve '{operand}' in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -205,6 +209,16 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Ensure the request is well-formed.
         ensure!(console_request.verify(&input_types, console_is_root), "Request is invalid");
+        // Ensure each input record belongs to the signer, or is held in custody by the calling program.
+        let console_signer = *console_request.signer();
+        for (input, input_type) in console_request.inputs().iter().zip_eq(&input_types) {
+            if let (Value::Record(record), ValueType::Record(..)) = (input, input_type) {
+                ensure!(
+                    **record.owner() == console_signer || (!console_is_root && **record.owner() == console_parent),
+                    "Input record must belong to the signer or to the calling program"
+                );
+            }
+        }
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
@@ -238,6 +252,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Ensure the request has a valid signature, inputs, and transition view key.
         A::assert(request.verify(&input_types, &tpk, root_tvk, is_root));
+        // Ensure each input record belongs to the signer, or is held in custody by the caller.
+        // Note: The caller is the signer in a root request, so a record in custody is only consumed by its program.
+        for (input, input_type) in request.inputs().iter().zip_eq(&input_types) {
+            if let (circuit::Value::Record(record), ValueType::Record(..)) = (input, input_type) {
+                A::assert((**record.owner()).is_equal(request.signer()) | (**record.owner()).is_equal(&caller));
+            }
+        }
         lap!(timer, "Verify the circuit request");
 
         // Set the transition signer.
@@ -289,6 +310,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                    Instruction::CallDynamic(call) => CallTrait::evaluate(call, self, &mut registers),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
@@ -302,6 +325,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                Instruction::CallDynamic(call) => CallTrait::execute(call, self, &mut registers, rng),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
@@ -311,11 +336,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
-            if let Instruction::Call(call) = instruction {
+            match instruction {
                 // Check if the call is a function call.
-                if call.is_function_call(self)? {
-                    contains_function_call = true;
-                }
+                Instruction::Call(call) => contains_function_call |= call.is_function_call(self)?,
+                // A dynamic call is always a function call.
+                Instruction::CallDynamic(..) => contains_function_call = true,
+                _ => (),
             }
         }
         lap!(timer, "Execute the instructions");
@@ -354,6 +380,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a function scope")
                     }
+                    // If the operand is the block timestamp, previous block hash, or block round, throw an error.
+                    operand @ (Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound) => {
+                        bail!("Illegal operation: cannot retrieve '{operand}' in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
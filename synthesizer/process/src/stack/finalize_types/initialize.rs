@@ -35,9 +35,11 @@ impl<N: Network> FinalizeTypes<N> {
         let mut input_futures = Vec::new();
 
         // Step 1. Check the inputs are well-formed. Store the input futures.
-        for input in finalize.inputs() {
-            // Check the input register type.
-            finalize_types.check_input(stack, input.register(), input.finalize_type())?;
+        for (index, input) in finalize.inputs().iter().enumerate() {
+            // Check the input register type, and annotate an error with the span of the input.
+            finalize_types
+                .check_input(stack, input.register(), input.finalize_type())
+                .map_err(|error| SpanError::annotate(error, finalize.input_span(index)))?;
 
             // If the input is a future, add it to the list of input futures.
             if let FinalizeType::Future(locator) = input.finalize_type() {
@@ -49,19 +51,24 @@ impl<N: Network> FinalizeTypes<N> {
         let mut consumed_futures = HashSet::new();
 
         // Step 2. Check the commands are well-formed. Make sure all the input futures are awaited.
-        for command in finalize.commands() {
-            // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize, command)?;
-
-            // If the command is an `await`, add the future to the set of consumed futures.
-            if let Command::Await(await_) = command {
-                // Note: `check_command` ensures that the register is a future. This is an additional check.
-                let locator = match finalize_types.get_type(stack, await_.register())? {
-                    FinalizeType::Future(locator) => locator,
-                    FinalizeType::Plaintext(..) => bail!("Expected a future in '{await_}'"),
-                };
-                consumed_futures.insert((await_.register(), locator));
-            }
+        for (index, command) in finalize.commands().iter().enumerate() {
+            let mut check_command = || {
+                // Check the command opcode, operands, and destinations.
+                finalize_types.check_command(stack, finalize, command)?;
+
+                // If the command is an `await`, add the future to the set of consumed futures.
+                if let Command::Await(await_) = command {
+                    // Note: `check_command` ensures that the register is a future. This is an additional check.
+                    let locator = match finalize_types.get_type(stack, await_.register())? {
+                        FinalizeType::Future(locator) => locator,
+                        FinalizeType::Plaintext(..) => bail!("Expected a future in '{await_}'"),
+                    };
+                    consumed_futures.insert((await_.register(), locator));
+                }
+                Ok(())
+            };
+            // Annotate an error with the span of the command.
+            check_command().map_err(|error| SpanError::annotate(error, finalize.command_span(index)))?;
         }
 
         // Check that all input futures are consumed.
@@ -143,6 +150,10 @@ impl<N: Network> FinalizeTypes<N> {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
             FinalizeType::Plaintext(PlaintextType::Array(array_type)) => RegisterTypes::check_array(stack, array_type)?,
+            FinalizeType::Plaintext(PlaintextType::Enum(enum_name)) => RegisterTypes::check_enum(stack, enum_name)?,
+            FinalizeType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                RegisterTypes::check_vector(stack, vector_type)?
+            }
             FinalizeType::Future(..) => (),
         };
 
@@ -636,6 +647,9 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
+            Opcode::CallDynamic => {
+                bail!("Instruction 'call.dynamic' is not allowed in 'finalize'");
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -673,6 +687,25 @@ impl<N: Network> FinalizeTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Enum(enum_name)) => {
+                            bail!("Casting to the enum '{enum_name}' requires a variant, i.e. '{enum_name}::<variant>'")
+                        }
+                        CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                            // Ensure that the vector type is valid.
+                            RegisterTypes::check_vector(stack, vector_type)?;
+                            // Ensure the number of operands is within the maximum length of the vector.
+                            let num_operands = instruction.operands().len();
+                            ensure!(
+                                (N::MIN_ARRAY_ELEMENTS..=**vector_type.max_length() as usize).contains(&num_operands),
+                                "Casting to '{vector_type}' expects 1 to {} operands, found {num_operands}",
+                                vector_type.max_length()
+                            );
+                        }
+                        CastType::EnumVariant(enum_name, variant) => {
+                            // Ensure the variant is defined in the enum.
+                            RegisterTypes::check_enum_variant(stack, enum_name, variant)?;
+                            ensure!(instruction.operands().len() == 1, "Expected 1 operand.");
+                        }
                         CastType::Record(..) => {
                             bail!("Illegal operation: Cannot cast to a record.")
                         }
@@ -718,6 +751,10 @@ impl<N: Network> FinalizeTypes<N> {
                     matches!(instruction, Instruction::IsNeq(..)),
                     "Instruction '{instruction}' is not for opcode '{opcode}'."
                 ),
+                "is.variant" => ensure!(
+                    matches!(instruction, Instruction::IsVariant(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
             Opcode::Sign => {
@@ -727,6 +764,25 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Vector(opcode) => match opcode {
+                "vector.push" => ensure!(
+                    matches!(instruction, Instruction::VectorPush(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vector.len" => ensure!(
+                    matches!(instruction, Instruction::VectorLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vector.get" => ensure!(
+                    matches!(instruction, Instruction::VectorGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vector.set" => ensure!(
+                    matches!(instruction, Instruction::VectorSet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
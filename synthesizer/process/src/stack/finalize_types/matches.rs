@@ -106,6 +106,16 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{network_id_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp, previous hash, or round type matches the member type.
+                Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                    // Retrieve the operand type.
+                    let operand_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the operand type matches the member type.
+                    ensure!(
+                        operand_type == FinalizeType::Plaintext(member_type.clone()),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{operand_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -198,6 +208,17 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block timestamp, previous hash, or round type matches the element type.
+                Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                    // Retrieve the operand type.
+                    let operand_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the operand type matches the element type.
+                    ensure!(
+                        operand_type == FinalizeType::Plaintext(array_type.next_element_type().clone()),
+                        "Array element expects {}, but found '{operand_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
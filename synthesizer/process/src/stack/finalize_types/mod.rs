@@ -50,6 +50,7 @@ use synthesizer_program::{
     RandChaCha,
     Remove,
     Set,
+    SpanError,
     StackMatches,
     StackProgram,
     MAX_ADDITIONAL_SEEDS,
@@ -71,9 +72,10 @@ pub struct FinalizeTypes<N: Network> {
 impl<N: Network> FinalizeTypes<N> {
     /// Initializes a new instance of `FinalizeTypes` for the given finalize.
     /// Checks that the given finalize is well-formed for the given stack.
+    /// If the finalize was parsed from a string, an error is annotated with the span of the command or finalize.
     #[inline]
     pub fn from_finalize(stack: &(impl StackMatches<N> + StackProgram<N>), finalize: &Finalize<N>) -> Result<Self> {
-        Self::initialize_finalize_types(stack, finalize)
+        Self::initialize_finalize_types(stack, finalize).map_err(|error| SpanError::annotate(error, finalize.span()))
     }
 
     /// Returns `true` if the given register exists.
@@ -104,6 +106,9 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
             Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::PreviousBlockHash => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            Operand::BlockRound => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U64)),
         })
     }
 
@@ -163,6 +168,16 @@ impl<N: Network> FinalizeTypes<N> {
                         None => bail!("'{identifier}' does not exist in struct '{struct_name}'"),
                     }
                 }
+                // Access the variant payload on the path to output the register type.
+                (FinalizeRefType::Plaintext(PlaintextType::Enum(enum_name)), Access::Member(identifier)) => {
+                    // Retrieve the payload type from the enum and check that the variant exists.
+                    match stack.program().get_enum(enum_name)?.variants().get(identifier) {
+                        // Retrieve the payload and update `finalize_type` for the next iteration.
+                        Some(payload_type) => finalize_type = FinalizeRefType::Plaintext(payload_type),
+                        // Halts if the variant does not exist.
+                        None => bail!("'{identifier}' does not exist in enum '{enum_name}'"),
+                    }
+                }
                 // Access the member on the path to output the register type and check that it is in bounds.
                 (FinalizeRefType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
                     match index < array_type.length() {
@@ -201,6 +216,8 @@ impl<N: Network> FinalizeTypes<N> {
                 }
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
+                | (FinalizeRefType::Plaintext(PlaintextType::Enum(..)), Access::Index(..))
+                | (FinalizeRefType::Plaintext(PlaintextType::Vector(..)), _)
                 | (FinalizeRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
                 }
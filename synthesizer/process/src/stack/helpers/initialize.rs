@@ -22,11 +22,13 @@ impl<N: Network> Stack<N> {
         let mut stack = Self {
             program: program.clone(),
             external_stacks: Default::default(),
+            dynamic_stacks: process.dynamic_stacks().clone(),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            external_circuits: Default::default(),
             number_of_calls: Default::default(),
             finalize_costs: Default::default(),
             program_depth: 0,
@@ -55,6 +57,40 @@ impl<N: Network> Stack<N> {
             stack.insert_closure(closure)?;
         }
 
+        // Ensure the program functions conform to the function signatures of the program interfaces.
+        // Note: The callee of a dynamic call is resolved at runtime, so the caller cannot account for
+        // the finalize cost or the calls of the callee. As such, the callee may not contain either.
+        for interface in program.interfaces().values() {
+            for signature in interface.functions().values() {
+                // Retrieve the function with the same name, if the program implements it.
+                let Ok(function) = program.get_function_ref(signature.name()) else { continue };
+                // Ensure the function matches the function signature.
+                ensure!(
+                    function.input_types() == signature.inputs() && function.output_types() == signature.outputs(),
+                    "Function '{}' does not match its signature in interface '{}'",
+                    function.name(),
+                    interface.name()
+                );
+                // Ensure the function does not contain a finalize block.
+                ensure!(
+                    function.finalize_logic().is_none(),
+                    "Function '{}' in interface '{}' cannot contain a finalize block",
+                    function.name(),
+                    interface.name()
+                );
+                // Ensure the function does not contain calls.
+                ensure!(
+                    !function
+                        .instructions()
+                        .iter()
+                        .any(|instruction| matches!(instruction, Instruction::Call(..) | Instruction::CallDynamic(..))),
+                    "Function '{}' in interface '{}' cannot contain calls",
+                    function.name(),
+                    interface.name()
+                );
+            }
+        }
+
         // Add the program functions to the stack.
         for function in program.functions().values() {
             // Add the function to the stack.
@@ -62,9 +98,9 @@ impl<N: Network> Stack<N> {
             // Determine the number of calls for the function.
             let mut num_calls = 1;
             for instruction in function.instructions() {
-                if let Instruction::Call(call) = instruction {
+                match instruction {
                     // Determine if this is a function call.
-                    if call.is_function_call(&stack)? {
+                    Instruction::Call(call) if call.is_function_call(&stack)? => {
                         // Increment by the number of calls.
                         num_calls += match call.operator() {
                             CallOperator::Locator(locator) => stack
@@ -73,6 +109,9 @@ impl<N: Network> Stack<N> {
                             CallOperator::Resource(resource) => stack.get_number_of_calls(resource)?,
                         };
                     }
+                    // Note: The callee of a dynamic call cannot contain calls, so it is exactly one call.
+                    Instruction::CallDynamic(..) => num_calls += 1,
+                    _ => (),
                 }
             }
             // Check that the number of calls does not exceed the maximum.
@@ -200,6 +200,10 @@ impl<N: Network> Stack<N> {
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
                 // If `plaintext` is an array, this is a mismatch.
                 Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
+                // If `plaintext` is an enum, this is a mismatch.
+                Plaintext::Enum(..) => bail!("'{plaintext_type}' is invalid: expected literal, found enum"),
+                // If `plaintext` is a vector, this is a mismatch.
+                Plaintext::Vector(..) => bail!("'{plaintext_type}' is invalid: expected literal, found vector"),
             },
             PlaintextType::Struct(struct_name) => {
                 // Ensure the struct name is valid.
@@ -220,6 +224,8 @@ impl<N: Network> Stack<N> {
                     Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
                     Plaintext::Struct(members, ..) => members,
                     Plaintext::Array(..) => bail!("'{struct_name}' is invalid: expected struct, found array"),
+                    Plaintext::Enum(..) => bail!("'{struct_name}' is invalid: expected struct, found enum"),
+                    Plaintext::Vector(..) => bail!("'{struct_name}' is invalid: expected struct, found vector"),
                 };
 
                 let num_members = members.len();
@@ -280,6 +286,68 @@ impl<N: Network> Stack<N> {
                     }
                     Ok(())
                 }
+                // If `plaintext` is an enum, this is a mismatch.
+                Plaintext::Enum(..) => bail!("'{plaintext_type}' is invalid: expected array, found enum"),
+                // If `plaintext` is a vector, this is a mismatch.
+                Plaintext::Vector(..) => bail!("'{plaintext_type}' is invalid: expected array, found vector"),
+            },
+            PlaintextType::Enum(enum_name) => {
+                // Ensure the enum name is valid.
+                ensure!(!Program::is_reserved_keyword(enum_name), "Enum '{enum_name}' is reserved");
+
+                // Retrieve the enum from the program.
+                let Ok(enum_) = self.program().get_enum(enum_name) else {
+                    bail!("Enum '{enum_name}' is not defined in the program")
+                };
+
+                // Retrieve the variant and payload.
+                let (variant, payload) = match plaintext {
+                    Plaintext::Literal(..) => bail!("'{enum_name}' is invalid: expected enum, found literal"),
+                    Plaintext::Struct(..) => bail!("'{enum_name}' is invalid: expected enum, found struct"),
+                    Plaintext::Array(..) => bail!("'{enum_name}' is invalid: expected enum, found array"),
+                    Plaintext::Enum(variant, payload, ..) => (variant, payload),
+                    Plaintext::Vector(..) => bail!("'{enum_name}' is invalid: expected enum, found vector"),
+                };
+
+                // Retrieve the payload type of the variant.
+                let Some(payload_type) = enum_.variants().get(variant) else {
+                    bail!("'{enum_name}' is invalid: variant '{variant}' is not defined")
+                };
+                // Ensure the payload matches (recursive call).
+                self.matches_plaintext_internal(payload, payload_type, depth + 1)
+            }
+            PlaintextType::Vector(vector_type) => match plaintext {
+                // If `plaintext` is a literal, this is a mismatch.
+                Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected vector, found literal"),
+                // If `plaintext` is a struct, this is a mismatch.
+                Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected vector, found struct"),
+                // If `plaintext` is an array, this is a mismatch.
+                Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected vector, found array"),
+                // If `plaintext` is an enum, this is a mismatch.
+                Plaintext::Enum(..) => bail!("'{plaintext_type}' is invalid: expected vector, found enum"),
+                // If `plaintext` is a vector, it must match the vector type.
+                Plaintext::Vector(elements, length, ..) => {
+                    // Ensure the vector is padded to its maximum length.
+                    let (actual_length, max_length) = (elements.len(), vector_type.max_length());
+                    if **max_length as usize != actual_length {
+                        bail!(
+                            "'{plaintext_type}' is invalid: expected {max_length} elements, found {actual_length} elements"
+                        )
+                    }
+                    // Ensure the vector length does not exceed the maximum length.
+                    if **length > **max_length {
+                        bail!("'{plaintext_type}' is invalid: length {length} exceeds the maximum length {max_length}")
+                    }
+                    // Ensure the vector elements match, including the padding.
+                    for element in elements.iter() {
+                        self.matches_plaintext_internal(element, vector_type.element_type(), depth + 1)?;
+                    }
+                    // Ensure the elements beyond the length are zero.
+                    if !Plaintext::is_zero_padded(elements, **length) {
+                        bail!("'{plaintext_type}' is invalid: the elements beyond length {length} must be zero")
+                    }
+                    Ok(())
+                }
             },
         }
     }
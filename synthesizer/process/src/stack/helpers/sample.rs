@@ -148,6 +148,39 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Array(elements, Default::default())
             }
+            // Sample an enum.
+            PlaintextType::Enum(enum_name) => {
+                // Retrieve the enum.
+                let enum_ = self.program.get_enum(enum_name)?;
+                // Select a variant of the enum.
+                let Some((variant, payload_type)) =
+                    enum_.variants().get_index(rng.gen_range(0..enum_.variants().len()))
+                else {
+                    bail!("Enum '{enum_name}' does not have any variants")
+                };
+                // Sample the payload of the variant.
+                let payload = self.sample_plaintext_internal(payload_type, depth + 1, rng)?;
+
+                Plaintext::Enum(*variant, Box::new(payload), Default::default())
+            }
+            // Sample a vector.
+            PlaintextType::Vector(vector_type) => {
+                // Sample the length of the vector.
+                let length = U32::new(rng.gen_range(0..=**vector_type.max_length()));
+                // Sample each element of the vector, and pad the elements beyond the length with zeros.
+                let elements = (0..**vector_type.max_length())
+                    .map(|i| {
+                        // Sample the element value.
+                        let element = self.sample_plaintext_internal(vector_type.element_type(), depth + 1, rng)?;
+                        match i < *length {
+                            true => Ok(element),
+                            false => element.to_zero(),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Plaintext::Vector(elements, length, Default::default())
+            }
         };
         // Return the plaintext.
         Ok(plaintext)
@@ -36,7 +36,7 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
+use crate::{cost_in_microcredits, traits::*, CallMetrics, ExternalCircuit, Process, Trace};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -61,7 +61,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::{Field, Group},
+    types::{Field, Group, U32},
 };
 use ledger_block::{Deployment, Transition};
 use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
@@ -70,11 +70,15 @@ use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The mapping of program addresses to the stacks in a process, shared with each stack for dynamic calls.
+/// Note: The stacks are weakly referenced, as each stack holds the mapping.
+pub(crate) type DynamicStacks<N> = Arc<RwLock<IndexMap<Address<N>, Weak<Stack<N>>>>>;
+
 pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>::Field>, CallMetrics<N>)>>>;
 
 #[derive(Clone)]
@@ -176,6 +180,8 @@ pub struct Stack<N: Network> {
     program: Program<N>,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of the stacks in the process as `(program address, stack)`, for dynamic calls.
+    dynamic_stacks: DynamicStacks<N>,
     /// The mapping of closure and function names to their register types.
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
@@ -186,6 +192,8 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of function name to the external circuit it enforces.
+    external_circuits: Arc<RwLock<IndexMap<Identifier<N>, ExternalCircuit<N>>>>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
     /// The mapping of function names to finalize cost.
@@ -202,6 +210,37 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Return the stack.
+        Stack::check_and_initialize(process, program)
+    }
+
+    /// Initializes the stack for an upgraded edition of a program, given the process and the upgraded program.
+    #[inline]
+    pub fn upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Ensure the upgraded program is compatible with the program in the process.
+        program.check_upgrade_from(process.get_program(program.id())?)?;
+        // Return the stack.
+        Stack::check_and_initialize(process, program)
+    }
+
+    /// Recomputes the stack on the given process, retaining its circuit keys.
+    /// This is used to refresh the external stacks after one of the imports is upgraded.
+    #[inline]
+    pub(crate) fn reinitialize(&self, process: &Process<N>) -> Result<Self> {
+        // Recompute the stack.
+        let mut stack = Stack::initialize(process, &self.program)?;
+        // Retain the circuit keys, as the signatures of the imports are unchanged by an upgrade.
+        stack.proving_keys = self.proving_keys.clone();
+        stack.verifying_keys = self.verifying_keys.clone();
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Ensures the program is well-formed, and initializes its stack.
+    #[inline]
+    fn check_and_initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -268,6 +307,14 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         }
     }
 
+    /// Returns the stack of the program with the given program address, for a dynamic call.
+    #[inline]
+    fn get_dynamic_stack(&self, address: &Address<N>) -> Result<Arc<Stack<N>>> {
+        // Retrieve the stack, if it is still in the process.
+        let stack = self.dynamic_stacks.read().get(address).and_then(Weak::upgrade);
+        stack.ok_or_else(|| anyhow!("Program with address '{address}' does not exist."))
+    }
+
     /// Returns the external record if the stack contains the external record.
     #[inline]
     fn get_external_record(&self, locator: &Locator<N>) -> Result<&RecordType<N>> {
@@ -427,6 +474,36 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Returns the external circuit for the given function name.
+    #[inline]
+    pub fn get_external_circuit(&self, function_name: &Identifier<N>) -> Result<ExternalCircuit<N>> {
+        // Return the external circuit, if it exists.
+        match self.external_circuits.read().get(function_name) {
+            Some(external_circuit) => Ok(external_circuit.clone()),
+            None => bail!("External circuit not found for: {}/{function_name}", self.program.id()),
+        }
+    }
+
+    /// Inserts the given external circuit for the given function name.
+    #[inline]
+    pub fn insert_external_circuit(
+        &self,
+        function_name: &Identifier<N>,
+        external_circuit: ExternalCircuit<N>,
+    ) -> Result<()> {
+        // Retrieve the function.
+        let function = self.program.get_function_ref(function_name)?;
+        // Ensure the function enforces the external circuit.
+        ensure!(
+            function.to_string() == external_circuit.to_function(*function_name)?.to_string(),
+            "Function '{function_name}' does not enforce the external circuit in program '{}'.",
+            self.program.id()
+        );
+        // Insert the external circuit.
+        self.external_circuits.write().insert(*function_name, external_circuit);
+        Ok(())
+    }
+
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
@@ -26,31 +26,46 @@ impl<N: Network> RegisterTypes<N> {
         let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
 
         // Step 1. Check the inputs are well-formed.
-        for input in closure.inputs() {
-            // Check the input register type.
-            register_types.check_input(stack, input.register(), input.register_type())?;
+        for (index, input) in closure.inputs().iter().enumerate() {
+            // Check the input register type, and annotate an error with the span of the input.
+            register_types
+                .check_input(stack, input.register(), input.register_type())
+                .map_err(|error| SpanError::annotate(error, closure.input_span(index)))?;
         }
 
         // Step 2. Check the instructions are well-formed.
-        for instruction in closure.instructions() {
-            // Ensure the closure contains no async instructions.
-            ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
-            // Ensure the closure contains no call instructions.
-            ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
-            // Check the instruction opcode, operands, and destinations.
-            register_types.check_instruction(stack, closure.name(), instruction)?;
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            let mut check_instruction = || {
+                // Ensure the closure contains no async instructions.
+                ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
+                // Ensure the closure contains no call instructions.
+                ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+                // Ensure the closure contains no dynamic call instructions.
+                ensure!(
+                    instruction.opcode() != Opcode::CallDynamic,
+                    "A 'call.dynamic' instruction is not allowed in closures"
+                );
+                // Check the instruction opcode, operands, and destinations.
+                register_types.check_instruction(stack, closure.name(), instruction)
+            };
+            // Annotate an error with the span of the instruction.
+            check_instruction().map_err(|error| SpanError::annotate(error, closure.instruction_span(index)))?;
         }
 
         // Step 3. Check the outputs are well-formed.
-        for output in closure.outputs() {
-            // Ensure the closure output register is not a record.
-            ensure!(
-                !matches!(output.register_type(), RegisterType::Record(..)),
-                "Closure outputs do not support records"
-            );
+        for (index, output) in closure.outputs().iter().enumerate() {
+            let check_output = || {
+                // Ensure the closure output register is not a record.
+                ensure!(
+                    !matches!(output.register_type(), RegisterType::Record(..)),
+                    "Closure outputs do not support records"
+                );
 
-            // Check the output operand type.
-            register_types.check_output(stack, output.operand(), output.register_type())?;
+                // Check the output operand type.
+                register_types.check_output(stack, output.operand(), output.register_type())
+            };
+            // Annotate an error with the span of the output.
+            check_output().map_err(|error| SpanError::annotate(error, closure.output_span(index)))?;
         }
 
         Ok(register_types)
@@ -68,47 +83,63 @@ impl<N: Network> RegisterTypes<N> {
 
         /* Step 1. Check the inputs are well-formed. */
 
-        for input in function.inputs() {
-            // TODO (howardwu): In order to support constant inputs, update `Self::deploy()` to allow
-            //  the caller to provide optional constant inputs (instead of sampling random constants).
-            //  Then, this check can be removed to enable support for constant inputs in functions.
-            ensure!(!matches!(input.value_type(), ValueType::Constant(..)), "Constant inputs are not supported");
-            ensure!(!matches!(input.value_type(), ValueType::Future(..)), "Future inputs are not supported");
-
-            // Check the input register type.
-            register_types.check_input(stack, input.register(), &RegisterType::from(input.value_type().clone()))?;
+        for (index, input) in function.inputs().iter().enumerate() {
+            let mut check_input = || {
+                // TODO (howardwu): In order to support constant inputs, update `Self::deploy()` to allow
+                //  the caller to provide optional constant inputs (instead of sampling random constants).
+                //  Then, this check can be removed to enable support for constant inputs in functions.
+                ensure!(!matches!(input.value_type(), ValueType::Constant(..)), "Constant inputs are not supported");
+                ensure!(!matches!(input.value_type(), ValueType::Future(..)), "Future inputs are not supported");
+
+                // Check the input register type.
+                register_types.check_input(stack, input.register(), &RegisterType::from(input.value_type().clone()))
+            };
+            // Annotate an error with the span of the input.
+            check_input().map_err(|error| SpanError::annotate(error, function.input_span(index)))?;
         }
 
         /* Step 2. Check the instructions are well-formed. */
         // - If the function has a finalize block, then it must contain exactly one `async` instruction.
         // - If the function has no finalize block, then it must **not** have `async` instructions.
-        // - All `call` instructions must precede any `async` instruction.
+        // - All `call` and `call.dynamic` instructions must precede any `async` instruction.
 
         let mut async_ = None;
-        for instruction in function.instructions() {
-            // Check the instruction opcode, operands, and destinations.
-            register_types.check_instruction(stack, function.name(), instruction)?;
-            // Additional validation.
-            match instruction.opcode() {
-                Opcode::Async => {
-                    // Ensure the function does not contain more than one `async` instruction.
-                    ensure!(
-                        async_.is_none(),
-                        "Function '{}' can contain at most one 'async' instruction",
-                        function.name()
-                    );
-                    // Save the `async` instruction.
-                    async_ = match &instruction {
-                        Instruction::Async(async_) => Some(async_),
-                        _ => bail!("Expected 'async' instruction"),
-                    };
-                }
-                Opcode::Call => {
-                    // Ensure the `call` instruction precedes any `async` instruction.
-                    ensure!(async_.is_none(), "The 'call' can only be invoked before an 'async' instruction")
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            let mut check_instruction = || {
+                // Check the instruction opcode, operands, and destinations.
+                register_types.check_instruction(stack, function.name(), instruction)?;
+                // Additional validation.
+                match instruction.opcode() {
+                    Opcode::Async => {
+                        // Ensure the function does not contain more than one `async` instruction.
+                        ensure!(
+                            async_.is_none(),
+                            "Function '{}' can contain at most one 'async' instruction",
+                            function.name()
+                        );
+                        // Save the `async` instruction.
+                        async_ = match &instruction {
+                            Instruction::Async(async_) => Some(async_),
+                            _ => bail!("Expected 'async' instruction"),
+                        };
+                    }
+                    Opcode::Call => {
+                        // Ensure the `call` instruction precedes any `async` instruction.
+                        ensure!(async_.is_none(), "The 'call' can only be invoked before an 'async' instruction")
+                    }
+                    Opcode::CallDynamic => {
+                        // Ensure the `call.dynamic` instruction precedes any `async` instruction.
+                        ensure!(
+                            async_.is_none(),
+                            "The 'call.dynamic' can only be invoked before an 'async' instruction"
+                        )
+                    }
+                    _ => {}
                 }
-                _ => {}
-            }
+                Ok(())
+            };
+            // Annotate an error with the span of the instruction.
+            check_instruction().map_err(|error| SpanError::annotate(error, function.instruction_span(index)))?;
         }
 
         // Ensure the number of `async` instructions is valid.
@@ -123,9 +154,11 @@ impl<N: Network> RegisterTypes<N> {
         // - If the function has no finalize block, then it must **not** have `future` outputs.
 
         let mut num_futures = 0;
-        for output in function.outputs() {
-            // Check the output operand type.
-            register_types.check_output(stack, output.operand(), &RegisterType::from(output.value_type().clone()))?;
+        for (index, output) in function.outputs().iter().enumerate() {
+            // Check the output operand type, and annotate an error with the span of the output.
+            register_types
+                .check_output(stack, output.operand(), &RegisterType::from(output.value_type().clone()))
+                .map_err(|error| SpanError::annotate(error, function.output_span(index)))?;
             // Additional validation.
             if matches!(output.value_type(), ValueType::Future(..)) {
                 num_futures += 1;
@@ -266,7 +299,17 @@ impl<N: Network> RegisterTypes<N> {
         match register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
-            RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Note: The circuit of a function must not depend on the variant of an enum.
+                if let PlaintextType::Enum(..) = array_type.base_element_type() {
+                    bail!("Input '{register}' cannot be an array of enums, as the enum variant is a circuit constant.")
+                }
+                Self::check_array(stack, array_type)?
+            }
+            RegisterType::Plaintext(PlaintextType::Enum(..)) => {
+                bail!("Input '{register}' cannot be an enum, as the enum variant is a circuit constant.")
+            }
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => Self::check_vector(stack, vector_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -318,7 +361,17 @@ impl<N: Network> RegisterTypes<N> {
         match register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
-            RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Note: The circuit of a function must not depend on the variant of an enum.
+                if let PlaintextType::Enum(..) = array_type.base_element_type() {
+                    bail!("Output '{operand}' cannot be an array of enums, as the enum variant is a circuit constant.")
+                }
+                Self::check_array(stack, array_type)?
+            }
+            RegisterType::Plaintext(PlaintextType::Enum(..)) => {
+                bail!("Output '{operand}' cannot be an enum, as the enum variant is a circuit constant.")
+            }
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => Self::check_vector(stack, vector_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -497,6 +550,16 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
             }
+            Opcode::CallDynamic => {
+                // Retrieve the dynamic call operation.
+                let call = match instruction {
+                    Instruction::CallDynamic(call) => call,
+                    _ => bail!("Instruction '{instruction}' is not a dynamic call operation."),
+                };
+                // Ensure the function is declared in an interface of the current program.
+                // Note: The callee program is resolved from the program address when the function is called.
+                stack.program().get_interface(call.interface())?.get_function(call.function())?;
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -534,6 +597,25 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Enum(enum_name)) => {
+                            bail!("Casting to the enum '{enum_name}' requires a variant, i.e. '{enum_name}::<variant>'")
+                        }
+                        CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                            // Ensure that the vector type is valid.
+                            RegisterTypes::check_vector(stack, vector_type)?;
+                            // Ensure the number of operands is within the maximum length of the vector.
+                            let num_operands = instruction.operands().len();
+                            ensure!(
+                                (N::MIN_ARRAY_ELEMENTS..=**vector_type.max_length() as usize).contains(&num_operands),
+                                "Casting to '{vector_type}' expects 1 to {} operands, found {num_operands}",
+                                vector_type.max_length()
+                            );
+                        }
+                        CastType::EnumVariant(enum_name, variant) => {
+                            // Ensure the variant is defined in the enum.
+                            Self::check_enum_variant(stack, enum_name, variant)?;
+                            ensure!(instruction.operands().len() == 1, "Expected 1 operand.");
+                        }
                         CastType::Record(record_name) => {
                             // Ensure the record type is defined in the program.
                             if !stack.program().contains_record(record_name) {
@@ -586,6 +668,10 @@ impl<N: Network> RegisterTypes<N> {
                     matches!(instruction, Instruction::IsNeq(..)),
                     "Instruction '{instruction}' is not for opcode '{opcode}'."
                 ),
+                "is.variant" => ensure!(
+                    matches!(instruction, Instruction::IsVariant(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
             Opcode::Sign => {
@@ -595,6 +681,25 @@ impl<N: Network> RegisterTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Vector(opcode) => match opcode {
+                "vector.push" => ensure!(
+                    matches!(instruction, Instruction::VectorPush(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vector.len" => ensure!(
+                    matches!(instruction, Instruction::VectorLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vector.get" => ensure!(
+                    matches!(instruction, Instruction::VectorGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vector.set" => ensure!(
+                    matches!(instruction, Instruction::VectorSet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
@@ -664,6 +769,8 @@ impl<N: Network> RegisterTypes<N> {
                 PlaintextType::Literal(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+                PlaintextType::Enum(enum_name) => Self::check_enum(stack, enum_name)?,
+                PlaintextType::Vector(vector_type) => Self::check_vector(stack, vector_type)?,
             }
         }
         Ok(())
@@ -681,6 +788,68 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
             }
         }
+        // If the base element type is an enum, check that it is defined in the program.
+        if let PlaintextType::Enum(enum_name) = array_type.base_element_type() {
+            // Ensure the enum is defined in the program.
+            if !stack.program().contains_enum(enum_name) {
+                bail!("Enum '{enum_name}' in '{}' is not defined.", stack.program_id())
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the enum exists in the program, and recursively-checks the payloads of its variants.
+    pub(crate) fn check_enum(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        enum_name: &Identifier<N>,
+    ) -> Result<()> {
+        // Retrieve the enum from the program.
+        let Ok(enum_) = stack.program().get_enum(enum_name) else {
+            bail!("Enum '{enum_name}' in '{}' is not defined.", stack.program_id())
+        };
+
+        // Ensure the payload types of the variants are defined in the program.
+        for payload_type in enum_.variants().values() {
+            match payload_type {
+                PlaintextType::Literal(..) => (),
+                PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
+                PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+                // Note: An enum may only contain an enum that is defined before it, so this recursion terminates.
+                PlaintextType::Enum(enum_name) => Self::check_enum(stack, enum_name)?,
+                PlaintextType::Vector(vector_type) => Self::check_vector(stack, vector_type)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the element type of the vector is defined in the program.
+    pub(crate) fn check_vector(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        vector_type: &VectorType<N>,
+    ) -> Result<()> {
+        match vector_type.element_type() {
+            PlaintextType::Literal(..) => Ok(()),
+            PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name),
+            PlaintextType::Array(array_type) => Self::check_array(stack, array_type),
+            // Note: This is enforced by `VectorType::new`, and is checked here for completeness.
+            PlaintextType::Enum(..) | PlaintextType::Vector(..) => {
+                bail!("Vector '{vector_type}' must contain a literal, struct, or array")
+            }
+        }
+    }
+
+    /// Ensures the enum exists in the program, and that it defines the given variant.
+    pub(crate) fn check_enum_variant(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        enum_name: &Identifier<N>,
+        variant: &Identifier<N>,
+    ) -> Result<()> {
+        // Ensure the enum is well-formed.
+        Self::check_enum(stack, enum_name)?;
+        // Ensure the variant is defined in the enum.
+        if !stack.program().get_enum(enum_name)?.variants().contains_key(variant) {
+            bail!("Variant '{variant}' is not defined in enum '{enum_name}'.")
+        }
         Ok(())
     }
 
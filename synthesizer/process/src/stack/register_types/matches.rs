@@ -92,6 +92,10 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::NetworkID => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a network ID in a non-finalize scope"
                 ),
+                // If the operand is a block timestamp, previous block hash, or block round, throw an error.
+                Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from '{operand}' in a non-finalize scope"
+                ),
             }
         }
         Ok(())
@@ -168,6 +172,10 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
                 // If the operand is a network ID type, throw an error.
                 Operand::NetworkID => bail!("Array element cannot be from a network ID in a non-finalize scope"),
+                // If the operand is a block timestamp, previous block hash, or block round, throw an error.
+                Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                    bail!("Array element cannot be from '{operand}' in a non-finalize scope")
+                }
             }
         }
         Ok(())
@@ -233,6 +241,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::NetworkID => {
                 bail!("Forbidden operation: Cannot cast a network ID as a record owner")
             }
+            operand @ (Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound) => {
+                bail!("Forbidden operation: Cannot cast '{operand}' as a record owner")
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -294,6 +305,12 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a network ID in the operand '{operand}'."
                             )
                         }
+                        // Fail if the operand is a block timestamp, previous block hash, or block round.
+                        Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block value in the operand '{operand}'."
+                            )
+                        }
                     }
                 }
             }
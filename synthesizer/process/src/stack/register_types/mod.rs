@@ -29,6 +29,7 @@ use console::{
         RegisterType,
         StructType,
         ValueType,
+        VectorType,
     },
 };
 use synthesizer_program::{
@@ -41,6 +42,7 @@ use synthesizer_program::{
     Opcode,
     Operand,
     Program,
+    SpanError,
     StackMatches,
     StackProgram,
 };
@@ -59,16 +61,18 @@ pub struct RegisterTypes<N: Network> {
 impl<N: Network> RegisterTypes<N> {
     /// Initializes a new instance of `RegisterTypes` for the given closure.
     /// Checks that the given closure is well-formed for the given stack.
+    /// If the closure was parsed from a string, an error is annotated with the span of the instruction or closure.
     #[inline]
     pub fn from_closure(stack: &(impl StackMatches<N> + StackProgram<N>), closure: &Closure<N>) -> Result<Self> {
-        Self::initialize_closure_types(stack, closure)
+        Self::initialize_closure_types(stack, closure).map_err(|error| SpanError::annotate(error, closure.span()))
     }
 
     /// Initializes a new instance of `RegisterTypes` for the given function.
     /// Checks that the given function is well-formed for the given stack.
+    /// If the function was parsed from a string, an error is annotated with the span of the instruction or function.
     #[inline]
     pub fn from_function(stack: &(impl StackMatches<N> + StackProgram<N>), function: &Function<N>) -> Result<Self> {
-        Self::initialize_function_types(stack, function)
+        Self::initialize_function_types(stack, function).map_err(|error| SpanError::annotate(error, function.span()))
     }
 
     /// Returns `true` if the given register exists.
@@ -99,6 +103,9 @@ impl<N: Network> RegisterTypes<N> {
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
             Operand::NetworkID => bail!("'network.id' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                bail!("'{operand}' is not a valid operand in a non-finalize context.")
+            }
         })
     }
 
@@ -216,6 +223,15 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
                 // Traverse the path to output the register type.
+                (RegisterRefType::Plaintext(PlaintextType::Enum(enum_name)), Access::Member(identifier)) => {
+                    // Retrieve the payload type from the enum.
+                    match stack.program().get_enum(enum_name)?.variants().get(identifier) {
+                        // Update the payload type.
+                        Some(payload_type) => register_type = RegisterRefType::Plaintext(payload_type),
+                        None => bail!("'{identifier}' does not exist in enum '{enum_name}'"),
+                    }
+                }
+                // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
                     match index < array_type.length() {
                         true => register_type = RegisterRefType::Plaintext(array_type.next_element_type()),
@@ -251,6 +267,8 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
+                | (RegisterRefType::Plaintext(PlaintextType::Enum(..)), Access::Index(..))
+                | (RegisterRefType::Plaintext(PlaintextType::Vector(..)), _)
                 | (RegisterRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
                 }
@@ -40,6 +40,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
             // If the operand is the network ID, throw an error.
             Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
+            // If the operand is the block timestamp, previous block hash, or block round, throw an error.
+            Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                bail!("Cannot load '{operand}' in a non-finalize context")
+            }
         };
 
         // Retrieve the stack value.
@@ -125,6 +129,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
             // If the operand is the network ID, throw an error.
             Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
+            // If the operand is the block timestamp, previous block hash, or block round, throw an error.
+            Operand::BlockTimestamp | Operand::PreviousBlockHash | Operand::BlockRound => {
+                bail!("Cannot load '{operand}' in a non-finalize context")
+            }
         };
 
         // Retrieve the circuit value.
@@ -14,16 +14,18 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Authorization,
     CallStack,
+    ExternalCircuit,
     Process,
     Stack,
     Trace,
 };
-use circuit::{network::AleoV0, Aleo};
+use circuit::{environment::R1CSFile, network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Request, Value},
     types::{Field, U64},
 };
 use ledger_block::{Fee, Transaction};
@@ -47,7 +49,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -1831,6 +1833,104 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("16u64").unwrap());
 }
 
+#[test]
+fn test_process_execute_and_finalize_block_context() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program block_context.aleo;
+
+mapping timestamps:
+    key as u8.public;
+    value as i64.public;
+
+mapping rounds:
+    key as u8.public;
+    value as u64.public;
+
+mapping hashes:
+    key as u8.public;
+    value as field.public;
+
+function store:
+    input r0 as u8.public;
+    async store r0 into r1;
+    output r1 as block_context.aleo/store.future;
+
+finalize store:
+    input r0 as u8.public;
+    set block.timestamp into timestamps[r0];
+    set block.round into rounds[r0];
+    set block.previous_hash into hashes[r0];
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("store").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, ["0u8"].into_iter(), rng)
+        .unwrap();
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("block_context", rng).unwrap();
+
+    // Finalize the execution, with a block timestamp, round, and previous block hash.
+    let previous_block_hash = Field::<CurrentNetwork>::from_u64(7).to_bytes_le().unwrap().try_into().unwrap();
+    let state = FinalizeGlobalState::from(5, 2, 1_700_000_000, previous_block_hash, [0u8; 32]);
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Check that the block context was stored in the mappings.
+    let key = Plaintext::from_str("0u8").unwrap();
+    let get_value = |mapping_name: &str| {
+        let mapping_name = Identifier::from_str(mapping_name).unwrap();
+        finalize_store.get_value_speculative(*program.id(), mapping_name, &key).unwrap().unwrap()
+    };
+    assert_eq!(get_value("timestamps"), Value::from_str("1700000000i64").unwrap());
+    assert_eq!(get_value("rounds"), Value::from_str("5u64").unwrap());
+    assert_eq!(get_value("hashes"), Value::from_str("7field").unwrap());
+
+    // Ensure the block context cannot be used outside of a finalize scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program block_context_fail.aleo;
+
+function store:
+    input r0 as u8.public;
+    add block.round 1u64 into r1;
+    output r1 as u64.public;
+",
+    )
+    .unwrap();
+    assert!(Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_err());
+}
+
 #[test]
 fn test_execution_order() {
     // Initialize a new program.
@@ -2259,6 +2359,289 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("{ count: 3u8, data: 6u8 }").unwrap());
 }
 
+#[test]
+fn test_process_execute_and_finalize_get_set_with_enum() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+struct dimensions:
+    width as u64;
+    height as u64;
+
+enum shape:
+    circle as u64;
+    square as dimensions;
+
+mapping shapes:
+    key as address.public;
+    value as shape.enum.public;
+
+function compute:
+    input r0 as u64.public;
+    cast r0 into r1 as shape::circle;
+    is.variant r1 shape::circle into r2;
+    assert.eq r2 true;
+    async compute self.caller r1 into r3;
+    output r3 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as shape.enum.public;
+    is.variant r1 shape::square into r2;
+    assert.eq r2 false;
+    mul r1.circle 2u64 into r3;
+    cast r3 r3 into r4 as dimensions;
+    cast r4 into r5 as shape::square;
+    set r5 into shapes[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("shapes").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the enum is stored as expected.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("square({ width: 10u64, height: 10u64 })").unwrap());
+}
+
+#[test]
+fn test_process_rejects_enum_function_inputs_and_outputs() {
+    // Ensure a function cannot take an enum as an input.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+enum shape:
+    circle as u64;
+    square as u64;
+
+function compute:
+    input r0 as shape.enum.public;
+    is.variant r0 shape::circle into r1;
+    output r1 as boolean.public;",
+    )
+    .unwrap();
+    let error = Process::load().unwrap().add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("cannot be an enum"), "{error}");
+
+    // Ensure a function cannot output an array of enums.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+enum shape:
+    circle as u64;
+    square as u64;
+
+function compute:
+    input r0 as u64.public;
+    cast r0 into r1 as shape::circle;
+    cast r1 r1 into r2 as [shape.enum; 2u32];
+    output r2 as [shape.enum; 2u32].public;",
+    )
+    .unwrap();
+    let error = Process::load().unwrap().add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("cannot be an array of enums"), "{error}");
+
+    // Ensure a closure cannot take an enum as an input.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+enum shape:
+    circle as u64;
+    square as u64;
+
+closure helper:
+    input r0 as shape.enum;
+    is.variant r0 shape::circle into r1;
+    output r1 as boolean;
+
+function compute:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+    )
+    .unwrap();
+    let error = Process::load().unwrap().add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("cannot be an enum"), "{error}");
+}
+
+#[test]
+fn test_process_execute_and_finalize_get_set_with_vector() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping scores:
+    key as address.public;
+    value as vector[u64; 4u32].public;
+
+function compute:
+    input r0 as vector[u64; 4u32].public;
+    input r1 as u64.public;
+    vector.push r0 r1 into r2;
+    vector.len r2 into r3;
+    assert.eq r3 2u32;
+    vector.get r2 1u32 into r4;
+    assert.eq r4 r1;
+    async compute self.caller r2 into r5;
+    output r2 as vector[u64; 4u32].public;
+    output r5 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as vector[u64; 4u32].public;
+    vector.get r1 0u32 into r2;
+    mul r2 2u64 into r3;
+    vector.set r1 0u32 r3 into r4;
+    vector.push r4 r3 into r5;
+    set r5 into scores[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("scores").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("vector[3u64, 0u64, 0u64, 0u64; 1u32]").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("7u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(candidate[0], Value::from_str("vector[3u64, 7u64, 0u64, 0u64; 2u32]").unwrap());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the vector is stored as expected.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("vector[6u64, 7u64, 6u64, 0u64; 3u32]").unwrap());
+}
+
 #[test]
 fn test_process_execute_and_verify_call_to_closure() {
     // Initialize a new program.
@@ -2363,8 +2746,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        dynamic_stacks: Default::default(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2613,6 +2999,77 @@ fn test_max_imports() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_process_add_program_reports_spans() {
+    // Initialize a program whose function contains a type error.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program spans.aleo;
+
+function compute:
+    input r0 as u8.public;
+    input r1 as u16.public;
+    add r0 r1 into r2;
+    output r2 as u8.public;",
+    )
+    .unwrap();
+
+    // Ensure the error reports the position of the instruction.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().ends_with("(at line 6, column 5)"), "{error}");
+
+    // Initialize a program whose finalize contains a type error.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program spans.aleo;
+
+function compute:
+    input r0 as u8.public;
+    async compute r0 into r1;
+    output r1 as spans.aleo/compute.future;
+
+finalize compute:
+    input r0 as u8.public;
+    add r0 r3 into r1;",
+    )
+    .unwrap();
+
+    // Ensure the error reports the position of the command.
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().ends_with("(at line 10, column 5)"), "{error}");
+
+    // Ensure a program decoded from bytes reports the error without a position.
+    let program = Program::<CurrentNetwork>::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap();
+    let error = process.add_program(&program).unwrap_err();
+    assert!(!error.to_string().contains("(at line"), "{error}");
+
+    // Initialize a program whose function contains an invalid input and an invalid output.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program spans.aleo;
+
+function compute:
+    input r0 as u8.constant;
+    output r0 as u8.public;",
+    )
+    .unwrap();
+
+    // Ensure the error reports the position of the input.
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().ends_with("(at line 4, column 5)"), "{error}");
+
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program spans.aleo;
+
+function compute:
+    input r0 as u8.public;
+    output r1 as u8.public;",
+    )
+    .unwrap();
+
+    // Ensure the error reports the position of the output.
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().ends_with("(at line 5, column 5)"), "{error}");
+}
+
 #[test]
 fn test_program_exceeding_transaction_spend_limit() {
     // Construct a finalize body whose finalize cost is excessively large.
@@ -2645,3 +3102,325 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_execute_external_circuit() {
+    // Construct the external R1CS for `a * b == c - 1`, with the wires `[1, c, a, b]`.
+    let one = <CurrentNetwork as Environment>::Field::one();
+    let constraints = vec![(vec![(2, one)], vec![(3, one)], vec![(1, one), (0, -one)])];
+    let r1cs = R1CSFile::new(4, 1, 1, 1, constraints).unwrap();
+    let external_circuit = ExternalCircuit::<CurrentNetwork>::new(r1cs.clone(), move |inputs: &[_]| {
+        Ok(vec![one, inputs[0] * inputs[1] + one, inputs[0], inputs[1]])
+    });
+
+    // Compile the external circuit into a program.
+    let program_id = ProgramID::from_str("external_circuit.aleo").unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+    let program = external_circuit.to_program(program_id, function_name).unwrap();
+    let function = program.get_function(&function_name).unwrap();
+    assert_eq!(function.inputs().len(), 3);
+    assert_eq!(function.outputs().len(), 1);
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process, and ensure the deployment of the program verifies.
+    let mut process = Process::load().unwrap();
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    process.add_program(&program).unwrap();
+
+    // Register the external circuit.
+    process.insert_external_circuit(&program_id, &function_name, external_circuit.clone()).unwrap();
+
+    // Authorize the function call, given the inputs of the R1CS.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let (a, b) = (*Field::<CurrentNetwork>::from_u64(3), *Field::<CurrentNetwork>::from_u64(5));
+    let authorization = process
+        .authorize_external_circuit::<CurrentAleo, _>(&private_key, program_id, function_name, &[a, b], rng)
+        .unwrap();
+    let inputs = external_circuit.to_function_inputs(&[a, b]).unwrap();
+    assert_eq!(inputs[0], Value::from_str("3field").unwrap());
+    assert_eq!(inputs[1], Value::from_str("5field").unwrap());
+
+    // Ensure the output is the output wire of the R1CS, `3 * 5 + 1`.
+    let expected = Value::<CurrentNetwork>::from_str("16field").unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), &[expected.clone()]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &[expected]);
+
+    // Prove and verify the execution.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("external_circuit", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure a witness that does not satisfy the R1CS is rejected by the program.
+    let row = |first: &str| format!("[{first}, {}]", ["0field"; 31].join(", "));
+    let mut inputs = inputs;
+    inputs[2] = Value::from_str(&format!("[{}, {}]", row("15field"), vec![row("0field"); 31].join(", "))).unwrap();
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs.iter(), rng).unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+    assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+
+    // Ensure a witness generator that does not satisfy the R1CS is rejected.
+    let external_circuit = ExternalCircuit::<CurrentNetwork>::new(r1cs, move |inputs: &[_]| {
+        Ok(vec![one, inputs[0] * inputs[1], inputs[0], inputs[1]])
+    });
+    assert!(external_circuit.to_function_inputs(&[a, b]).is_err());
+
+    // Ensure an external circuit cannot be registered for a function that does not enforce it.
+    let r1cs = R1CSFile::new(4, 1, 1, 1, vec![(vec![(2, one)], vec![(3, one)], vec![(1, one)])]).unwrap();
+    let external_circuit = ExternalCircuit::<CurrentNetwork>::new(r1cs, |_: &[_]| bail!("Unreachable"));
+    assert!(process.insert_external_circuit(&program_id, &function_name, external_circuit).is_err());
+
+    // Ensure an external circuit with too many inputs is rejected.
+    let r1cs = R1CSFile::new(18, 0, 17, 0, vec![]).unwrap();
+    let external_circuit = ExternalCircuit::<CurrentNetwork>::new(r1cs, |_: &[_]| bail!("Unreachable"));
+    assert!(external_circuit.to_function(function_name).is_err());
+}
+
+#[test]
+fn test_process_execute_and_verify_call_dynamic() {
+    // Declare the interface.
+    let interface = r"
+interface adder:
+    function add:
+        input as field.public;
+        output as field.private;";
+
+    // Initialize the programs that implement the interface.
+    let one = Program::<CurrentNetwork>::from_str(&format!(
+        r"program one.aleo; {interface}
+
+function add:
+    input r0 as field.public;
+    add r0 1field into r1;
+    output r1 as field.private;"
+    ))
+    .unwrap();
+    let two = Program::<CurrentNetwork>::from_str(&format!(
+        r"program two.aleo; {interface}
+
+function add:
+    input r0 as field.public;
+    add r0 2field into r1;
+    output r1 as field.private;"
+    ))
+    .unwrap();
+    // Initialize a program that does not implement the interface.
+    let three = Program::<CurrentNetwork>::from_str(
+        r"program three.aleo;
+
+function add:
+    input r0 as field.public;
+    add r0 3field into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+    // Initialize the program that dynamically calls the interface.
+    let router = Program::<CurrentNetwork>::from_str(&format!(
+        r"program router.aleo; {interface}
+
+function route:
+    input r0 as address.public;
+    input r1 as field.public;
+    call.dynamic adder::add r0 r1 into r2;
+    output r2 as field.private;"
+    ))
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&one);
+    process.add_program(&two).unwrap();
+    process.add_program(&three).unwrap();
+    process.add_program(&router).unwrap();
+
+    // Ensure a program that does not conform to its interface is rejected.
+    let invalid = Program::<CurrentNetwork>::from_str(&format!(
+        r"program four.aleo; {interface}
+
+function add:
+    input r0 as field.public;
+    add r0 4field into r1;
+    output r1 as field.public;"
+    ))
+    .unwrap();
+    assert!(process.add_program(&invalid).is_err());
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("route").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    for (program_id, expected) in [("one.aleo", "6field"), ("two.aleo", "7field")] {
+        // Declare the inputs.
+        let address = ProgramID::<CurrentNetwork>::from_str(program_id).unwrap().to_address().unwrap();
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("5field").unwrap()];
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, router.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 2);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str(expected).unwrap()]);
+
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str(expected).unwrap()]);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("router", rng).unwrap();
+
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+    }
+
+    // Ensure a dynamic call to a program that does not implement the interface fails.
+    let address = ProgramID::<CurrentNetwork>::from_str("three.aleo").unwrap().to_address().unwrap();
+    let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("5field").unwrap()];
+    assert!(process
+        .authorize::<CurrentAleo, _>(&caller_private_key, router.id(), function_name, inputs.iter(), rng)
+        .is_err());
+}
+
+#[test]
+fn test_process_execute_and_verify_program_owned_record() {
+    // Initialize the program that defines the record.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    cast r1 r0.amount into r2 as token.record;
+    output r2 as token.record;",
+    )
+    .unwrap();
+    // Initialize the program that holds records in custody, and a program that attempts to spend them.
+    let release = |program_id: &str| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            r"import token.aleo;
+program {program_id};
+
+function release:
+    input r0 as token.aleo/token.record;
+    input r1 as address.private;
+    call token.aleo/transfer r0 r1 into r2;
+    output r2 as token.aleo/token.record;"
+        ))
+        .unwrap()
+    };
+    let escrow = release("escrow.aleo");
+    let thief = release("thief.aleo");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&token);
+    process.add_program(&escrow).unwrap();
+    process.add_program(&thief).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Value::from_str(&Address::try_from(&caller_private_key).unwrap().to_string()).unwrap();
+
+    // Mint a record that is owned by the escrow program.
+    let escrow_address = escrow.id().to_address().unwrap();
+    let inputs = [Value::from_str(&escrow_address.to_string()).unwrap(), Value::from_str("100u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            token.id(),
+            Identifier::from_str("mint").unwrap(),
+            inputs.iter(),
+            rng,
+        )
+        .unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    let record = response.outputs()[0].clone();
+
+    // Ensure the signer cannot spend the record directly.
+    let function_name = Identifier::from_str("transfer").unwrap();
+    let inputs = [record.clone(), caller.clone()];
+    assert!(process
+        .authorize::<CurrentAleo, _>(&caller_private_key, token.id(), function_name, inputs.iter(), rng)
+        .is_err());
+
+    // Ensure another program cannot spend the record, as it is not the owner of the record.
+    let function_name = Identifier::from_str("release").unwrap();
+    let inputs = [record, caller];
+    let error = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, thief.id(), function_name, inputs.iter(), rng)
+        .unwrap_err();
+    assert!(error.to_string().contains("Input record must belong to the signer or to the calling program"));
+
+    // Sign the requests of the other program directly, so the authorization is not checked by `authorize`.
+    let transfer_name = Identifier::from_str("transfer").unwrap();
+    let input_types = thief.get_function(&function_name).unwrap().input_types();
+    let request =
+        Request::sign(&caller_private_key, *thief.id(), function_name, inputs.iter(), &input_types, None, true, rng)
+            .unwrap();
+    let root_tvk = Some(*request.tvk());
+    let input_types = token.get_function(&transfer_name).unwrap().input_types();
+    let authorization = Authorization::new(request);
+    authorization.push(
+        Request::sign(
+            &caller_private_key,
+            *token.id(),
+            transfer_name,
+            inputs.iter(),
+            &input_types,
+            root_tvk,
+            false,
+            rng,
+        )
+        .unwrap(),
+    );
+    // Ensure the third-party caller is rejected when the authorization is evaluated and executed.
+    let error = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    assert!(error.to_string().contains("Input record must belong to the signer or to the calling program"));
+    let error = process.execute::<CurrentAleo, _>(authorization, rng).unwrap_err();
+    assert!(error.to_string().contains("Input record must belong to the signer or to the calling program"));
+
+    // Ensure the escrow program can spend the record.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, escrow.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("escrow", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
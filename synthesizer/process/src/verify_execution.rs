@@ -61,7 +61,7 @@ impl<N: Network> Process<N> {
             // Debug-mode only, as the `Transition` constructor recomputes the transition ID at initialization.
             debug_assert_eq!(
                 **transition.id(),
-                N::hash_bhp512(&(transition.to_root()?, *transition.tcm()).to_bits_le())?,
+                Transition::compute_id(transition.to_root()?, *transition.tcm(), transition.memos())?,
                 "The transition ID is incorrect"
             );
 
@@ -183,11 +183,33 @@ impl<N: Network> Process<N> {
         // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
         inputs.extend([*is_root, *parent_x, *parent_y]);
 
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Determine whether each function call is a dynamic call, in the order that they were called.
+        let mut is_dynamic_calls = Vec::new();
+        for instruction in stack.get_function_ref(transition.function_name())?.instructions() {
+            match instruction {
+                Instruction::Call(call) if call.is_function_call(stack.as_ref())? => is_dynamic_calls.push(false),
+                Instruction::CallDynamic(..) => is_dynamic_calls.push(true),
+                _ => (),
+            }
+        }
+
         // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
+        for (transition_id, is_dynamic) in call_graph.get(transition.id()).unwrap().iter().zip_eq(is_dynamic_calls) {
             // Note: This unwrap is safe, as we are processing transitions in post-order,
             // which implies that all child transition IDs have been added to `transition_map`.
             let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
+            // If the call is dynamic, then extend the verifier inputs with the program address and function ID.
+            if is_dynamic {
+                // Compute the x- and y-coordinate of the program address of the dynamic call.
+                let (address_x, address_y) = transition.program_id().to_address()?.to_xy_coordinates();
+                // Compute the function ID of the dynamic call.
+                let function_id =
+                    compute_function_id(&U16::new(N::ID), transition.program_id(), transition.function_name())?;
+                // [Inputs] Extend the verifier inputs with the program address and function ID of the dynamic call.
+                inputs.extend([*address_x, *address_y, *function_id]);
+            }
             // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
             inputs.extend([**transition.tcm()]);
             // [Inputs] Extend the verifier inputs with the input IDs of the external call.
@@ -239,13 +261,16 @@ impl<N: Network> Process<N> {
             fname: Identifier<N>,
             tid: Option<N::TransitionID>,
             children: Option<Vec<usize>>,
+            // The dynamic call, if the transition is dynamically called.
+            // Note: The program ID of a dynamic call is the caller's, until its transition is processed.
+            dynamic: Option<CallDynamic<N>>,
         }
 
         impl<N: Network> TransitionMetadata<N> {
             fn new(counter: &mut usize, pid: ProgramID<N>, fname: Identifier<N>, tid: Option<N::TransitionID>) -> Self {
                 let uid = *counter;
                 *counter += 1;
-                Self { uid, pid, fname, tid, children: None }
+                Self { uid, pid, fname, tid, children: None, dynamic: None }
             }
 
             /// Returns 'true' if the subgraph starting from this transition has been fully-indexed.
@@ -305,9 +330,25 @@ impl<N: Network> Process<N> {
                     ));
                 }
                 // If the stack is not empty, then add the current transition ID to the entry.
-                Some(head) => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
-                    true => head.tid = Some(*transition.id()),
-                    false => bail!("Invalid traversal - unexpected transition in the execution"),
+                Some(head) => match head.dynamic.take() {
+                    // If the entry is a dynamic call, then ensure the transition's program implements the interface.
+                    Some(call) => {
+                        ensure!(
+                            head.fname == *transition.function_name(),
+                            "Invalid traversal - unexpected transition in the execution"
+                        );
+                        check_callee(
+                            self.get_stack(head.pid)?.as_ref(),
+                            self.get_stack(transition.program_id())?.as_ref(),
+                            &call,
+                        )?;
+                        head.pid = *transition.program_id();
+                        head.tid = Some(*transition.id());
+                    }
+                    None => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
+                        true => head.tid = Some(*transition.id()),
+                        false => bail!("Invalid traversal - unexpected transition in the execution"),
+                    },
                 },
             }
 
@@ -326,17 +367,26 @@ impl<N: Network> Process<N> {
                 // Collect the children of the current transition.
                 let mut children = Vec::new();
                 for instruction in function.instructions() {
-                    if let Instruction::Call(call) = instruction {
-                        let (pid, fname) = match call.operator() {
-                            synthesizer_program::CallOperator::Locator(locator) => {
-                                (locator.program_id(), locator.resource())
+                    match instruction {
+                        Instruction::Call(call) => {
+                            let (pid, fname) = match call.operator() {
+                                synthesizer_program::CallOperator::Locator(locator) => {
+                                    (locator.program_id(), locator.resource())
+                                }
+                                synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
+                            };
+                            // Add the child to the traversal stack, only if it is a call to a transition.
+                            if self.get_stack(pid)?.get_function(fname).is_ok() {
+                                children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
                             }
-                            synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
-                        };
-                        // Add the child to the traversal stack, only if it is a call to a transition.
-                        if self.get_stack(pid)?.get_function(fname).is_ok() {
-                            children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
                         }
+                        // Add the dynamic call to the traversal stack, as its program is resolved from the transition.
+                        Instruction::CallDynamic(call) => {
+                            let mut child = TransitionMetadata::new(&mut counter, top.pid, *call.function(), None);
+                            child.dynamic = Some(call.clone());
+                            children.push(child);
+                        }
+                        _ => (),
                     }
                 }
 
@@ -35,7 +35,7 @@ impl<N: Network> Process<N> {
             // Debug-mode only, as the `Transition` constructor recomputes the transition ID at initialization.
             debug_assert_eq!(
                 **fee.id(),
-                N::hash_bhp512(&(fee.to_root()?, *fee.tcm()).to_bits_le())?,
+                Transition::compute_id(fee.to_root()?, *fee.tcm(), fee.memos())?,
                 "Transition ID of the fee is incorrect"
             );
         }
@@ -50,6 +50,8 @@ impl<N: Network> Process<N> {
         ensure!(fee.inputs().len() <= N::MAX_INPUTS, "Fee exceeded maximum number of inputs");
         // Ensure the number of outputs is within the allowed range.
         ensure!(fee.outputs().len() <= N::MAX_INPUTS, "Fee exceeded maximum number of outputs");
+        // Ensure the fee does not contain memos, as nothing binds the fee transition ID.
+        ensure!(fee.memos().is_empty(), "The fee transition cannot contain memos");
 
         // Retrieve the candidate deployment or execution ID.
         let Ok(candidate_id) = fee.deployment_or_execution_id() else {
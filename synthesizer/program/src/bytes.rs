@@ -55,6 +55,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the enum.
+                5 => program.add_enum(EnumType::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the interface.
+                6 => program.add_interface(Interface::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
@@ -104,6 +108,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                     }
                     None => return Err(error(format!("Struct '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::Enum => match self.enums.get(identifier) {
+                    Some(enum_) => {
+                        // Write the variant.
+                        5u8.write_le(&mut writer)?;
+                        // Write the enum.
+                        enum_.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Enum '{identifier}' is not defined."))),
+                },
                 ProgramDefinition::Record => match self.records.get(identifier) {
                     Some(record) => {
                         // Write the variant.
@@ -131,6 +144,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::Interface => match self.interfaces.get(identifier) {
+                    Some(interface) => {
+                        // Write the variant.
+                        6u8.write_le(&mut writer)?;
+                        // Write the interface.
+                        interface.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Interface '{identifier}' is not defined."))),
+                },
             }
         }
 
@@ -21,7 +21,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::InstructionTrait;
+use crate::{InstructionTrait, SourcePosition, Span};
 use console::{
     network::prelude::*,
     program::{Identifier, Register, RegisterType},
@@ -29,7 +29,7 @@ use console::{
 
 use indexmap::IndexSet;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct ClosureCore<N: Network, Instruction: InstructionTrait<N>> {
     /// The name of the closure.
     name: Identifier<N>,
@@ -40,12 +40,29 @@ pub struct ClosureCore<N: Network, Instruction: InstructionTrait<N>> {
     instructions: Vec<Instruction>,
     /// The output statements, in order of the desired output.
     outputs: IndexSet<Output<N>>,
+    /// The span of the closure in the source, if the closure was parsed from a string.
+    span: Option<Span>,
+    /// The spans of the input statements in the source, if the closure was parsed from a string.
+    input_spans: Vec<Span>,
+    /// The spans of the instructions in the source, if the closure was parsed from a string.
+    instruction_spans: Vec<Span>,
+    /// The spans of the output statements in the source, if the closure was parsed from a string.
+    output_spans: Vec<Span>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
     /// Initializes a new closure with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), instructions: Vec::new(), outputs: IndexSet::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            instructions: Vec::new(),
+            outputs: IndexSet::new(),
+            span: None,
+            input_spans: Vec::new(),
+            instruction_spans: Vec::new(),
+            output_spans: Vec::new(),
+        }
     }
 
     /// Returns the name of the closure.
@@ -67,8 +84,48 @@ impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
     }
+
+    /// Returns the span of the closure in the source, if the closure was parsed from a string.
+    pub const fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+
+    /// Returns the span of the input statement at the given index, if the closure was parsed from a string.
+    pub fn input_span(&self, index: usize) -> Option<&Span> {
+        self.input_spans.get(index)
+    }
+
+    /// Returns the span of the instruction at the given index, if the closure was parsed from a string.
+    pub fn instruction_span(&self, index: usize) -> Option<&Span> {
+        self.instruction_spans.get(index)
+    }
+
+    /// Returns the span of the output statement at the given index, if the closure was parsed from a string.
+    pub fn output_span(&self, index: usize) -> Option<&Span> {
+        self.output_spans.get(index)
+    }
+
+    /// Updates the spans, which are relative to the start of the closure, to be relative to the given origin.
+    pub(crate) fn relocate(&mut self, origin: SourcePosition) {
+        self.span = self.span.map(|span| span.relative_to(origin));
+        self.input_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+        self.instruction_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+        self.output_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+    }
 }
 
+impl<N: Network, Instruction: InstructionTrait<N> + PartialEq> PartialEq for ClosureCore<N, Instruction> {
+    /// Returns `true` if the closures are equal, ignoring their spans in the source.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.inputs == other.inputs
+            && self.instructions == other.instructions
+            && self.outputs == other.outputs
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N> + Eq> Eq for ClosureCore<N, Instruction> {}
+
 impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
     /// Adds the input statement to the closure.
     ///
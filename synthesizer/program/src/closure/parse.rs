@@ -20,6 +20,8 @@ impl<N: Network, Instruction: InstructionTrait<N>> Parser for ClosureCore<N, Ins
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
+        // Note: The spans are relative to the start of the closure.
+        let source = string;
         // Parse the 'closure' keyword from the string.
         let (string, _) = tag(Self::type_name())(string)?;
         // Parse the whitespace from the string.
@@ -32,18 +34,23 @@ impl<N: Network, Instruction: InstructionTrait<N>> Parser for ClosureCore<N, Ins
         let (string, _) = tag(":")(string)?;
 
         // Parse the inputs from the string.
-        let (string, inputs) = many1(Input::parse)(string)?;
+        let (string, inputs) = many1(Span::parser(source, Input::parse))(string)?;
         // Parse the instructions from the string.
-        let (string, instructions) = many1(Instruction::parse)(string)?;
+        let (string, instructions) = many1(Span::parser(source, Instruction::parse))(string)?;
         // Parse the outputs from the string.
-        let (string, outputs) = many0(Output::parse)(string)?;
+        let (string, outputs) = many0(Span::parser(source, Output::parse))(string)?;
 
         map_res(take(0usize), move |_| {
             // Initialize a new closure.
             let mut closure = Self::new(name);
-            inputs.iter().cloned().try_for_each(|input| closure.add_input(input))?;
-            instructions.iter().cloned().try_for_each(|instruction| closure.add_instruction(instruction))?;
-            outputs.iter().cloned().try_for_each(|output| closure.add_output(output))?;
+            inputs.iter().cloned().try_for_each(|(input, _)| closure.add_input(input))?;
+            instructions.iter().cloned().try_for_each(|(instruction, _)| closure.add_instruction(instruction))?;
+            outputs.iter().cloned().try_for_each(|(output, _)| closure.add_output(output))?;
+            // Retain the spans of the closure and its statements.
+            closure.span = Some(Span::until(source, string));
+            closure.input_spans = inputs.iter().map(|(_, span)| *span).collect();
+            closure.instruction_spans = instructions.iter().map(|(_, span)| *span).collect();
+            closure.output_spans = outputs.iter().map(|(_, span)| *span).collect();
             Ok::<_, Error>(closure)
         })(string)
     }
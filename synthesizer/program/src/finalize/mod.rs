@@ -18,7 +18,7 @@ use input::*;
 mod bytes;
 mod parse;
 
-use crate::traits::CommandTrait;
+use crate::{traits::CommandTrait, SourcePosition, Span};
 use console::{
     network::prelude::*,
     program::{FinalizeType, Identifier, Register},
@@ -27,7 +27,7 @@ use console::{
 use indexmap::IndexSet;
 use std::collections::HashMap;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct FinalizeCore<N: Network, Command: CommandTrait<N>> {
     /// The name of the associated function.
     name: Identifier<N>,
@@ -40,12 +40,27 @@ pub struct FinalizeCore<N: Network, Command: CommandTrait<N>> {
     num_writes: u16,
     /// A mapping from `Position`s to their index in `commands`.
     positions: HashMap<Identifier<N>, usize>,
+    /// The span of the finalize logic in the source, if the finalize logic was parsed from a string.
+    span: Option<Span>,
+    /// The spans of the input statements in the source, if the finalize logic was parsed from a string.
+    input_spans: Vec<Span>,
+    /// The spans of the commands in the source, if the finalize logic was parsed from a string.
+    command_spans: Vec<Span>,
 }
 
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), num_writes: 0, positions: HashMap::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            num_writes: 0,
+            positions: HashMap::new(),
+            span: None,
+            input_spans: Vec::new(),
+            command_spans: Vec::new(),
+        }
     }
 
     /// Returns the name of the associated function.
@@ -77,8 +92,43 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
         &self.positions
     }
+
+    /// Returns the span of the finalize logic in the source, if the finalize logic was parsed from a string.
+    pub const fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+
+    /// Returns the span of the input statement at the given index, if the finalize logic was parsed from a string.
+    pub fn input_span(&self, index: usize) -> Option<&Span> {
+        self.input_spans.get(index)
+    }
+
+    /// Returns the span of the command at the given index, if the finalize logic was parsed from a string.
+    pub fn command_span(&self, index: usize) -> Option<&Span> {
+        self.command_spans.get(index)
+    }
+
+    /// Updates the spans, which are relative to the start of the finalize logic, to be relative to the given origin.
+    pub(crate) fn relocate(&mut self, origin: SourcePosition) {
+        self.span = self.span.map(|span| span.relative_to(origin));
+        self.input_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+        self.command_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N> + PartialEq> PartialEq for FinalizeCore<N, Command> {
+    /// Returns `true` if the finalize logic is equal, ignoring the spans in the source.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.inputs == other.inputs
+            && self.commands == other.commands
+            && self.num_writes == other.num_writes
+            && self.positions == other.positions
+    }
 }
 
+impl<N: Network, Command: CommandTrait<N> + Eq> Eq for FinalizeCore<N, Command> {}
+
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// Adds the input statement to finalize.
     ///
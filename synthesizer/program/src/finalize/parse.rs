@@ -20,6 +20,8 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
+        // Note: The spans are relative to the start of the finalize logic.
+        let source = string;
         // Parse the 'finalize' keyword from the string.
         let (string, _) = tag(Self::type_name())(string)?;
         // Parse the whitespace from the string.
@@ -32,21 +34,25 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
         let (string, _) = tag(":")(string)?;
 
         // Parse the inputs from the string.
-        let (string, inputs) = many0(Input::parse)(string)?;
+        let (string, inputs) = many0(Span::parser(source, Input::parse))(string)?;
         // Parse the commands from the string.
-        let (string, commands) = many1(Command::parse)(string)?;
+        let (string, commands) = many1(Span::parser(source, Command::parse))(string)?;
 
         map_res(take(0usize), move |_| {
             // Initialize a new finalize.
             let mut finalize = Self::new(name);
-            if let Err(error) = inputs.iter().cloned().try_for_each(|input| finalize.add_input(input)) {
+            if let Err(error) = inputs.iter().cloned().try_for_each(|(input, _)| finalize.add_input(input)) {
                 eprintln!("{error}");
                 return Err(error);
             }
-            if let Err(error) = commands.iter().cloned().try_for_each(|command| finalize.add_command(command)) {
+            if let Err(error) = commands.iter().cloned().try_for_each(|(command, _)| finalize.add_command(command)) {
                 eprintln!("{error}");
                 return Err(error);
             }
+            // Retain the spans of the finalize logic and its statements.
+            finalize.span = Some(Span::until(source, string));
+            finalize.input_spans = inputs.iter().map(|(_, span)| *span).collect();
+            finalize.command_spans = commands.iter().map(|(_, span)| *span).collect();
             Ok::<_, Error>(finalize)
         })(string)
     }
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::network::prelude::recognize;
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Formats the given program source into the canonical layout of the program, preserving its comments.
+    ///
+    /// The statements are printed as in the `Display` of the program, where a comment is printed
+    /// at the end of the line of the statement it follows on the same line, and otherwise,
+    /// on its own line before the statement that follows it.
+    ///
+    /// Note: The order of the imports, definitions, and statements is part of the program encoding,
+    /// and is preserved, so that the formatted program has the same `to_bytes_le` as the source.
+    pub fn format(source: &str) -> Result<String> {
+        // Parse the program from the source.
+        let program = Self::from_str(source)?;
+        // Scan the source for its statements and comments.
+        let (num_statements, comments) = scan(source)?;
+
+        // Print the canonical layout of the program, inserting the comments.
+        let mut comments = comments.into_iter().peekable();
+        let mut formatted = String::with_capacity(source.len());
+        let mut statement = 0;
+        for line in program.to_string().lines() {
+            // Retrieve the index of the statement after this line.
+            statement += scan(line)?.0;
+            // Retrieve the comments attached to the statements on this line.
+            let mut leading = Vec::new();
+            let mut trailing = Vec::new();
+            while let Some(comment) = comments.next_if(|comment| comment.statement < statement) {
+                match comment.is_trailing {
+                    true => trailing.push(comment.text),
+                    false => leading.push(comment.text),
+                }
+            }
+            // Print the leading comments, with the indentation of the line.
+            let indentation = &line[..line.len() - line.trim_start().len()];
+            leading.iter().for_each(|text| formatted.push_str(&format!("{indentation}{text}\n")));
+            // Print the line, followed by the trailing comments.
+            formatted.push_str(line);
+            trailing.iter().for_each(|text| formatted.push_str(&format!(" {text}")));
+            formatted.push('\n');
+        }
+        // Ensure every statement in the source was printed.
+        ensure!(statement == num_statements, "Failed to match the statements of the program '{}'", program.id());
+        // Print the comments after the last statement.
+        comments.for_each(|comment| formatted.push_str(&format!("{}\n", comment.text)));
+
+        // Ensure the formatted program has the same encoding as the source.
+        ensure!(
+            Self::from_str(&formatted)?.to_bytes_le()? == program.to_bytes_le()?,
+            "Formatting the program '{}' changed its encoding",
+            program.id()
+        );
+        Ok(formatted)
+    }
+}
+
+/// A comment in the source of a program.
+struct Comment<'a> {
+    /// The index of the statement the comment is attached to.
+    statement: usize,
+    /// Whether the comment follows the statement on the same line, or otherwise precedes the statement.
+    is_trailing: bool,
+    /// The comment, including its delimiters.
+    text: &'a str,
+}
+
+/// Scans the given source, returning the number of statements and the comments.
+/// A statement is terminated by a ';' or ':' that is not nested in brackets, braces, or a string, or part of a '::'.
+fn scan(source: &str) -> Result<(usize, Vec<Comment<'_>>)> {
+    let mut num_statements = 0;
+    let mut comments = Vec::new();
+    // The nesting depth of the brackets and braces.
+    let mut depth = 0usize;
+    // Whether a statement has started, and whether a line has ended, since the last statement.
+    let mut is_in_statement = false;
+    let mut is_new_line = true;
+
+    let mut string = source;
+    while let Some(character) = string.chars().next() {
+        // Parse a comment.
+        if string.starts_with("//") || string.starts_with("/*") {
+            let (remainder, text) = recognize(Sanitizer::parse_comment)(string)
+                .map_err(|error| anyhow!("Failed to parse the comment at {}: {error}", position(source, string)))?;
+            // A comment on the line of the last statement is attached to it, and otherwise to the next statement.
+            comments.push(match is_in_statement || is_new_line {
+                true => Comment { statement: num_statements, is_trailing: false, text: text.trim_end() },
+                false => Comment { statement: num_statements - 1, is_trailing: true, text: text.trim_end() },
+            });
+            // Note: A line comment includes the end of the line.
+            is_new_line |= text.starts_with("//");
+            string = remainder;
+            continue;
+        }
+        // Skip a string, which may contain delimiters.
+        if character == '"' {
+            let mut is_escaped = false;
+            let length = string[1..]
+                .char_indices()
+                .find(|(_, character)| {
+                    let is_end = !is_escaped && *character == '"';
+                    is_escaped = !is_escaped && *character == '\\';
+                    is_end
+                })
+                .map(|(index, _)| index + 2)
+                .ok_or_else(|| anyhow!("Failed to find the end of the string at {}", position(source, string)))?;
+            is_in_statement = true;
+            string = &string[length..];
+            continue;
+        }
+        // Skip a path separator, as in an enum variant, which is not the end of a statement.
+        if string.starts_with("::") {
+            is_in_statement = true;
+            string = &string[2..];
+            continue;
+        }
+        match character {
+            ';' | ':' if depth == 0 => {
+                num_statements += 1;
+                is_in_statement = false;
+                is_new_line = false;
+            }
+            '\n' => is_new_line = true,
+            character if character.is_whitespace() => (),
+            character => {
+                is_in_statement = true;
+                match character {
+                    '[' | '{' => depth += 1,
+                    ']' | '}' => depth = depth.saturating_sub(1),
+                    _ => (),
+                }
+            }
+        }
+        string = &string[character.len_utf8()..];
+    }
+    Ok((num_statements, comments))
+}
+
+/// Returns the position of the given remainder in the given source.
+fn position(source: &str, remainder: &str) -> SourcePosition {
+    SourcePosition::after(&source[..source.len() - remainder.len()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_format() -> Result<()> {
+        let source = r"// A program to format.
+program   format_me.aleo;   // The program ID.
+/* The account
+   balances. */
+mapping account : key as address.public;
+        value as u64.public;
+function transfer:
+  input r0 as address.public;
+    input r1 as u64.public; // The amount.
+  // Schedule the finalize logic.
+  async transfer r0    r1 into r2;
+  output r2 as format_me.aleo/transfer.future;
+finalize transfer:
+  input r0 as address.public; input r1 as u64.public;
+  get.or_use account[r0] 0u64 into r2; add r2 r1 into r3; /* Add the amount. */
+  set r3 into account[r0];
+// The end.
+";
+        let expected = r"// A program to format.
+program format_me.aleo; // The program ID.
+
+/* The account
+   balances. */
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public; // The amount.
+    // Schedule the finalize logic.
+    async transfer r0 r1 into r2;
+    output r2 as format_me.aleo/transfer.future;
+
+finalize transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3; /* Add the amount. */
+    set r3 into account[r0];
+// The end.
+";
+        // Format the program.
+        let formatted = Program::<CurrentNetwork>::format(source)?;
+        assert_eq!(expected, formatted);
+        // Ensure the encoding is unchanged.
+        assert_eq!(
+            Program::<CurrentNetwork>::from_str(source)?.to_bytes_le()?,
+            Program::<CurrentNetwork>::from_str(&formatted)?.to_bytes_le()?
+        );
+        // Ensure formatting is idempotent.
+        assert_eq!(formatted, Program::<CurrentNetwork>::format(&formatted)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_without_comments() -> Result<()> {
+        let source = r"program to_format.aleo;
+
+struct message:
+    first as field;
+    second as [u8; 4u32];
+
+function compute:
+    input r0 as message.private;
+    cast 1u8 2u8 3u8 4u8 into r1 as [u8; 4u32];
+    output r1 as [u8; 4u32].private;
+";
+        // Ensure a program without comments is formatted as its `Display`.
+        let formatted = Program::<CurrentNetwork>::format(source)?;
+        assert_eq!(Program::<CurrentNetwork>::from_str(source)?.to_string(), formatted);
+        assert_eq!(source, formatted);
+
+        // Ensure an invalid program is not formatted.
+        assert!(Program::<CurrentNetwork>::format("program to_format.aleo; function compute").is_err());
+
+        Ok(())
+    }
+}
@@ -24,6 +24,8 @@ mod parse;
 use crate::{
     finalize::FinalizeCore,
     traits::{CommandTrait, InstructionTrait},
+    SourcePosition,
+    Span,
 };
 use console::{
     network::prelude::*,
@@ -32,7 +34,7 @@ use console::{
 
 use indexmap::IndexSet;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct FunctionCore<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
     /// The name of the function.
     name: Identifier<N>,
@@ -45,12 +47,30 @@ pub struct FunctionCore<N: Network, Instruction: InstructionTrait<N>, Command: C
     outputs: IndexSet<Output<N>>,
     /// The optional finalize logic.
     finalize_logic: Option<FinalizeCore<N, Command>>,
+    /// The span of the function in the source, if the function was parsed from a string.
+    span: Option<Span>,
+    /// The spans of the input statements in the source, if the function was parsed from a string.
+    input_spans: Vec<Span>,
+    /// The spans of the instructions in the source, if the function was parsed from a string.
+    instruction_spans: Vec<Span>,
+    /// The spans of the output statements in the source, if the function was parsed from a string.
+    output_spans: Vec<Span>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionCore<N, Instruction, Command> {
     /// Initializes a new function with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), instructions: Vec::new(), outputs: IndexSet::new(), finalize_logic: None }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            instructions: Vec::new(),
+            outputs: IndexSet::new(),
+            finalize_logic: None,
+            span: None,
+            input_spans: Vec::new(),
+            instruction_spans: Vec::new(),
+            output_spans: Vec::new(),
+        }
     }
 
     /// Returns the name of the function.
@@ -87,6 +107,55 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     pub const fn finalize_logic(&self) -> Option<&FinalizeCore<N, Command>> {
         self.finalize_logic.as_ref()
     }
+
+    /// Returns the span of the function in the source, if the function was parsed from a string.
+    pub const fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+
+    /// Returns the span of the input statement at the given index, if the function was parsed from a string.
+    pub fn input_span(&self, index: usize) -> Option<&Span> {
+        self.input_spans.get(index)
+    }
+
+    /// Returns the span of the instruction at the given index, if the function was parsed from a string.
+    pub fn instruction_span(&self, index: usize) -> Option<&Span> {
+        self.instruction_spans.get(index)
+    }
+
+    /// Returns the span of the output statement at the given index, if the function was parsed from a string.
+    pub fn output_span(&self, index: usize) -> Option<&Span> {
+        self.output_spans.get(index)
+    }
+
+    /// Updates the spans, which are relative to the start of the function, to be relative to the given origin.
+    pub(crate) fn relocate(&mut self, origin: SourcePosition) {
+        self.span = self.span.map(|span| span.relative_to(origin));
+        self.input_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+        self.instruction_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+        self.output_spans.iter_mut().for_each(|span| *span = span.relative_to(origin));
+        if let Some(finalize) = &mut self.finalize_logic {
+            finalize.relocate(origin);
+        }
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N> + PartialEq, Command: CommandTrait<N> + PartialEq> PartialEq
+    for FunctionCore<N, Instruction, Command>
+{
+    /// Returns `true` if the functions are equal, ignoring their spans in the source.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.inputs == other.inputs
+            && self.instructions == other.instructions
+            && self.outputs == other.outputs
+            && self.finalize_logic == other.finalize_logic
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N> + Eq, Command: CommandTrait<N> + Eq> Eq
+    for FunctionCore<N, Instruction, Command>
+{
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionCore<N, Instruction, Command> {
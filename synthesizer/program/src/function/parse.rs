@@ -22,6 +22,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
+        // Note: The spans are relative to the start of the function.
+        let source = string;
         // Parse the 'function' keyword from the string.
         let (string, _) = tag(Self::type_name())(string)?;
         // Parse the whitespace from the string.
@@ -34,38 +36,46 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, _) = tag(":")(string)?;
 
         // Parse the inputs from the string.
-        let (string, inputs) = many0(Input::parse)(string)?;
+        let (string, inputs) = many0(Span::parser(source, Input::parse))(string)?;
         // Parse the instructions from the string.
-        let (string, instructions) = many0(Instruction::parse)(string)?;
+        let (string, instructions) = many0(Span::parser(source, Instruction::parse))(string)?;
         // Parse the outputs from the string.
-        let (string, outputs) = many0(Output::parse)(string)?;
+        let (string, outputs) = many0(Span::parser(source, Output::parse))(string)?;
 
         // Parse an optional finalize command from the string.
-        let (string, finalize) = opt(FinalizeCore::parse)(string)?;
+        let (string, finalize) = opt(Span::parser(source, FinalizeCore::parse))(string)?;
 
         map_res(take(0usize), move |_| {
             // Initialize a new function.
             let mut function = Self::new(name);
-            if let Err(error) = inputs.iter().cloned().try_for_each(|input| function.add_input(input)) {
+            if let Err(error) = inputs.iter().cloned().try_for_each(|(input, _)| function.add_input(input)) {
                 eprintln!("{error}");
                 return Err(error);
             }
             if let Err(error) =
-                instructions.iter().cloned().try_for_each(|instruction| function.add_instruction(instruction))
+                instructions.iter().cloned().try_for_each(|(instruction, _)| function.add_instruction(instruction))
             {
                 eprintln!("{error}");
                 return Err(error);
             }
-            if let Err(error) = outputs.iter().cloned().try_for_each(|output| function.add_output(output)) {
+            if let Err(error) = outputs.iter().cloned().try_for_each(|(output, _)| function.add_output(output)) {
                 eprintln!("{error}");
                 return Err(error);
             }
-            if let Some(finalize) = &finalize {
-                if let Err(error) = function.add_finalize(finalize.clone()) {
+            if let Some((finalize, span)) = &finalize {
+                // Update the spans of the finalize logic to be relative to the start of the function.
+                let mut finalize = finalize.clone();
+                finalize.relocate(span.start());
+                if let Err(error) = function.add_finalize(finalize) {
                     eprintln!("{error}");
                     return Err(error);
                 }
             }
+            // Retain the spans of the function and its statements.
+            function.span = Some(Span::until(source, string));
+            function.input_spans = inputs.iter().map(|(_, span)| *span).collect();
+            function.instruction_spans = instructions.iter().map(|(_, span)| *span).collect();
+            function.output_spans = outputs.iter().map(|(_, span)| *span).collect();
             Ok::<_, Error>(function)
         })(string)
     }
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Interface<N> {
+    /// Reads the interface from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the interface name.
+        let name = Identifier::<N>::read_le(&mut reader)?;
+        // Read the number of functions.
+        let num_functions = u16::read_le(&mut reader)?;
+        // Ensure the number of functions is within the allowed range.
+        if num_functions as usize > N::MAX_FUNCTIONS {
+            return Err(error(format!("Failed to deserialize an interface: too many functions ({num_functions})")));
+        }
+        // Read the function signatures.
+        let functions = (0..num_functions).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the new interface.
+        Self::new(name, functions).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for Interface<N> {
+    /// Writes the interface to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the interface name.
+        self.name.write_le(&mut writer)?;
+        // Write the number of functions.
+        u16::try_from(self.functions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the function signatures.
+        self.functions.values().try_for_each(|signature| signature.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_interface_bytes() -> Result<()> {
+        let interface_string = r"
+interface token:
+    function transfer:
+        input as address.public;
+        input as u64.public;
+        output as u64.public;";
+
+        let expected = Interface::<CurrentNetwork>::from_str(interface_string)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Interface::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod signature;
+pub use signature::*;
+
+mod bytes;
+mod parse;
+
+use console::{network::prelude::*, program::Identifier};
+
+use indexmap::IndexMap;
+
+/// An interface declares a set of function signatures, which a program implements by declaring the same
+/// interface and defining a function with the same name, inputs, and outputs for each signature.
+///
+/// Note: The callee of a dynamic call is resolved at runtime, so the caller cannot account for its
+/// finalize cost or its calls. As such, an implementing function cannot contain a finalize scope or calls.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Interface<N: Network> {
+    /// The name of the interface.
+    name: Identifier<N>,
+    /// The function signatures, in the order they were declared.
+    functions: IndexMap<Identifier<N>, FunctionSignature<N>>,
+}
+
+impl<N: Network> Interface<N> {
+    /// Initializes a new interface with the given name and function signatures.
+    pub fn new(name: Identifier<N>, functions: Vec<FunctionSignature<N>>) -> Result<Self> {
+        // Ensure the interface declares at least one function.
+        ensure!(!functions.is_empty(), "Interface '{name}' must declare at least one function");
+        // Ensure the number of functions is within the allowed range.
+        ensure!(functions.len() <= N::MAX_FUNCTIONS, "Interface '{name}' exceeds the maximum number of functions");
+
+        // Initialize the function signatures.
+        let mut signatures = IndexMap::with_capacity(functions.len());
+        for function in functions {
+            // Retrieve the function name.
+            let function_name = *function.name();
+            // Ensure the function name is unique.
+            ensure!(
+                signatures.insert(function_name, function).is_none(),
+                "Duplicate function '{function_name}' in interface '{name}'"
+            );
+        }
+        Ok(Self { name, functions: signatures })
+    }
+
+    /// Returns the name of the interface.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the function signatures.
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, FunctionSignature<N>> {
+        &self.functions
+    }
+
+    /// Returns `true` if the interface declares a function with the given name.
+    pub fn contains_function(&self, name: &Identifier<N>) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Returns the function signature with the given name.
+    pub fn get_function(&self, name: &Identifier<N>) -> Result<&FunctionSignature<N>> {
+        match self.functions.get(name) {
+            Some(signature) => Ok(signature),
+            None => bail!("Function '{name}' is not declared in interface '{}'", self.name),
+        }
+    }
+}
+
+impl<N: Network> TypeName for Interface<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "interface"
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for Interface<N> {
+    /// Parses a string into an interface.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'interface' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the interface name from the string.
+        let (string, name) = Identifier::<N>::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the function signatures from the string.
+        map_res(many1(FunctionSignature::parse), move |functions| Self::new(name, functions))(string)
+    }
+}
+
+impl<N: Network> FromStr for Interface<N> {
+    type Err = Error;
+
+    /// Returns an interface from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Interface<N> {
+    /// Prints the interface as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Interface<N> {
+    /// Prints the interface as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the interface to a string.
+        write!(f, "{} {}:", Self::type_name(), self.name)?;
+        // Write each function signature, indented under the interface.
+        for signature in self.functions.values() {
+            signature.to_string().lines().try_for_each(|line| write!(f, "\n    {line}"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_interface_parse() {
+        let interface = Interface::<CurrentNetwork>::parse(
+            r"
+interface token:
+    function transfer:
+        input as address.public;
+        input as u64.public;
+        output as u64.public;
+    function balance:
+        input as address.public;
+        output as u64.public;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("token", interface.name().to_string());
+        assert_eq!(2, interface.functions().len());
+
+        // Ensure duplicate functions are rejected.
+        let duplicate = r"
+interface token:
+    function balance:
+        output as u64.public;
+    function balance:
+        output as u64.public;";
+        assert!(Interface::<CurrentNetwork>::parse(duplicate).is_err());
+    }
+
+    #[test]
+    fn test_interface_display() {
+        let expected = r"interface token:
+    function transfer:
+        input as address.public;
+        input as u64.public;
+        output as u64.public;
+    function balance:
+        input as address.public;
+        output as u64.public;";
+        let interface = Interface::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{interface}"));
+    }
+}
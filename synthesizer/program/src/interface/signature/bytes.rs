@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for FunctionSignature<N> {
+    /// Reads the function signature from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the function name.
+        let name = Identifier::<N>::read_le(&mut reader)?;
+
+        // Read the number of inputs.
+        let num_inputs = u16::read_le(&mut reader)?;
+        // Ensure the number of inputs is within the allowed range.
+        if num_inputs as usize > N::MAX_INPUTS {
+            return Err(error(format!("Failed to deserialize a function signature: too many inputs ({num_inputs})")));
+        }
+        // Read the input types.
+        let inputs = (0..num_inputs).map(|_| ValueType::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Read the number of outputs.
+        let num_outputs = u16::read_le(&mut reader)?;
+        // Ensure the number of outputs is within the allowed range.
+        if num_outputs as usize > N::MAX_OUTPUTS {
+            return Err(error(format!("Failed to deserialize a function signature: too many outputs ({num_outputs})")));
+        }
+        // Read the output types.
+        let outputs = (0..num_outputs).map(|_| ValueType::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Return the function signature.
+        Self::new(name, inputs, outputs).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for FunctionSignature<N> {
+    /// Writes the function signature to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the function name.
+        self.name.write_le(&mut writer)?;
+
+        // Write the number of inputs.
+        u16::try_from(self.inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the input types.
+        self.inputs.iter().try_for_each(|input| input.write_le(&mut writer))?;
+
+        // Write the number of outputs.
+        u16::try_from(self.outputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the output types.
+        self.outputs.iter().try_for_each(|output| output.write_le(&mut writer))
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ValueType},
+};
+
+/// A function signature declares the input and output types of a function in an interface, and is of the form
+/// `function {name}:` followed by statements of the form `input as {value_type};` and `output as {value_type};`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FunctionSignature<N: Network> {
+    /// The name of the function.
+    name: Identifier<N>,
+    /// The input value types.
+    inputs: Vec<ValueType<N>>,
+    /// The output value types.
+    outputs: Vec<ValueType<N>>,
+}
+
+impl<N: Network> FunctionSignature<N> {
+    /// Initializes a new function signature with the given name, input types, and output types.
+    pub fn new(name: Identifier<N>, inputs: Vec<ValueType<N>>, outputs: Vec<ValueType<N>>) -> Result<Self> {
+        // Ensure the signature declares at least one input or output.
+        ensure!(!inputs.is_empty() || !outputs.is_empty(), "Function signature '{name}' is missing inputs and outputs");
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(inputs.len() <= N::MAX_INPUTS, "Function signature '{name}' exceeds the maximum number of inputs");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(outputs.len() <= N::MAX_OUTPUTS, "Function signature '{name}' exceeds the maximum number of outputs");
+        // Ensure the inputs and outputs are plaintext types.
+        // Note: Records and futures refer to the program of the callee, which is only known at runtime.
+        for value_type in inputs.iter().chain(outputs.iter()) {
+            match value_type {
+                ValueType::Constant(..) | ValueType::Public(..) | ValueType::Private(..) => (),
+                _ => bail!("Function signature '{name}' cannot contain the type '{value_type}'"),
+            }
+        }
+        Ok(Self { name, inputs, outputs })
+    }
+
+    /// Returns the name of the function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the input value types.
+    pub fn inputs(&self) -> &[ValueType<N>] {
+        &self.inputs
+    }
+
+    /// Returns the output value types.
+    pub fn outputs(&self) -> &[ValueType<N>] {
+        &self.outputs
+    }
+}
+
+impl<N: Network> TypeName for FunctionSignature<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "function"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_function_signature_new() -> Result<()> {
+        let name = Identifier::<CurrentNetwork>::from_str("transfer")?;
+        let public = ValueType::<CurrentNetwork>::from_str("u64.public")?;
+        let record = ValueType::<CurrentNetwork>::from_str("token.record")?;
+
+        // Ensure a signature with plaintext inputs and outputs is valid.
+        assert!(FunctionSignature::new(name, vec![public.clone()], vec![public.clone()]).is_ok());
+        // Ensure a signature must declare at least one input or output.
+        assert!(FunctionSignature::new(name, vec![], vec![]).is_err());
+        // Ensure a signature cannot contain a record.
+        assert!(FunctionSignature::new(name, vec![record.clone()], vec![public]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for FunctionSignature<N> {
+    /// Parses a string into a function signature.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a statement of the form `{keyword} as {value_type};`.
+        fn parse_statement<'a, N: Network>(keyword: &'static str) -> impl Fn(&'a str) -> ParserResult<'a, ValueType<N>> {
+            move |string: &'a str| {
+                // Parse the whitespace and comments from the string.
+                let (string, _) = Sanitizer::parse(string)?;
+                // Parse the keyword from the string.
+                let (string, _) = tag(keyword)(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the "as" from the string.
+                let (string, _) = tag("as")(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the value type from the string.
+                let (string, value_type) = ValueType::parse(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the semicolon from the string.
+                let (string, _) = tag(";")(string)?;
+                // Return the value type.
+                Ok((string, value_type))
+            }
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'function' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the function name from the string.
+        let (string, name) = Identifier::<N>::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+
+        // Parse the input and output statements from the string.
+        // Note: A signature declares at least one statement, which distinguishes it from a function definition.
+        map_res(
+            pair(many0(parse_statement("input")), many0(parse_statement("output"))),
+            move |(inputs, outputs)| Self::new(name, inputs, outputs),
+        )(string)
+    }
+}
+
+impl<N: Network> FromStr for FunctionSignature<N> {
+    type Err = Error;
+
+    /// Returns a function signature from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for FunctionSignature<N> {
+    /// Prints the function signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for FunctionSignature<N> {
+    /// Prints the function signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the function signature to a string.
+        write!(f, "{} {}:", Self::type_name(), self.name)?;
+        self.inputs.iter().try_for_each(|input| write!(f, "\n    input as {input};"))?;
+        self.outputs.iter().try_for_each(|output| write!(f, "\n    output as {output};"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_function_signature_parse() {
+        let signature = FunctionSignature::<CurrentNetwork>::parse(
+            r"
+function transfer:
+    input as address.public;
+    input as u64.public;
+    output as u64.private;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("transfer", signature.name().to_string());
+        assert_eq!(2, signature.inputs().len());
+        assert_eq!(1, signature.outputs().len());
+
+        // Ensure a function definition is not parsed as a signature.
+        let function = r"
+function transfer:
+    input r0 as u64.public;
+    output r0 as u64.public;";
+        assert!(FunctionSignature::<CurrentNetwork>::parse(function).is_err());
+    }
+
+    #[test]
+    fn test_function_signature_display() {
+        let expected = r"function transfer:
+    input as address.public;
+    input as u64.public;
+    output as u64.private;";
+        let signature = FunctionSignature::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{signature}"));
+    }
+}
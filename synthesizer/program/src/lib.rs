@@ -33,18 +33,26 @@ pub use function::*;
 mod import;
 pub use import::*;
 
+mod interface;
+pub use interface::*;
+
 pub mod logic;
 pub use logic::*;
 
 mod mapping;
 pub use mapping::*;
 
+mod span;
+pub use span::*;
+
 pub mod traits;
 pub use traits::*;
 
 mod bytes;
+mod format;
 mod parse;
 mod serialize;
+mod upgrade;
 
 use console::{
     network::prelude::{
@@ -84,7 +92,7 @@ use console::{
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{EnumType, Identifier, PlaintextType, ProgramID, RecordType, StructType, ValueType},
 };
 
 use indexmap::IndexMap;
@@ -95,12 +103,16 @@ enum ProgramDefinition {
     Mapping,
     /// A program struct.
     Struct,
+    /// A program enum.
+    Enum,
     /// A program record.
     Record,
     /// A program closure.
     Closure,
     /// A program function.
     Function,
+    /// A program interface.
+    Interface,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -115,12 +127,16 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     mappings: IndexMap<Identifier<N>, Mapping<N>>,
     /// A map of the declared structs for the program.
     structs: IndexMap<Identifier<N>, StructType<N>>,
+    /// A map of the declared enums for the program.
+    enums: IndexMap<Identifier<N>, EnumType<N>>,
     /// A map of the declared record types for the program.
     records: IndexMap<Identifier<N>, RecordType<N>>,
     /// A map of the declared closures for the program.
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// A map of the declared interfaces for the program.
+    interfaces: IndexMap<Identifier<N>, Interface<N>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -136,9 +152,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             identifiers: IndexMap::new(),
             mappings: IndexMap::new(),
             structs: IndexMap::new(),
+            enums: IndexMap::new(),
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            interfaces: IndexMap::new(),
         })
     }
 
@@ -168,6 +186,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.structs
     }
 
+    /// Returns the enums in the program.
+    pub const fn enums(&self) -> &IndexMap<Identifier<N>, EnumType<N>> {
+        &self.enums
+    }
+
     /// Returns the records in the program.
     pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
         &self.records
@@ -183,6 +206,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the interfaces in the program.
+    pub const fn interfaces(&self) -> &IndexMap<Identifier<N>, Interface<N>> {
+        &self.interfaces
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -198,6 +226,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.structs.contains_key(name)
     }
 
+    /// Returns `true` if the program contains an enum with the given name.
+    pub fn contains_enum(&self, name: &Identifier<N>) -> bool {
+        self.enums.contains_key(name)
+    }
+
     /// Returns `true` if the program contains a record with the given name.
     pub fn contains_record(&self, name: &Identifier<N>) -> bool {
         self.records.contains_key(name)
@@ -213,6 +246,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.functions.contains_key(name)
     }
 
+    /// Returns `true` if the program contains an interface with the given name.
+    pub fn contains_interface(&self, name: &Identifier<N>) -> bool {
+        self.interfaces.contains_key(name)
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -235,6 +273,18 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(struct_)
     }
 
+    /// Returns the enum with the given name.
+    pub fn get_enum(&self, name: &Identifier<N>) -> Result<&EnumType<N>> {
+        // Attempt to retrieve the enum.
+        let enum_ = self.enums.get(name).ok_or_else(|| anyhow!("Enum '{name}' is not defined."))?;
+        // Ensure the enum name matches.
+        ensure!(enum_.name() == name, "Expected enum '{name}', but found enum '{}'", enum_.name());
+        // Ensure the enum contains variants.
+        ensure!(!enum_.variants().is_empty(), "Enum '{name}' is missing variants.");
+        // Return the enum.
+        Ok(enum_)
+    }
+
     /// Returns the record with the given name.
     pub fn get_record(&self, name: &Identifier<N>) -> Result<&RecordType<N>> {
         // Attempt to retrieve the record.
@@ -283,6 +333,37 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the interface with the given name.
+    pub fn get_interface(&self, name: &Identifier<N>) -> Result<&Interface<N>> {
+        // Attempt to retrieve the interface.
+        let interface = self.interfaces.get(name).ok_or_else(|| anyhow!("Interface '{name}' is not defined."))?;
+        // Ensure the interface name matches.
+        ensure!(interface.name() == name, "Expected interface '{name}', but found interface '{}'", interface.name());
+        // Return the interface.
+        Ok(interface)
+    }
+
+    /// Returns `true` if the program implements the given interface.
+    /// A program implements an interface if it declares the same interface, and defines a function
+    /// with the same name, input types, and output types for each function signature,
+    /// without a finalize scope and without calls.
+    pub fn implements_interface(&self, interface: &Interface<N>) -> bool {
+        // Ensure the program declares the same interface.
+        if self.interfaces.get(interface.name()) != Some(interface) {
+            return false;
+        }
+        // Ensure the program defines each function in the interface.
+        interface.functions().values().all(|signature| match self.functions.get(signature.name()) {
+            Some(function) => {
+                function.input_types() == signature.inputs()
+                    && function.output_types() == signature.outputs()
+                    && function.finalize_logic().is_none()
+                    && !function.instructions().iter().any(|instruction| instruction.is_call())
+            }
+            None => false,
+        })
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -357,6 +438,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// This method will halt if the struct name is already in use in the program.
     /// This method will halt if the struct name is a reserved opcode or keyword.
     /// This method will halt if any structs in the struct's members are not already defined.
+    /// This method will halt if any of the struct's members is an enum.
     #[inline]
     fn add_struct(&mut self, struct_: StructType<N>) -> Result<()> {
         // Retrieve the struct name.
@@ -389,14 +471,28 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
-                PlaintextType::Array(array_type) => {
-                    if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
-                        // Ensure the member struct name exists in the program.
-                        if !self.structs.contains_key(struct_name) {
-                            bail!("'{struct_name}' in array '{array_type}' is not defined.")
-                        }
+                PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                    // Ensure the member struct name exists in the program.
+                    PlaintextType::Struct(struct_name) if !self.structs.contains_key(struct_name) => {
+                        bail!("'{struct_name}' in array '{array_type}' is not defined.")
                     }
+                    // Ensure the array does not contain enums.
+                    PlaintextType::Enum(..) => {
+                        bail!("Array '{array_type}' in struct '{struct_name}' cannot contain an enum.")
+                    }
+                    _ => continue,
+                },
+                // Note: Structs cannot contain enums, as structs may be used in records.
+                PlaintextType::Enum(member_identifier) => {
+                    bail!("Enum '{member_identifier}' in struct '{struct_name}' is not supported.")
                 }
+                PlaintextType::Vector(vector_type) => match vector_type.base_element_type() {
+                    // Ensure the member struct name exists in the program.
+                    PlaintextType::Struct(struct_name) if !self.structs.contains_key(struct_name) => {
+                        bail!("'{struct_name}' in vector '{vector_type}' is not defined.")
+                    }
+                    _ => continue,
+                },
             }
         }
 
@@ -411,6 +507,64 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(())
     }
 
+    /// Adds a new enum to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the enum was previously added.
+    /// This method will halt if the enum name is already in use in the program.
+    /// This method will halt if the enum name is a reserved opcode or keyword.
+    /// This method will halt if any structs or enums in the enum's variants are not already defined.
+    #[inline]
+    fn add_enum(&mut self, enum_: EnumType<N>) -> Result<()> {
+        // Retrieve the enum name.
+        let enum_name = *enum_.name();
+
+        // Ensure the program has not exceeded the maximum number of enums.
+        ensure!(self.enums.len() < N::MAX_STRUCTS, "Program exceeds the maximum number of enums.");
+
+        // Ensure the enum name is new.
+        ensure!(self.is_unique_name(&enum_name), "'{enum_name}' is already in use.");
+        // Ensure the enum name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&enum_name.to_string()), "'{enum_name}' is a reserved opcode.");
+        // Ensure the enum name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&enum_name), "'{enum_name}' is a reserved keyword.");
+
+        // Ensure the enum contains variants.
+        ensure!(!enum_.variants().is_empty(), "Enum '{enum_name}' is missing variants.");
+
+        // Ensure all enum variants are well-formed.
+        // Note: This design ensures cyclic references are not possible.
+        for (identifier, plaintext_type) in enum_.variants() {
+            // Ensure the variant name is not a reserved keyword.
+            ensure!(!Self::is_reserved_keyword(identifier), "'{identifier}' is a reserved keyword.");
+            // Ensure the payload type is already defined in the program.
+            let base_type = match plaintext_type {
+                PlaintextType::Array(array_type) => array_type.base_element_type(),
+                PlaintextType::Vector(vector_type) => vector_type.base_element_type(),
+                plaintext_type => plaintext_type,
+            };
+            match base_type {
+                PlaintextType::Struct(struct_name) if !self.structs.contains_key(struct_name) => {
+                    bail!("'{struct_name}' in enum '{enum_name}' is not defined.")
+                }
+                PlaintextType::Enum(payload_name) if !self.enums.contains_key(payload_name) => {
+                    bail!("'{payload_name}' in enum '{enum_name}' is not defined.")
+                }
+                _ => continue,
+            }
+        }
+
+        // Add the enum name to the identifiers.
+        if self.identifiers.insert(enum_name, ProgramDefinition::Enum).is_some() {
+            bail!("'{enum_name}' already exists in the program.")
+        }
+        // Add the enum to the program.
+        if self.enums.insert(enum_name, enum_).is_some() {
+            bail!("'{enum_name}' already exists in the program.")
+        }
+        Ok(())
+    }
+
     /// Adds a new record to the program.
     ///
     /// # Errors
@@ -418,6 +572,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// This method will halt if the record name is already in use in the program.
     /// This method will halt if the record name is a reserved opcode or keyword.
     /// This method will halt if any records in the record's members are not already defined.
+    /// This method will halt if any of the record's members is an enum.
     #[inline]
     fn add_record(&mut self, record: RecordType<N>) -> Result<()> {
         // Retrieve the record name.
@@ -446,14 +601,28 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                     }
                 }
-                PlaintextType::Array(array_type) => {
-                    if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
-                        // Ensure the member struct name exists in the program.
-                        if !self.structs.contains_key(struct_name) {
-                            bail!("'{struct_name}' in array '{array_type}' is not defined.")
-                        }
+                PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                    // Ensure the member struct name exists in the program.
+                    PlaintextType::Struct(struct_name) if !self.structs.contains_key(struct_name) => {
+                        bail!("'{struct_name}' in array '{array_type}' is not defined.")
+                    }
+                    // Ensure the array does not contain enums.
+                    PlaintextType::Enum(..) => {
+                        bail!("Array '{array_type}' in record '{record_name}' cannot contain an enum.")
                     }
+                    _ => continue,
+                },
+                // Note: The circuit of a function must not depend on the variant of an enum.
+                PlaintextType::Enum(identifier) => {
+                    bail!("Enum '{identifier}' in record '{record_name}' is not supported.")
                 }
+                PlaintextType::Vector(vector_type) => match vector_type.base_element_type() {
+                    // Ensure the member struct name exists in the program.
+                    PlaintextType::Struct(struct_name) if !self.structs.contains_key(struct_name) => {
+                        bail!("'{struct_name}' in vector '{vector_type}' is not defined.")
+                    }
+                    _ => continue,
+                },
             }
         }
 
@@ -561,6 +730,74 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds a new interface to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the interface was previously added.
+    /// This method will halt if the interface name is already in use in the program.
+    /// This method will halt if the interface name is a reserved opcode or keyword.
+    /// This method will halt if any structs in the function signatures are not already defined.
+    /// This method will halt if any of the function signatures contains an enum.
+    #[inline]
+    fn add_interface(&mut self, interface: Interface<N>) -> Result<()> {
+        // Retrieve the interface name.
+        let interface_name = *interface.name();
+
+        // Ensure the program has not exceeded the maximum number of interfaces.
+        ensure!(self.interfaces.len() < N::MAX_FUNCTIONS, "Program exceeds the maximum number of interfaces.");
+
+        // Ensure the interface name is new.
+        ensure!(self.is_unique_name(&interface_name), "'{interface_name}' is already in use.");
+        // Ensure the interface name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&interface_name.to_string()), "'{interface_name}' is a reserved opcode.");
+        // Ensure the interface name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&interface_name), "'{interface_name}' is a reserved keyword.");
+
+        // Ensure all function signatures are well-formed.
+        for signature in interface.functions().values() {
+            // Retrieve the function name.
+            let function_name = signature.name();
+            // Ensure the function name is not a reserved opcode.
+            ensure!(!Self::is_reserved_opcode(&function_name.to_string()), "'{function_name}' is a reserved opcode.");
+            // Ensure the function name is not a reserved keyword.
+            ensure!(!Self::is_reserved_keyword(function_name), "'{function_name}' is a reserved keyword.");
+            // Ensure the input and output types are already defined in the program.
+            for value_type in signature.inputs().iter().chain(signature.outputs()) {
+                let plaintext_type = match value_type {
+                    ValueType::Constant(plaintext_type)
+                    | ValueType::Public(plaintext_type)
+                    | ValueType::Private(plaintext_type) => plaintext_type,
+                    _ => bail!("Function '{function_name}' in interface '{interface_name}' must use plaintext types."),
+                };
+                let base_type = match plaintext_type {
+                    PlaintextType::Array(array_type) => array_type.base_element_type(),
+                    PlaintextType::Vector(vector_type) => vector_type.base_element_type(),
+                    plaintext_type => plaintext_type,
+                };
+                match base_type {
+                    PlaintextType::Struct(struct_name) if !self.structs.contains_key(struct_name) => {
+                        bail!("'{struct_name}' in interface '{interface_name}' is not defined.")
+                    }
+                    // Note: The circuit of a function must not depend on the variant of an enum.
+                    PlaintextType::Enum(enum_name) => {
+                        bail!("Enum '{enum_name}' in interface '{interface_name}' is not supported.")
+                    }
+                    _ => continue,
+                }
+            }
+        }
+
+        // Add the interface name to the identifiers.
+        if self.identifiers.insert(interface_name, ProgramDefinition::Interface).is_some() {
+            bail!("'{interface_name}' already exists in the program.")
+        }
+        // Add the interface to the program.
+        if self.interfaces.insert(interface_name, interface).is_some() {
+            bail!("'{interface_name}' already exists in the program.")
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -636,8 +873,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "union",
         "trait",
         "impl",
+        "interface",
         "type",
         "future",
+        "vector",
     ];
 
     /// Returns `true` if the given name does not already exist in the program.
@@ -719,6 +958,87 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_enum() -> Result<()> {
+        // Create a new enum.
+        let enum_ = EnumType::<CurrentNetwork>::from_str(
+            r"
+enum shape:
+    circle as u64;
+    square as dimensions;",
+        )?;
+        // Create the struct of the payload.
+        let struct_ = "struct dimensions: width as u64; height as u64;";
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {struct_} {enum_}"))?;
+        // Ensure the enum was added.
+        assert!(program.contains_enum(&Identifier::from_str("shape")?));
+        // Ensure the retrieved enum matches.
+        assert_eq!(&enum_, program.get_enum(&Identifier::from_str("shape")?)?);
+
+        // Ensure the payload type must be defined before the enum.
+        assert!(Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {enum_}")).is_err());
+        // Ensure an enum cannot be a struct member.
+        let program = format!("program unknown.aleo; {struct_} {enum_} struct foo: first as shape.enum;");
+        assert!(Program::<CurrentNetwork>::from_str(&program).is_err());
+        // Ensure an enum cannot be a record entry, including as the element of an array.
+        let record = "record foo: owner as address.private; first as [shape.enum; 2u32].public;";
+        let program = format!("program unknown.aleo; {struct_} {enum_} {record}");
+        assert!(Program::<CurrentNetwork>::from_str(&program).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_interface() -> Result<()> {
+        // Create a new interface.
+        let interface = Interface::<CurrentNetwork>::from_str(
+            r"
+interface token:
+    function transfer:
+        input as address.public;
+        input as u64.public;
+        output as u64.public;",
+        )?;
+        // Create a function that implements the interface.
+        let function = r"
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    add r1 1u64 into r2;
+    output r2 as u64.public;";
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {interface} {function}"))?;
+        // Ensure the interface was added.
+        assert!(program.contains_interface(&Identifier::from_str("token")?));
+        // Ensure the retrieved interface matches.
+        assert_eq!(&interface, program.get_interface(&Identifier::from_str("token")?)?);
+        // Ensure the program implements the interface.
+        assert!(program.implements_interface(&interface));
+
+        // Ensure a program without the function does not implement the interface.
+        let program = Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {interface}"))?;
+        assert!(!program.implements_interface(&interface));
+        // Ensure a program with a call in the function does not implement the interface.
+        let closure = "closure increment: input r0 as u64; add r0 1u64 into r1; output r1 as u64;";
+        let function = r"
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call increment r1 into r2;
+    output r2 as u64.public;";
+        let program =
+            Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {interface} {closure} {function}"))?;
+        assert!(!program.implements_interface(&interface));
+        // Ensure the structs in an interface must be defined before the interface.
+        let interface = "interface points: function get: output as point.public;";
+        assert!(Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {interface}")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
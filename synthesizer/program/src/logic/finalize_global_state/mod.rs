@@ -20,6 +20,10 @@ pub struct FinalizeGlobalState {
     block_round: u64,
     /// The block height.
     block_height: u32,
+    /// The block timestamp.
+    block_timestamp: i64,
+    /// The previous block hash, as the little-endian bytes of its field element.
+    previous_block_hash: [u8; 32],
     /// The block-specific random seed.
     random_seed: [u8; 32],
 }
@@ -31,6 +35,7 @@ impl FinalizeGlobalState {
         // Initialize the parameters.
         let block_round = 0;
         let block_height = 0;
+        let block_timestamp = 0;
         let block_cumulative_weight = 0;
         let block_cumulative_proof_target = 0;
         let previous_block_hash = N::BlockHash::default();
@@ -38,6 +43,7 @@ impl FinalizeGlobalState {
         Self::new::<N>(
             block_round,
             block_height,
+            block_timestamp,
             block_cumulative_weight,
             block_cumulative_proof_target,
             previous_block_hash,
@@ -49,6 +55,7 @@ impl FinalizeGlobalState {
     pub fn new<N: Network>(
         block_round: u64,
         block_height: u32,
+        block_timestamp: i64,
         block_cumulative_weight: u128,
         block_cumulative_proof_target: u128,
         previous_block_hash: N::BlockHash,
@@ -71,13 +78,28 @@ impl FinalizeGlobalState {
         let mut random_seed = [0u8; 32];
         random_seed.copy_from_slice(&seed[..32]);
 
-        Ok(Self { block_round, block_height, random_seed })
+        // Retrieve the previous block hash as bytes.
+        let hash = (*previous_block_hash).to_bytes_le()?;
+        // Ensure the previous block hash is 32-bytes.
+        ensure!(hash.len() == 32, "Invalid previous block hash length for finalize global state.");
+
+        // Convert the previous block hash into a 32-byte array.
+        let mut previous_block_hash = [0u8; 32];
+        previous_block_hash.copy_from_slice(&hash[..32]);
+
+        Ok(Self { block_round, block_height, block_timestamp, previous_block_hash, random_seed })
     }
 
     /// Initializes a new global state.
     #[inline]
-    pub const fn from(block_round: u64, block_height: u32, random_seed: [u8; 32]) -> Self {
-        Self { block_round, block_height, random_seed }
+    pub const fn from(
+        block_round: u64,
+        block_height: u32,
+        block_timestamp: i64,
+        previous_block_hash: [u8; 32],
+        random_seed: [u8; 32],
+    ) -> Self {
+        Self { block_round, block_height, block_timestamp, previous_block_hash, random_seed }
     }
 
     /// Returns the block round.
@@ -92,6 +114,18 @@ impl FinalizeGlobalState {
         self.block_height
     }
 
+    /// Returns the block timestamp.
+    #[inline]
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+
+    /// Returns the previous block hash, as the little-endian bytes of its field element.
+    #[inline]
+    pub const fn previous_block_hash(&self) -> &[u8; 32] {
+        &self.previous_block_hash
+    }
+
     /// Returns the random seed.
     #[inline]
     pub const fn random_seed(&self) -> &[u8; 32] {
@@ -201,6 +201,18 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Computes whether the enum in `first` is of the given variant as a boolean, storing the outcome in `destination`.
+    IsVariant(IsVariant<N>),
+    /// Appends `second` to the vector in `first`, storing the resulting vector in `destination`.
+    VectorPush(VectorPush<N>),
+    /// Computes the length of the vector in `first` as a `u32`, storing the outcome in `destination`.
+    VectorLen(VectorLen<N>),
+    /// Retrieves the element of the vector in `first` at the index in `second`, storing the outcome in `destination`.
+    VectorGet(VectorGet<N>),
+    /// Sets the element at index `second` of the vector in `first` to `third`, storing the vector in `destination`.
+    VectorSet(VectorSet<N>),
+    /// Calls a function of the program at the address in the first operand, through an interface.
+    CallDynamic(CallDynamic<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -300,6 +312,13 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended, to preserve the opcode indices of existing programs.
+            IsVariant,
+            VectorPush,
+            VectorLen,
+            VectorGet,
+            VectorSet,
+            CallDynamic,
         }}
     };
     // A variant **without** curly braces:
@@ -379,6 +398,12 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns `true` if the instruction is a static or dynamic call.
+    #[inline]
+    fn is_call(&self) -> bool {
+        matches!(self, Self::Call(..) | Self::CallDynamic(..))
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     #[inline]
     fn is_reserved_opcode(name: &str) -> bool {
@@ -469,7 +494,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            74,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
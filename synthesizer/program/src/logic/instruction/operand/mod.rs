@@ -43,6 +43,15 @@ pub enum Operand<N: Network> {
     /// The operand is the network ID.
     /// Note: This variant is only accessible in the `finalize` scope.
     NetworkID,
+    /// The operand is the block timestamp.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockTimestamp,
+    /// The operand is the previous block hash.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    PreviousBlockHash,
+    /// The operand is the block round.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockRound,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, LiteralType, PlaintextType, Register, RegisterType},
+};
+
+/// Calls a function of the program at the address in the first operand, through a function signature
+/// of an interface declared in this program, on the remaining operands.
+/// i.e. `call.dynamic token::transfer r0 r1 r2 into r3;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallDynamic<N: Network> {
+    /// The name of the interface.
+    interface: Identifier<N>,
+    /// The name of the function in the interface.
+    function: Identifier<N>,
+    /// The operands, starting with the address of the callee program.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::CallDynamic
+    }
+
+    /// Returns the name of the interface.
+    #[inline]
+    pub const fn interface(&self) -> &Identifier<N> {
+        &self.interface
+    }
+
+    /// Returns the name of the function in the interface.
+    #[inline]
+    pub const fn function(&self) -> &Identifier<N> {
+        &self.function
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the operand for the address of the callee program.
+    #[inline]
+    pub fn program_address(&self) -> &Operand<N> {
+        &self.operands[0]
+    }
+
+    /// Returns the operands for the inputs of the callee function.
+    #[inline]
+    pub fn arguments(&self) -> &[Operand<N>] {
+        &self.operands[1..]
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'call.dynamic' directly. Use 'call' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'call.dynamic' directly. Use 'call' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'call.dynamic'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Retrieve the function signature from the interface.
+        let signature = stack.program().get_interface(&self.interface)?.get_function(&self.function)?;
+
+        // Ensure the number of operands matches the number of input statements, and the program address.
+        if signature.inputs().len() + 1 != self.operands.len() {
+            bail!("Expected {} inputs, found {}", signature.inputs().len() + 1, self.operands.len())
+        }
+        // Ensure the number of inputs matches the number of input statements, and the program address.
+        if signature.inputs().len() + 1 != input_types.len() {
+            bail!("Expected {} input types, found {}", signature.inputs().len() + 1, input_types.len())
+        }
+        // Ensure the number of destinations matches the number of output statements.
+        if signature.outputs().len() != self.destinations.len() {
+            bail!("Expected {} outputs, found {}", signature.outputs().len(), self.destinations.len())
+        }
+
+        // Ensure the first input type is an address.
+        ensure!(
+            input_types[0] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            "Expected the program address of '{}' to be an address, found '{}'",
+            Self::opcode(),
+            input_types[0]
+        );
+        // Ensure the remaining input types match the function signature.
+        for (input_type, value_type) in input_types[1..].iter().zip(signature.inputs()) {
+            ensure!(
+                *input_type == RegisterType::from(value_type.clone()),
+                "Expected an input of type '{value_type}' for '{}::{}', found '{input_type}'",
+                self.interface,
+                self.function
+            );
+        }
+
+        // Return the output register types.
+        Ok(signature.outputs().iter().cloned().map(RegisterType::from).collect())
+    }
+}
+
+impl<N: Network> Parser for CallDynamic<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the name of the interface from the string.
+        let (string, interface) = Identifier::parse(string)?;
+        // Parse the "::" from the string.
+        let (string, _) = tag("::")(string)?;
+        // Parse the name of the function from the string.
+        let (string, function) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many1(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_OPERANDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'call.dynamic' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the destinations from the string.
+                let (string, destinations) =
+                    map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                        // Ensure the number of destinations is within the bounds.
+                        match destinations.len() <= N::MAX_OPERANDS {
+                            true => Ok(destinations),
+                            false => Err(error("Failed to parse 'call.dynamic' opcode: too many destinations")),
+                        }
+                    })(string)?;
+                // Return the string and the destinations.
+                (string, destinations)
+            }
+        };
+
+        Ok((string, Self { interface, function, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for CallDynamic<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallDynamic<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallDynamic<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}::{}", Self::opcode(), self.interface, self.function)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CallDynamic<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the name of the interface.
+        let interface = Identifier::read_le(&mut reader)?;
+        // Read the name of the function.
+        let function = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands == 0 || num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be nonzero and <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the destinations.
+        let mut destinations = Vec::with_capacity(num_destinations);
+        // Read the destination registers.
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Ok(Self { interface, function, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for CallDynamic<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be nonzero and <= {}", N::MAX_OPERANDS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the name of the interface.
+        self.interface.write_le(&mut writer)?;
+        // Write the name of the function.
+        self.function.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination register.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const TEST_CASES: &[&str] = &[
+        "call.dynamic token::balance r0",
+        "call.dynamic token::balance r0 into r1",
+        "call.dynamic token::transfer r0 r1 r2",
+        "call.dynamic token::transfer r0 r1 r2 into r3",
+        "call.dynamic token::transfer r0 r1.owner 1u64 into r3 r4",
+        "call.dynamic token::transfer aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw r0 into r1",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, call) =
+            CallDynamic::<CurrentNetwork>::parse("call.dynamic token::transfer r0 r1 r2 into r3 r4").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(call.interface(), &Identifier::from_str("token").unwrap());
+        assert_eq!(call.function(), &Identifier::from_str("transfer").unwrap());
+        assert_eq!(call.program_address(), &Operand::Register(Register::Locator(0)));
+        assert_eq!(call.arguments(), &[Operand::Register(Register::Locator(1)), Operand::Register(Register::Locator(2))]);
+        assert_eq!(call.destinations(), vec![Register::Locator(3), Register::Locator(4)]);
+
+        // Ensure the program address is required.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic token::transfer").is_err());
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic token::transfer into r0").is_err());
+        // Ensure the function must be referenced through an interface.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic transfer r0").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(CallDynamic::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = CallDynamic::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CallDynamic::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
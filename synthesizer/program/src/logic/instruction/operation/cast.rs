@@ -44,8 +44,9 @@ use console::{
         RegisterType,
         Value,
         ValueType,
+        VectorType,
     },
-    types::Field,
+    types::{Field, U32},
 };
 
 use indexmap::IndexMap;
@@ -58,6 +59,8 @@ pub enum CastType<N: Network> {
     Plaintext(PlaintextType<N>),
     Record(Identifier<N>),
     ExternalRecord(Locator<N>),
+    /// An enum variant, as the enum name and the variant name.
+    EnumVariant(Identifier<N>, Identifier<N>),
 }
 
 impl<N: Network> Parser for CastType<N> {
@@ -68,6 +71,9 @@ impl<N: Network> Parser for CastType<N> {
             map(tag("group.y"), |_| Self::GroupYCoordinate),
             map(pair(Locator::parse, tag(".record")), |(locator, _)| Self::ExternalRecord(locator)),
             map(pair(Identifier::parse, tag(".record")), |(identifier, _)| Self::Record(identifier)),
+            map(pair(terminated(Identifier::parse, tag("::")), Identifier::parse), |(enum_name, variant)| {
+                Self::EnumVariant(enum_name, variant)
+            }),
             map(PlaintextType::parse, Self::Plaintext),
         ))(string)
     }
@@ -81,6 +87,7 @@ impl<N: Network> Display for CastType<N> {
             Self::Plaintext(plaintext_type) => write!(f, "{}", plaintext_type),
             Self::Record(identifier) => write!(f, "{}.record", identifier),
             Self::ExternalRecord(locator) => write!(f, "{}.record", locator),
+            Self::EnumVariant(enum_name, variant) => write!(f, "{enum_name}::{variant}"),
         }
     }
 }
@@ -127,6 +134,11 @@ impl<N: Network> ToBytes for CastType<N> {
                 4u8.write_le(&mut writer)?;
                 locator.write_le(&mut writer)
             }
+            CastType::EnumVariant(enum_name, variant) => {
+                5u8.write_le(&mut writer)?;
+                enum_name.write_le(&mut writer)?;
+                variant.write_le(&mut writer)
+            }
         }
     }
 }
@@ -141,7 +153,8 @@ impl<N: Network> FromBytes for CastType<N> {
             2 => Ok(Self::Plaintext(PlaintextType::read_le(&mut reader)?)),
             3 => Ok(Self::Record(Identifier::read_le(&mut reader)?)),
             4 => Ok(Self::ExternalRecord(Locator::read_le(&mut reader)?)),
-            5.. => Err(error(format!("Failed to deserialize cast type variant {variant}"))),
+            5 => Ok(Self::EnumVariant(Identifier::read_le(&mut reader)?, Identifier::read_le(&mut reader)?)),
+            6.. => Err(error(format!("Failed to deserialize cast type variant {variant}"))),
         }
     }
 }
@@ -252,6 +265,15 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                self.cast_to_vector(stack, registers, vector_type, inputs)
+            }
+            CastType::Plaintext(PlaintextType::Enum(enum_name)) => {
+                bail!("Casting to the enum '{enum_name}' requires a variant, i.e. '{enum_name}::<variant>'")
+            }
+            CastType::EnumVariant(enum_name, variant) => {
+                self.cast_to_enum(stack, registers, enum_name, variant, inputs)
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -492,6 +514,79 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 // Store the array.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                // Ensure the operands length is at least the minimum.
+                if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to a vector requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
+                }
+                // Ensure the number of elements does not exceed the maximum length.
+                if inputs.len() > **vector_type.max_length() as usize {
+                    bail!("Casting to vector '{vector_type}' cannot exceed {} elements", vector_type.max_length())
+                }
+
+                // Initialize the elements.
+                let mut elements = Vec::with_capacity(**vector_type.max_length() as usize);
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the plaintext matches the element type.
+                            stack.matches_plaintext(&plaintext.eject_value(), vector_type.element_type())?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into a vector element is illegal"),
+                        // Ensure the element is not a future.
+                        circuit::Value::Future(..) => bail!("Casting a future into a vector element is illegal"),
+                    };
+                    // Store the element.
+                    elements.push(plaintext);
+                }
+                // Pad the elements to the maximum length with zeros.
+                let length = circuit::U32::constant(U32::new(u32::try_from(elements.len())?));
+                if let Some(last) = elements.last() {
+                    let zero = circuit::Plaintext::constant(last.eject_value().to_zero()?);
+                    elements.resize(**vector_type.max_length() as usize, zero);
+                }
+
+                // Construct the vector.
+                let vector = circuit::Plaintext::Vector(elements, length, Default::default());
+                // Store the vector.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(vector))
+            }
+            CastType::Plaintext(PlaintextType::Enum(enum_name)) => {
+                bail!("Casting to the enum '{enum_name}' requires a variant, i.e. '{enum_name}::<variant>'")
+            }
+            CastType::EnumVariant(enum_name, variant) => {
+                ensure!(inputs.len() == 1, "Casting to an enum variant requires exactly 1 operand");
+
+                // Retrieve the payload type of the variant.
+                let payload_type = Self::payload_type(stack, enum_name, variant)?;
+                // Retrieve the payload.
+                let payload = match &inputs[0] {
+                    circuit::Value::Plaintext(plaintext) => {
+                        // Ensure the payload matches the payload type.
+                        stack.matches_plaintext(&plaintext.eject_value(), payload_type)?;
+                        // Output the payload.
+                        plaintext.clone()
+                    }
+                    // Ensure the payload is not a record.
+                    circuit::Value::Record(..) => bail!("Casting a record into an enum variant is illegal"),
+                    // Ensure the payload is not a future.
+                    circuit::Value::Future(..) => bail!("Casting a future into an enum variant is illegal"),
+                };
+
+                // Construct the enum.
+                // Note: The variant is a constant, so the circuit does not depend on the variant of the enum.
+                let enum_ = circuit::Plaintext::Enum(
+                    circuit::Identifier::constant(*variant),
+                    Box::new(payload),
+                    Default::default(),
+                );
+                // Store the enum.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(enum_))
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -634,6 +729,15 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                self.cast_to_vector(stack, registers, vector_type, inputs)
+            }
+            CastType::Plaintext(PlaintextType::Enum(enum_name)) => {
+                bail!("Casting to the enum '{enum_name}' requires a variant, i.e. '{enum_name}::<variant>'")
+            }
+            CastType::EnumVariant(enum_name, variant) => {
+                self.cast_to_enum(stack, registers, enum_name, variant, inputs)
+            }
             CastType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -775,6 +879,39 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                // Ensure the input types length is at least the minimum.
+                if input_types.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to a vector requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
+                }
+                // Ensure the number of elements does not exceed the maximum length.
+                if input_types.len() > **vector_type.max_length() as usize {
+                    bail!("Casting to vector '{vector_type}' cannot exceed {} elements", vector_type.max_length())
+                }
+
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    ensure!(
+                        input_type == &RegisterType::Plaintext(vector_type.element_type().clone()),
+                        "Vector element type mismatch: expected '{}', found '{input_type}'",
+                        vector_type.element_type()
+                    )
+                }
+            }
+            CastType::Plaintext(PlaintextType::Enum(enum_name)) => {
+                bail!("Casting to the enum '{enum_name}' requires a variant, i.e. '{enum_name}::<variant>'")
+            }
+            CastType::EnumVariant(enum_name, variant) => {
+                ensure!(input_types.len() == 1, "Casting to an enum variant requires exactly 1 operand");
+                // Retrieve the payload type of the variant.
+                let payload_type = Self::payload_type(stack, enum_name, variant)?;
+                // Ensure the input type matches the payload type.
+                ensure!(
+                    input_types[0] == RegisterType::Plaintext(payload_type.clone()),
+                    "Enum '{enum_name}' variant '{variant}' type mismatch: expected '{payload_type}', found '{}'",
+                    input_types[0]
+                );
+            }
             CastType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(record_name)?;
@@ -844,6 +981,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
             CastType::Record(identifier) => RegisterType::Record(*identifier),
             CastType::ExternalRecord(locator) => RegisterType::ExternalRecord(*locator),
+            CastType::EnumVariant(enum_name, _) => RegisterType::Plaintext(PlaintextType::Enum(*enum_name)),
         }])
     }
 }
@@ -949,6 +1087,102 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         // Store the array.
         registers.store(stack, &self.destination, Value::Plaintext(array))
     }
+
+    /// A helper method to handle casting to a vector.
+    /// Note: The vector is padded to its maximum length with zeros.
+    fn cast_to_vector(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStore<N>,
+        vector_type: &VectorType<N>,
+        inputs: Vec<Value<N>>,
+    ) -> Result<()> {
+        // Ensure that there is at least one operand.
+        if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+            bail!("Casting to a vector requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
+        }
+        // Ensure the number of operands does not exceed the maximum length.
+        if inputs.len() > **vector_type.max_length() as usize {
+            bail!("Casting to vector '{vector_type}' cannot exceed {} elements", vector_type.max_length())
+        }
+
+        // Initialize the elements.
+        let mut elements = Vec::with_capacity(**vector_type.max_length() as usize);
+        for element in inputs.iter() {
+            // Retrieve the plaintext value from the element.
+            let plaintext = match element {
+                Value::Plaintext(plaintext) => {
+                    // Ensure the plaintext matches the element type.
+                    stack.matches_plaintext(plaintext, vector_type.element_type())?;
+                    // Output the plaintext.
+                    plaintext.clone()
+                }
+                // Ensure the element is not a record.
+                Value::Record(..) => bail!("Casting a record into a vector element is illegal"),
+                // Ensure the element is not a future.
+                Value::Future(..) => bail!("Casting a future into a vector element is illegal"),
+            };
+            // Store the element.
+            elements.push(plaintext);
+        }
+        // Pad the elements to the maximum length with zeros.
+        let length = U32::new(u32::try_from(elements.len())?);
+        if let Some(last) = elements.last() {
+            let zero = last.to_zero()?;
+            elements.resize(**vector_type.max_length() as usize, zero);
+        }
+
+        // Construct the vector.
+        let vector = Plaintext::Vector(elements, length, Default::default());
+        // Store the vector.
+        registers.store(stack, &self.destination, Value::Plaintext(vector))
+    }
+
+    /// A helper method to handle casting to an enum variant.
+    fn cast_to_enum(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStore<N>,
+        enum_name: &Identifier<N>,
+        variant: &Identifier<N>,
+        inputs: Vec<Value<N>>,
+    ) -> Result<()> {
+        // Ensure that there is exactly one operand.
+        ensure!(inputs.len() == 1, "Casting to an enum variant requires exactly 1 operand");
+
+        // Retrieve the payload type of the variant.
+        let payload_type = Self::payload_type(stack, enum_name, variant)?;
+        // Retrieve the payload.
+        let payload = match &inputs[0] {
+            Value::Plaintext(plaintext) => {
+                // Ensure the payload matches the payload type.
+                stack.matches_plaintext(plaintext, payload_type)?;
+                // Output the payload.
+                plaintext.clone()
+            }
+            // Ensure the payload is not a record.
+            Value::Record(..) => bail!("Casting a record into an enum variant is illegal"),
+            // Ensure the payload is not a future.
+            Value::Future(..) => bail!("Casting a future into an enum variant is illegal"),
+        };
+
+        // Construct the enum.
+        let enum_ = Plaintext::Enum(*variant, Box::new(payload), Default::default());
+        // Store the enum.
+        registers.store(stack, &self.destination, Value::Plaintext(enum_))
+    }
+
+    /// Returns the payload type of the given variant of the given enum.
+    fn payload_type<'a>(
+        stack: &'a impl StackProgram<N>,
+        enum_name: &Identifier<N>,
+        variant: &Identifier<N>,
+    ) -> Result<&'a PlaintextType<N>> {
+        // Retrieve the enum and ensure it is defined in the program.
+        let enum_ = stack.program().get_enum(enum_name)?;
+        // Retrieve the payload type of the variant.
+        enum_.variants().get(variant).ok_or_else(|| anyhow!("'{variant}' is not a variant of enum '{enum_name}'"))
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
@@ -987,9 +1221,13 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
         let max_operands = match cast_type {
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Enum(_))
+            | CastType::EnumVariant(..) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         match !operands.is_empty() && (operands.len() <= max_operands) {
@@ -1035,9 +1273,13 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
         let max_operands = match self.cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Enum(_))
+            | CastType::EnumVariant(..) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
@@ -1080,9 +1322,13 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
         let max_operands = match cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Enum(_))
+            | CastType::EnumVariant(..) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if num_operands.is_zero() || num_operands > max_operands {
@@ -1101,9 +1347,13 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
         let max_operands = match self.cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Enum(_))
+            | CastType::EnumVariant(..) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
@@ -1155,6 +1405,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_enum_variant() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 into r1 as shape::circle").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(
+            cast.cast_type,
+            CastType::EnumVariant(Identifier::from_str("shape").unwrap(), Identifier::from_str("circle").unwrap()),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0 into r1 as shape::circle");
+        assert_eq!(cast, Cast::<CurrentNetwork>::from_bytes_le(&cast.to_bytes_le().unwrap()).unwrap());
+
+        // Ensure an enum variant has exactly one payload.
+        assert!(Cast::<CurrentNetwork>::parse("cast r0 r1 into r2 as shape::circle").is_err());
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -111,6 +111,8 @@ fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) ->
             | PlaintextType::Literal(LiteralType::String)
             | PlaintextType::Struct(..)
             | PlaintextType::Array(..)
+            | PlaintextType::Enum(..)
+            | PlaintextType::Vector(..)
     )
 }
 
@@ -254,12 +256,16 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Enum(..)) => bail!("Cannot hash into an enum"),
+            (_, PlaintextType::Vector(..)) => bail!("Cannot hash into a vector"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Enum(..) => bail!("Cannot hash into an enum"),
+            PlaintextType::Vector(..) => bail!("Cannot hash into a vector"),
         };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
@@ -325,12 +331,16 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Enum(..)) => bail!("Cannot hash into an enum"),
+            (_, PlaintextType::Vector(..)) => bail!("Cannot hash into a vector"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Enum(..) => bail!("Cannot hash into an enum"),
+            PlaintextType::Vector(..) => bail!("Cannot hash into a vector"),
         };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether the enum in `first` is of the given variant as a boolean, storing the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IsVariant<N: Network> {
+    /// The operand.
+    operand: Operand<N>,
+    /// The enum name.
+    enum_name: Identifier<N>,
+    /// The variant name.
+    variant: Identifier<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> IsVariant<N> {
+    /// Initializes a new `is.variant` instruction.
+    #[inline]
+    pub fn new(
+        operand: Operand<N>,
+        enum_name: Identifier<N>,
+        variant: Identifier<N>,
+        destination: Register<N>,
+    ) -> Self {
+        Self { operand, enum_name, variant, destination }
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Is("is.variant")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        std::slice::from_ref(&self.operand)
+    }
+
+    /// Returns the enum name.
+    #[inline]
+    pub const fn enum_name(&self) -> &Identifier<N> {
+        &self.enum_name
+    }
+
+    /// Returns the variant name.
+    #[inline]
+    pub const fn variant(&self) -> &Identifier<N> {
+        &self.variant
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> IsVariant<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Retrieve the input.
+        let output = match registers.load(stack, &self.operand)? {
+            Value::Plaintext(Plaintext::Enum(variant, ..)) => Literal::Boolean(Boolean::new(variant == self.variant)),
+            input => bail!("Instruction '{}' expects an enum, found '{input}'", Self::opcode()),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{Eject, Inject};
+
+        // Retrieve the input.
+        // Note: The variant of an enum is a constant in the circuit, so the output is a constant.
+        let output = match registers.load_circuit(stack, &self.operand)? {
+            circuit::Value::Plaintext(circuit::Plaintext::Enum(variant, ..)) => {
+                circuit::Boolean::constant(variant.eject_value() == self.variant)
+            }
+            input => bail!("Instruction '{}' expects an enum, found '{}'", Self::opcode(), input.eject_value()),
+        };
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(
+            circuit::Literal::Boolean(output),
+            Default::default(),
+        ));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the operand is of the enum type.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Enum(self.enum_name)) {
+            bail!(
+                "Instruction '{}' expects an input of type '{}.enum'. Found input of type '{}'",
+                Self::opcode(),
+                self.enum_name,
+                input_types[0]
+            )
+        }
+        // Ensure the variant is defined in the enum.
+        let enum_ = stack.program().get_enum(&self.enum_name)?;
+        if !enum_.variants().contains_key(&self.variant) {
+            bail!("Variant '{}' is not defined in enum '{}'", self.variant, self.enum_name)
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for IsVariant<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the enum name from the string.
+        let (string, enum_name) = Identifier::parse(string)?;
+        // Parse the "::" from the string.
+        let (string, _) = tag("::")(string)?;
+        // Parse the variant name from the string.
+        let (string, variant) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operand, enum_name, variant, destination }))
+    }
+}
+
+impl<N: Network> FromStr for IsVariant<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for IsVariant<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for IsVariant<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {} {}::{} into {}", Self::opcode(), self.operand, self.enum_name, self.variant, self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for IsVariant<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the enum name.
+        let enum_name = Identifier::read_le(&mut reader)?;
+        // Read the variant name.
+        let variant = Identifier::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operand, enum_name, variant, destination })
+    }
+}
+
+impl<N: Network> ToBytes for IsVariant<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the operand.
+        self.operand.write_le(&mut writer)?;
+        // Write the enum name.
+        self.enum_name.write_le(&mut writer)?;
+        // Write the variant name.
+        self.variant.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = IsVariant::<CurrentNetwork>::parse("is.variant r0 shape::circle into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operand, Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(is.enum_name, Identifier::from_str("shape").unwrap(), "The enum name is incorrect");
+        assert_eq!(is.variant, Identifier::from_str("circle").unwrap(), "The variant is incorrect");
+        assert_eq!(is.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(is.to_string(), "is.variant r0 shape::circle into r1");
+
+        // Ensure the bytes round trip.
+        let bytes = is.to_bytes_le().unwrap();
+        assert_eq!(is, IsVariant::<CurrentNetwork>::from_bytes_le(&bytes).unwrap());
+    }
+}
@@ -21,6 +21,9 @@ pub use async_::*;
 mod call;
 pub use call::*;
 
+mod call_dynamic;
+pub use call_dynamic::*;
+
 mod cast;
 pub use cast::*;
 
@@ -33,6 +36,9 @@ pub use hash::*;
 mod is;
 pub use is::*;
 
+mod is_variant;
+pub use is_variant::*;
+
 mod literals;
 pub use literals::*;
 
@@ -41,6 +47,9 @@ mod macros;
 mod sign_verify;
 pub use sign_verify::*;
 
+mod vector;
+pub use vector::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -0,0 +1,498 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+    Operation,
+    TernaryOperation,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U32,
+};
+
+use indexmap::IndexMap;
+
+/// Appends `second` to the vector in `first`, storing the resulting vector in `destination`.
+pub type VectorPush<N> = VectorInstruction<N, { Variant::VectorPush as u8 }>;
+/// Computes the length of the vector in `first` as a `u32`, storing the outcome in `destination`.
+pub type VectorLen<N> = VectorInstruction<N, { Variant::VectorLen as u8 }>;
+/// Retrieves the element of the vector in `first` at the index in `second`, storing the outcome in `destination`.
+pub type VectorGet<N> = VectorInstruction<N, { Variant::VectorGet as u8 }>;
+/// Replaces the element of the vector in `first` at the index in `second` with `third`,
+/// storing the resulting vector in `destination`.
+pub type VectorSet<N> = VectorInstruction<N, { Variant::VectorSet as u8 }>;
+
+#[allow(clippy::enum_variant_names)]
+enum Variant {
+    VectorPush,
+    VectorLen,
+    VectorGet,
+    VectorSet,
+}
+
+/// Computes a vector operation on the operands, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VectorInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> VectorInstruction<N, VARIANT> {
+    /// Initializes a new `vector` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Vector("vector.push"),
+            1 => Opcode::Vector("vector.len"),
+            2 => Opcode::Vector("vector.get"),
+            3 => Opcode::Vector("vector.set"),
+            _ => panic!("Invalid 'vector' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands of the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            0 => 2,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            _ => panic!("Invalid 'vector' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is the correct length.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Invalid number of operands for '{}'",
+            Self::opcode()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> VectorInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the vector.
+        let (mut elements, length) = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Vector(elements, length, _)) => (elements, *length),
+            input => bail!("Instruction '{}' expects a vector, found '{input}'", Self::opcode()),
+        };
+        // Ensure the elements beyond the length are zero, if the instruction updates the vector.
+        if matches!(VARIANT, 0 | 3) {
+            ensure!(Plaintext::is_zero_padded(&elements, length), "Vector of length {length} has nonzero padding");
+        }
+        // Retrieve the index, if the instruction has one.
+        let index = match VARIANT {
+            2 | 3 => match registers.load_literal(stack, &self.operands[1])? {
+                Literal::U32(index) => {
+                    // Ensure the index is within the length of the vector.
+                    ensure!(*index < length, "Index '{index}' is out of bounds for a vector of length {length}");
+                    *index as usize
+                }
+                input => bail!("Instruction '{}' expects a 'u32' index, found '{input}'", Self::opcode()),
+            },
+            _ => 0,
+        };
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => {
+                // Ensure the vector is not full.
+                ensure!((length as usize) < elements.len(), "Vector of length {length} is full");
+                // Append the element.
+                elements[length as usize] = registers.load_plaintext(stack, &self.operands[1])?;
+                Plaintext::Vector(elements, U32::new(length + 1), Default::default())
+            }
+            1 => Plaintext::from(Literal::U32(U32::new(length))),
+            2 => elements[index].clone(),
+            3 => {
+                // Replace the element.
+                elements[index] = registers.load_plaintext(stack, &self.operands[2])?;
+                Plaintext::Vector(elements, U32::new(length), Default::default())
+            }
+            _ => bail!("Invalid 'vector' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the vector.
+        let (elements, length) = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Vector(elements, length, _)) => (elements, length),
+            input => bail!("Instruction '{}' expects a vector, found '{}'", Self::opcode(), input.eject_value()),
+        };
+        // Ensure the elements beyond the length are zero, if the instruction updates the vector.
+        if matches!(VARIANT, 0 | 3) {
+            A::assert(circuit::Plaintext::is_zero_padded(&elements, &length));
+        }
+        // Retrieve the index, if the instruction has one.
+        let index = match VARIANT {
+            2 | 3 => match registers.load_literal_circuit(stack, &self.operands[1])? {
+                circuit::Literal::U32(index) => {
+                    // Ensure the index is within the length of the vector.
+                    A::assert(index.is_less_than(&length));
+                    Some(index)
+                }
+                input => {
+                    bail!("Instruction '{}' expects a 'u32' index, found '{}'", Self::opcode(), input.eject_value())
+                }
+            },
+            _ => None,
+        };
+        // Initialize a helper to compare a position in the vector to the given circuit value.
+        let is_position = |value: &circuit::U32<A>, i: u32| value.is_equal(&circuit::U32::constant(U32::new(i)));
+
+        // Compute the output.
+        // Note: Every element of the vector is visited, so the circuit is sized to the maximum length.
+        let output = match (VARIANT, index) {
+            (0, _) => {
+                // Ensure the vector is not full.
+                A::assert(length.is_less_than(&circuit::U32::constant(U32::new(u32::try_from(elements.len())?))));
+                // Append the element.
+                let element = registers.load_plaintext_circuit(stack, &self.operands[1])?;
+                let elements = (0..)
+                    .zip(&elements)
+                    .map(|(i, current)| ternary_circuit::<N, A>(&is_position(&length, i), &element, current))
+                    .collect::<Result<Vec<_>>>()?;
+                circuit::Plaintext::Vector(elements, length + circuit::U32::constant(U32::new(1)), Default::default())
+            }
+            (1, _) => circuit::Plaintext::from(circuit::Literal::U32(length)),
+            (2, Some(index)) => {
+                // Select the element at the index.
+                let mut output = elements.first().cloned().ok_or_else(|| anyhow!("Vector has no elements"))?;
+                for (i, current) in (0..).zip(&elements).skip(1) {
+                    output = ternary_circuit::<N, A>(&is_position(&index, i), current, &output)?;
+                }
+                output
+            }
+            (3, Some(index)) => {
+                // Replace the element at the index.
+                let element = registers.load_plaintext_circuit(stack, &self.operands[2])?;
+                let elements = (0..)
+                    .zip(&elements)
+                    .map(|(i, current)| ternary_circuit::<N, A>(&is_position(&index, i), &element, current))
+                    .collect::<Result<Vec<_>>>()?;
+                circuit::Plaintext::Vector(elements, length, Default::default())
+            }
+            _ => bail!("Invalid 'vector' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Ensure the first operand is a vector.
+        let vector_type = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => vector_type,
+            input_type => bail!("Instruction '{}' expects a vector, found '{input_type}'", Self::opcode()),
+        };
+        let element_type = RegisterType::Plaintext(vector_type.element_type().clone());
+        let index_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32));
+
+        // Ensure the remaining operands are of the expected types.
+        let expected_types = match VARIANT {
+            0 => vec![element_type.clone()],
+            1 => vec![],
+            2 => vec![index_type],
+            3 => vec![index_type, element_type.clone()],
+            _ => bail!("Invalid 'vector' variant: {VARIANT}"),
+        };
+        for (input_type, expected_type) in input_types[1..].iter().zip_eq(expected_types.iter()) {
+            if input_type != expected_type {
+                bail!(
+                    "Instruction '{}' expects an input of type '{expected_type}', found '{input_type}'",
+                    Self::opcode()
+                )
+            }
+        }
+
+        match VARIANT {
+            0 | 3 => Ok(vec![input_types[0].clone()]),
+            1 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))]),
+            2 => Ok(vec![element_type]),
+            _ => bail!("Invalid 'vector' variant: {VARIANT}"),
+        }
+    }
+}
+
+/// Returns `first` if `condition` is `true`, otherwise returns `second`.
+fn ternary_circuit<N: Network, A: circuit::Aleo<Network = N>>(
+    condition: &circuit::Boolean<A>,
+    first: &circuit::Plaintext<A>,
+    second: &circuit::Plaintext<A>,
+) -> Result<circuit::Plaintext<A>> {
+    use circuit::Eject;
+
+    match (first, second) {
+        (circuit::Plaintext::Literal(a, _), circuit::Plaintext::Literal(b, _)) => {
+            let inputs = [circuit::Literal::Boolean(condition.clone()), a.clone(), b.clone()];
+            Ok(circuit::Plaintext::from(TernaryOperation::<N>::execute(&inputs)?))
+        }
+        (circuit::Plaintext::Struct(a, _), circuit::Plaintext::Struct(b, _)) if a.len() == b.len() => {
+            let members = a
+                .iter()
+                .zip_eq(b.iter())
+                .map(|((name_a, a), (name_b, b))| {
+                    // Ensure the member names match.
+                    ensure!(name_a.eject_value() == name_b.eject_value(), "Mismatching struct members in ternary");
+                    Ok((name_a.clone(), ternary_circuit::<N, A>(condition, a, b)?))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?;
+            Ok(circuit::Plaintext::Struct(members, Default::default()))
+        }
+        (circuit::Plaintext::Array(a, _), circuit::Plaintext::Array(b, _)) if a.len() == b.len() => {
+            let elements = a
+                .iter()
+                .zip_eq(b.iter())
+                .map(|(a, b)| ternary_circuit::<N, A>(condition, a, b))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(circuit::Plaintext::Array(elements, Default::default()))
+        }
+        _ => bail!("Mismatching vector elements '{}' and '{}' in ternary", first.eject_value(), second.eject_value()),
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for VectorInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operands from the string.
+        let (string, operands) =
+            count(terminated(Operand::parse, Sanitizer::parse_whitespaces), Self::num_operands())(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for VectorInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for VectorInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for VectorInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for VectorInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for VectorInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, vector) = VectorPush::<CurrentNetwork>::parse("vector.push r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vector.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(vector.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(vector.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(vector.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, vector) = VectorLen::<CurrentNetwork>::parse("vector.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vector.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(vector.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, vector) = VectorGet::<CurrentNetwork>::parse("vector.get r0 0u32 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vector.operands[1], Operand::from_str("0u32").unwrap(), "The index operand is incorrect");
+
+        let (string, vector) = VectorSet::<CurrentNetwork>::parse("vector.set r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vector.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(vector.to_string(), "vector.set r0 r1 r2 into r3");
+
+        // Ensure the number of operands is enforced.
+        assert!(VectorLen::<CurrentNetwork>::from_str("vector.len r0 r1 into r2").is_err());
+        assert!(VectorSet::<CurrentNetwork>::from_str("vector.set r0 r1 into r2").is_err());
+    }
+}
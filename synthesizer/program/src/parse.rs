@@ -24,11 +24,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         enum P<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
             M(Mapping<N>),
             I(StructType<N>),
+            E(EnumType<N>),
             R(RecordType<N>),
             C(ClosureCore<N, Instruction>),
             F(FunctionCore<N, Instruction, Command>),
+            T(Interface<N>),
         }
 
+        // Note: The spans of the closures and functions are relative to the start of the program.
+        let source = string;
+
         // Parse the imports from the string.
         let (string, imports) = many0(Import::parse)(string)?;
         // Parse the whitespace and comments from the string.
@@ -48,9 +53,17 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, components) = many1(alt((
             map(Mapping::parse, |mapping| P::<N, Instruction, Command>::M(mapping)),
             map(StructType::parse, |struct_| P::<N, Instruction, Command>::I(struct_)),
+            map(EnumType::parse, |enum_| P::<N, Instruction, Command>::E(enum_)),
             map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
-            map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
-            map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
+            map(Interface::parse, |interface| P::<N, Instruction, Command>::T(interface)),
+            map(Span::parser(source, ClosureCore::parse), |(mut closure, span)| {
+                closure.relocate(span.start());
+                P::<N, Instruction, Command>::C(closure)
+            }),
+            map(Span::parser(source, FunctionCore::parse), |(mut function, span)| {
+                function.relocate(span.start());
+                P::<N, Instruction, Command>::F(function)
+            }),
         )))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
@@ -70,9 +83,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                 let result = match component {
                     P::M(mapping) => program.add_mapping(mapping.clone()),
                     P::I(struct_) => program.add_struct(struct_.clone()),
+                    P::E(enum_) => program.add_enum(enum_.clone()),
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
                     P::F(function) => program.add_function(function.clone()),
+                    P::T(interface) => program.add_interface(interface.clone()),
                 };
 
                 match result {
@@ -112,7 +127,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Remaining invalid string is: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string at {}. Remaining invalid string is: \"{remainder}\"",
+                    SourcePosition::after(&string[..string.len() - remainder.len()])
+                );
                 // Return the object.
                 Ok(object)
             }
@@ -162,6 +181,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     Some(struct_) => program.push_str(&format!("{struct_}\n\n")),
                     None => return Err(fmt::Error),
                 },
+                ProgramDefinition::Enum => match self.enums.get(identifier) {
+                    Some(enum_) => program.push_str(&format!("{enum_}\n\n")),
+                    None => return Err(fmt::Error),
+                },
                 ProgramDefinition::Record => match self.records.get(identifier) {
                     Some(record) => program.push_str(&format!("{record}\n\n")),
                     None => return Err(fmt::Error),
@@ -174,6 +197,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     Some(function) => program.push_str(&format!("{function}\n\n")),
                     None => return Err(fmt::Error),
                 },
+                ProgramDefinition::Interface => match self.interfaces.get(identifier) {
+                    Some(interface) => program.push_str(&format!("{interface}\n\n")),
+                    None => return Err(fmt::Error),
+                },
             }
         }
         // Remove the last newline.
@@ -259,6 +286,53 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_spans() -> Result<()> {
+        let string = r"program spans.aleo;
+
+// A comment before the closure.
+closure twice:
+    input r0 as u8;
+    add r0 r0 into r1;
+    output r1 as u8;
+
+function compute:
+    input r0 as u8.public;
+    call twice r0 into r1;
+    async compute r1 into r2;
+    output r2 as spans.aleo/compute.future;
+
+finalize compute:
+    input r0 as u8.public;
+    add r0 1u8 into r1;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(string)?;
+
+        // Ensure the closure spans are retained.
+        let closure = program.get_closure(&Identifier::from_str("twice")?)?;
+        assert_eq!(closure.span().unwrap().start(), SourcePosition::new(4, 1));
+        assert_eq!(closure.span().unwrap().end(), SourcePosition::new(7, 21));
+        assert_eq!(closure.instruction_span(0).unwrap().start(), SourcePosition::new(6, 5));
+        assert_eq!(closure.instruction_span(0).unwrap().end(), SourcePosition::new(6, 23));
+
+        // Ensure the function and finalize spans are retained.
+        let function = program.get_function(&Identifier::from_str("compute")?)?;
+        assert_eq!(function.span().unwrap().start(), SourcePosition::new(9, 1));
+        assert_eq!(function.instruction_span(1).unwrap().start(), SourcePosition::new(12, 5));
+        let finalize = function.finalize_logic().unwrap();
+        assert_eq!(finalize.span().unwrap().start(), SourcePosition::new(15, 1));
+        assert_eq!(finalize.command_span(0).unwrap().start(), SourcePosition::new(17, 5));
+        assert!(finalize.command_span(1).is_none());
+
+        // Ensure the spans are not part of the binary encoding, and do not affect equality.
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&program.to_bytes_le()?)?;
+        assert!(candidate.get_function(&Identifier::from_str("compute")?)?.span().is_none());
+        assert_eq!(program, candidate);
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_size() {
         // Define variable name for easy experimentation with program sizes.
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// A position in the source of a program, as a line and column (both starting from 1).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourcePosition {
+    /// The line.
+    line: usize,
+    /// The column, in characters.
+    column: usize,
+}
+
+impl SourcePosition {
+    /// Initializes a new position.
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Returns the line.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the position right after the given text, which starts at line 1, column 1.
+    pub(crate) fn after(text: &str) -> Self {
+        let line = 1 + text.matches('\n').count();
+        let column = 1 + text.rsplit('\n').next().map_or(0, |last_line| last_line.chars().count());
+        Self { line, column }
+    }
+
+    /// Returns this position (which is relative to the start of a text), relative to the given origin of the text.
+    const fn relative_to(self, origin: SourcePosition) -> Self {
+        match self.line {
+            1 => Self { line: origin.line, column: origin.column + self.column - 1 },
+            _ => Self { line: origin.line + self.line - 1, column: self.column },
+        }
+    }
+}
+
+impl Display for SourcePosition {
+    /// Prints the position, such as `line 3, column 5`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// The span of a parsed program item in the source of the program.
+/// Note: Spans are only retained when parsing from a string, and are not part of the binary encoding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The position of the first character.
+    start: SourcePosition,
+    /// The position right after the last character.
+    end: SourcePosition,
+}
+
+impl Span {
+    /// Initializes a new span.
+    pub const fn new(start: SourcePosition, end: SourcePosition) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the position of the first character.
+    pub const fn start(&self) -> SourcePosition {
+        self.start
+    }
+
+    /// Returns the position right after the last character.
+    pub const fn end(&self) -> SourcePosition {
+        self.end
+    }
+
+    /// Returns the span from the start of the given source, up to the given remainder of the source.
+    pub(crate) fn until(source: &str, remainder: &str) -> Self {
+        Self { start: SourcePosition::new(1, 1), end: SourcePosition::after(&source[..source.len() - remainder.len()]) }
+    }
+
+    /// Returns this span (which is relative to the start of a text), relative to the given origin of the text.
+    pub(crate) const fn relative_to(self, origin: SourcePosition) -> Self {
+        Self { start: self.start.relative_to(origin), end: self.end.relative_to(origin) }
+    }
+
+    /// Returns a parser that parses the whitespace and comments, followed by the given parser,
+    /// and returns the output with its span, relative to the start of the given source.
+    /// Note: The given source must contain the strings that are parsed.
+    pub(crate) fn parser<'a, O>(
+        source: &'a str,
+        mut parser: impl FnMut(&'a str) -> ParserResult<'a, O>,
+    ) -> impl FnMut(&'a str) -> ParserResult<'a, (O, Span)> {
+        move |string: &'a str| {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the output from the string.
+            let (remainder, output) = parser(string)?;
+            // Compute the span of the parsed string.
+            let start = SourcePosition::after(&source[..source.len() - string.len()]);
+            let end = SourcePosition::after(&source[..source.len() - remainder.len()]);
+            Ok((remainder, (output, Span { start, end })))
+        }
+    }
+}
+
+impl Display for Span {
+    /// Prints the start of the span, such as `line 3, column 5`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.start, f)
+    }
+}
+
+/// An error caused by a program item, annotated with the span of the item.
+#[derive(Debug)]
+pub struct SpanError {
+    /// The span of the program item.
+    span: Span,
+    /// The error.
+    error: Error,
+}
+
+impl SpanError {
+    /// Returns the given error, annotated with the given span.
+    /// If there is no span, or the error is already annotated, the error is returned as is.
+    pub fn annotate(error: Error, span: Option<&Span>) -> Error {
+        match span {
+            Some(span) if !error.is::<SpanError>() => Error::new(SpanError { span: *span, error }),
+            _ => error,
+        }
+    }
+
+    /// Returns the span of the program item.
+    pub const fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Returns the error.
+    pub const fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl Display for SpanError {
+    /// Prints the error, followed by the span.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (at {})", self.error, self.span)
+    }
+}
+
+impl std::error::Error for SpanError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_after() {
+        assert_eq!(SourcePosition::after(""), SourcePosition::new(1, 1));
+        assert_eq!(SourcePosition::after("abc"), SourcePosition::new(1, 4));
+        assert_eq!(SourcePosition::after("abc\n"), SourcePosition::new(2, 1));
+        assert_eq!(SourcePosition::after("abc\n  dé"), SourcePosition::new(2, 5));
+    }
+
+    #[test]
+    fn test_span_relative_to() {
+        let span = Span::new(SourcePosition::new(1, 3), SourcePosition::new(2, 4));
+        let origin = SourcePosition::new(5, 9);
+        assert_eq!(span.relative_to(origin), Span::new(SourcePosition::new(5, 11), SourcePosition::new(6, 4)));
+    }
+
+    #[test]
+    fn test_span_error() {
+        let span = Span::new(SourcePosition::new(3, 5), SourcePosition::new(3, 20));
+        let error = SpanError::annotate(anyhow!("Register 'r1' does not exist"), Some(&span));
+        assert_eq!(error.to_string(), "Register 'r1' does not exist (at line 3, column 5)");
+        // Ensure an annotated error is not annotated again.
+        let error = SpanError::annotate(error, Some(&Span::new(SourcePosition::new(1, 1), SourcePosition::new(9, 1))));
+        assert_eq!(error.to_string(), "Register 'r1' does not exist (at line 3, column 5)");
+        assert_eq!(error.downcast_ref::<SpanError>().unwrap().span(), &span);
+        // Ensure an error without a span is unchanged.
+        assert_eq!(SpanError::annotate(anyhow!("error"), None).to_string(), "error");
+    }
+}
@@ -21,6 +21,8 @@ use console::{
 pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     /// Returns the destination registers of the instruction.
     fn destinations(&self) -> Vec<Register<N>>;
+    /// Returns `true` if the instruction is a static or dynamic call.
+    fn is_call(&self) -> bool;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
 }
@@ -77,6 +77,9 @@ pub trait StackProgram<N: Network> {
     /// Returns the external program for the given program ID.
     fn get_external_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>>;
 
+    /// Returns the stack of the program with the given program address, for a dynamic call.
+    fn get_dynamic_stack(&self, address: &Address<N>) -> Result<Arc<Self>>;
+
     /// Returns `true` if the stack contains the external record.
     fn get_external_record(&self, locator: &Locator<N>) -> Result<&RecordType<N>>;
 
@@ -197,6 +200,8 @@ pub trait RegistersLoad<N: Network> {
             Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
             Value::Plaintext(Plaintext::Struct(..))
             | Value::Plaintext(Plaintext::Array(..))
+            | Value::Plaintext(Plaintext::Enum(..))
+            | Value::Plaintext(Plaintext::Vector(..))
             | Value::Record(..)
             | Value::Future(..) => {
                 bail!("Operand must be a literal")
@@ -251,6 +256,8 @@ pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
             circuit::Value::Plaintext(circuit::Plaintext::Struct(..))
             | circuit::Value::Plaintext(circuit::Plaintext::Array(..))
+            | circuit::Value::Plaintext(circuit::Plaintext::Enum(..))
+            | circuit::Value::Plaintext(circuit::Plaintext::Vector(..))
             | circuit::Value::Record(..)
             | circuit::Value::Future(..) => bail!("Operand must be a literal"),
         }
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Checks that this program is a compatible upgrade of the given (previously-deployed) program.
+    ///
+    /// An upgrade may replace the logic of closures, functions, and finalize blocks, and may add
+    /// new mappings, structs, enums, records, and functions. To preserve the existing mappings, records,
+    /// and the callers of this program, an upgrade must:
+    ///  - declare the same imports,
+    ///  - retain every mapping, with the same key and value types,
+    ///  - retain every struct, enum, and record, with the same layout,
+    ///  - retain every interface, with the same function signatures,
+    ///  - retain every function, with the same input and output types, and the same finalize input types.
+    pub fn check_upgrade_from(&self, previous: &Self) -> Result<()> {
+        let program_id = self.id();
+
+        // Ensure the program ID matches.
+        ensure!(program_id == previous.id(), "Cannot upgrade '{}' with program '{program_id}'", previous.id());
+        // Ensure the imports are unchanged.
+        ensure!(
+            self.imports.keys().eq(previous.imports.keys()),
+            "An upgrade of '{program_id}' must declare the same imports as the deployed program"
+        );
+
+        // Ensure the mappings are retained, with the same key and value types.
+        for (name, mapping) in &previous.mappings {
+            match self.mappings.get(name) {
+                Some(upgraded) => ensure!(
+                    upgraded.key() == mapping.key() && upgraded.value() == mapping.value(),
+                    "An upgrade of '{program_id}' cannot change the key or value type of mapping '{name}'"
+                ),
+                None => bail!("An upgrade of '{program_id}' cannot remove mapping '{name}'"),
+            }
+        }
+        // Ensure the structs are retained, with the same layout.
+        for (name, struct_) in &previous.structs {
+            match self.structs.get(name) {
+                Some(upgraded) => ensure!(
+                    upgraded == struct_,
+                    "An upgrade of '{program_id}' cannot change the layout of struct '{name}'"
+                ),
+                None => bail!("An upgrade of '{program_id}' cannot remove struct '{name}'"),
+            }
+        }
+        // Ensure the enums are retained, with the same variants.
+        for (name, enum_) in &previous.enums {
+            match self.enums.get(name) {
+                Some(upgraded) => ensure!(
+                    upgraded == enum_,
+                    "An upgrade of '{program_id}' cannot change the variants of enum '{name}'"
+                ),
+                None => bail!("An upgrade of '{program_id}' cannot remove enum '{name}'"),
+            }
+        }
+        // Ensure the records are retained, with the same layout.
+        for (name, record) in &previous.records {
+            match self.records.get(name) {
+                Some(upgraded) => ensure!(
+                    upgraded == record,
+                    "An upgrade of '{program_id}' cannot change the layout of record '{name}'"
+                ),
+                None => bail!("An upgrade of '{program_id}' cannot remove record '{name}'"),
+            }
+        }
+        // Ensure the interfaces are retained, with the same function signatures.
+        for (name, interface) in &previous.interfaces {
+            match self.interfaces.get(name) {
+                Some(upgraded) => ensure!(
+                    upgraded == interface,
+                    "An upgrade of '{program_id}' cannot change the function signatures of interface '{name}'"
+                ),
+                None => bail!("An upgrade of '{program_id}' cannot remove interface '{name}'"),
+            }
+        }
+        // Ensure the functions are retained, with the same signature.
+        for (name, function) in &previous.functions {
+            match self.functions.get(name) {
+                Some(upgraded) => {
+                    ensure!(
+                        upgraded.input_types() == function.input_types()
+                            && upgraded.output_types() == function.output_types(),
+                        "An upgrade of '{program_id}' cannot change the inputs or outputs of function '{name}'"
+                    );
+                    ensure!(
+                        upgraded.finalize_logic().map(|finalize| finalize.input_types())
+                            == function.finalize_logic().map(|finalize| finalize.input_types()),
+                        "An upgrade of '{program_id}' cannot change the finalize inputs of function '{name}'"
+                    );
+                }
+                None => bail!("An upgrade of '{program_id}' cannot remove function '{name}'"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const PROGRAM: &str = r"
+program token.aleo;
+
+struct info:
+    supply as u64;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async mint r0 r1 into r2;
+    output r2 as token.aleo/mint.future;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];";
+
+    #[test]
+    fn test_check_upgrade_from() -> Result<()> {
+        let previous = Program::<CurrentNetwork>::from_str(PROGRAM)?;
+
+        // Ensure the same program is a valid upgrade.
+        assert!(previous.check_upgrade_from(&previous).is_ok());
+
+        // Ensure changing the finalize logic, and adding a mapping and function, is a valid upgrade.
+        let upgrade = Program::<CurrentNetwork>::from_str(&format!(
+            "{}
+mapping minted:
+    key as boolean.public;
+    value as u64.public;
+
+function burn:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+            PROGRAM.replace("add r2 r1 into r3;", "mul r2 r1 into r3;")
+        ))?;
+        assert!(upgrade.check_upgrade_from(&previous).is_ok());
+
+        // Ensure removing a function is not a valid upgrade.
+        assert!(previous.check_upgrade_from(&upgrade).is_err());
+
+        // Ensure changing the type of a mapping value is not a valid upgrade.
+        let upgrade =
+            Program::<CurrentNetwork>::from_str(&PROGRAM.replace("value as u64.public;", "value as u128.public;"))?;
+        assert!(upgrade.check_upgrade_from(&previous).is_err());
+
+        // Ensure changing the layout of a struct is not a valid upgrade.
+        let upgrade = Program::<CurrentNetwork>::from_str(&PROGRAM.replace("supply as u64;", "supply as u128;"))?;
+        assert!(upgrade.check_upgrade_from(&previous).is_err());
+
+        // Ensure changing the inputs of a function is not a valid upgrade.
+        let upgrade = Program::<CurrentNetwork>::from_str(&PROGRAM.replacen(
+            "input r1 as u64.public;",
+            "input r1 as u64.private;",
+            1,
+        ))?;
+        assert!(upgrade.check_upgrade_from(&previous).is_err());
+
+        // Ensure upgrading a different program is not a valid upgrade.
+        let upgrade = Program::<CurrentNetwork>::from_str(&PROGRAM.replace("token.aleo", "other.aleo"))?;
+        assert!(upgrade.check_upgrade_from(&previous).is_err());
+
+        Ok(())
+    }
+}
@@ -17,14 +17,8 @@ use super::*;
 impl<N: Network> FromBytes for Certificate<N> {
     /// Reads the certificate from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid certificate version"));
-        }
-        // Read the certificate.
-        let certificate = FromBytes::read_le(&mut reader)?;
+        // Read the certificate, using the version of the encoding that was written.
+        let certificate = VersionedBytes::read_versioned_le(&mut reader)?;
         // Return the certificate.
         Ok(Self { certificate })
     }
@@ -33,10 +27,8 @@ impl<N: Network> FromBytes for Certificate<N> {
 impl<N: Network> ToBytes for Certificate<N> {
     /// Writes the certificate to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-        // Write the bytes.
-        self.certificate.write_le(&mut writer)
+        // Write the certificate, using the current version of the encoding.
+        self.certificate.write_versioned_le(&mut writer)
     }
 }
 
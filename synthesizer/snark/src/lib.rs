@@ -18,7 +18,10 @@
 #![cfg_attr(not(feature = "aleo-cli"), allow(unused_variables))]
 
 use console::network::{prelude::*, FiatShamir};
-use snarkvm_algorithms::{snark::varuna, traits::SNARK};
+use snarkvm_algorithms::{
+    snark::varuna::{self, VersionedBytes},
+    traits::SNARK,
+};
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -161,6 +164,43 @@ mod test {
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
 
+    #[test]
+    fn test_varuna_fixtures() -> Result<()> {
+        // Load the fixtures for the example circuit, which were frozen in version 1 of the encoding.
+        let verifying_key_v1 = &include_bytes!("./resources/verifying_key.v1")[..];
+        let certificate_v1 = &include_bytes!("./resources/certificate.v1")[..];
+        let proof_v1 = &include_bytes!("./resources/proof.v1")[..];
+        assert!([verifying_key_v1[0], certificate_v1[0], proof_v1[0]].iter().all(|version| *version == 1));
+
+        // Ensure the fixtures are readable, and still verify.
+        let verifying_key = VerifyingKey::<CurrentNetwork>::read_le(verifying_key_v1)?;
+        let certificate = Certificate::<CurrentNetwork>::read_le(certificate_v1)?;
+        let proof = Proof::<CurrentNetwork>::read_le(proof_v1)?;
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(certificate.verify("test", &crate::test_helpers::sample_assignment(), &verifying_key));
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+
+        // Ensure the fixtures are re-encoded byte-for-byte, as version 1 is still the current version.
+        assert_eq!(verifying_key_v1, &verifying_key.to_bytes_le()?[..]);
+        assert_eq!(certificate_v1, &certificate.to_bytes_le()?[..]);
+        assert_eq!(proof_v1, &proof.to_bytes_le()?[..]);
+
+        // Ensure version 2 of the encoding is accepted on read.
+        let mut proof_v2 = vec![];
+        (*proof).write_versioned_le_as(2, &mut proof_v2)?;
+        assert_eq!(proof, Proof::read_le(&proof_v2[..])?);
+
+        // Ensure an unknown version is rejected.
+        for fixture in [verifying_key_v1, certificate_v1, proof_v1] {
+            let mut bytes = fixture.to_vec();
+            bytes[0] = 3;
+            assert!(VerifyingKey::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+            assert!(Certificate::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+            assert!(Proof::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_varuna_verify_public_input_size() {
         /// Creates a simple circuit: a * b.
@@ -17,14 +17,8 @@ use super::*;
 impl<N: Network> FromBytes for Proof<N> {
     /// Reads the proof from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid proof version"));
-        }
-        // Read the proof.
-        let proof = FromBytes::read_le(&mut reader)?;
+        // Read the proof, using the version of the encoding that was written.
+        let proof = VersionedBytes::read_versioned_le(&mut reader)?;
         // Return the proof.
         Ok(Self { proof })
     }
@@ -33,10 +27,8 @@ impl<N: Network> FromBytes for Proof<N> {
 impl<N: Network> ToBytes for Proof<N> {
     /// Writes the proof to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-        // Write the bytes.
-        self.proof.write_le(&mut writer)
+        // Write the proof, using the current version of the encoding.
+        self.proof.write_versioned_le(&mut writer)
     }
 }
 
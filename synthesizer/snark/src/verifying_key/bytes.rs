@@ -17,14 +17,8 @@ use super::*;
 impl<N: Network> FromBytes for VerifyingKey<N> {
     /// Reads the verifying key from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid verifying key version"));
-        }
-        // Read the verifying key.
-        let verifying_key = Arc::new(FromBytes::read_le(&mut reader)?);
+        // Read the verifying key, using the version of the encoding that was written.
+        let verifying_key = Arc::new(VersionedBytes::read_versioned_le(&mut reader)?);
         // Read the number of variables.
         let num_variables = u64::read_le(&mut reader)?;
         // Return the verifying key.
@@ -35,10 +29,8 @@ impl<N: Network> FromBytes for VerifyingKey<N> {
 impl<N: Network> ToBytes for VerifyingKey<N> {
     /// Writes the verifying key to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-        // Write the verifying key.
-        self.verifying_key.write_le(&mut writer)?;
+        // Write the verifying key, using the current version of the encoding.
+        self.verifying_key.write_versioned_le(&mut writer)?;
         // Write the number of variables.
         self.num_variables.write_le(&mut writer)
     }
@@ -148,7 +148,10 @@ impl<N: Network> Restrictions<N> {
                                                     }
                                                 }
                                             }
-                                            Plaintext::Struct(..) | Plaintext::Array(..) => continue,
+                                            Plaintext::Struct(..)
+                                            | Plaintext::Array(..)
+                                            | Plaintext::Enum(..)
+                                            | Plaintext::Vector(..) => continue,
                                         }
                                     }
                                     _ => continue,
@@ -167,7 +170,10 @@ impl<N: Network> Restrictions<N> {
                                                     }
                                                 }
                                             }
-                                            Plaintext::Struct(..) | Plaintext::Array(..) => continue,
+                                            Plaintext::Struct(..)
+                                            | Plaintext::Array(..)
+                                            | Plaintext::Enum(..)
+                                            | Plaintext::Vector(..) => continue,
                                         }
                                     }
                                     _ => continue,
@@ -69,14 +69,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a deployment for the given program.
+    /// If the program is already deployed, this returns an upgrade to the next edition of the program.
     #[inline]
     pub(super) fn deploy_raw<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
+        // Determine the next edition, if the program is already deployed.
+        let next_edition = match self.transaction_store().deployment_store().get_edition(program.id())? {
+            Some(edition) => Some(edition.checked_add(1).ok_or_else(|| anyhow!("Exceeded the maximum edition"))?),
+            None => None,
+        };
+
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the program.
                 let program = cast_ref!(&program as Program<$network>);
                 // Compute the deployment.
-                let deployment = $process.deploy::<$aleo, _>(program, rng)?;
+                let deployment = match next_edition {
+                    Some(edition) => $process.deploy_upgrade::<$aleo, _>(program, edition, rng)?,
+                    None => $process.deploy::<$aleo, _>(program, rng)?,
+                };
                 // Prepare the deployment.
                 Ok(cast_ref!(deployment as Deployment<N>).clone())
             }};
@@ -15,6 +15,7 @@
 use super::*;
 
 use ledger_committee::{MAX_DELEGATORS, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_SELF_STAKE};
+use synthesizer_program::StackProgram;
 use utilities::cfg_sort_by_cached_key;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -748,9 +749,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Start the commit process. */
 
-            // Commit all of the stacks to the process.
-            if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+            // Commit all of the stacks to the process, adding the new programs before the upgrades,
+            // so that a new program which imports an upgraded program is recomputed with the upgrade.
+            stacks.sort_by_key(|stack| process.contains_program(stack.program_id()));
+            for stack in stacks {
+                // Note: An upgraded stack also recomputes the stacks of the programs that import it.
+                match process.contains_program(stack.program_id()) {
+                    true => process.upgrade_stack(stack).map_err(|e| format!("Failed to upgrade a stack - {e}"))?,
+                    false => process.add_stack(stack),
+                }
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -3284,4 +3291,119 @@ finalize compute:
             assert!(expected_withdraw.contains(entry));
         }
     }
+
+    #[test]
+    fn test_upgrade_preserves_mappings() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next blocks.
+        let splits_block = generate_splits(&vm, &private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Initialize the program, whose finalize logic increments a counter by the given amount.
+        let program_string = |amount: &str| {
+            format!(
+                "
+program upgradable.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function bump:
+    async bump self.caller into r0;
+    output r0 as upgradable.aleo/bump.future;
+
+finalize bump:
+    input r0 as address.public;
+    get.or_use counts[r0] 0u64 into r1;
+    add r1 {amount} into r2;
+    set r2 into counts[r0];"
+            )
+        };
+        let program = Program::<CurrentNetwork>::from_str(&program_string("1u64")).unwrap();
+        let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+
+        // Deploy the first edition of the program.
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&view_key).unwrap());
+        let transaction = vm.deploy(&private_key, &program, credits, 10, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &private_key, &[transaction], &splits_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Increment the counter.
+        let execution =
+            create_execution(&vm, private_key, "upgradable.aleo", "bump", vec![], &mut unspent_records, rng);
+        let execution_block =
+            sample_next_block(&vm, &private_key, &[execution], &deployment_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&execution_block).unwrap();
+
+        // Ensure the counter is incremented.
+        let program_id = ProgramID::from_str("upgradable.aleo").unwrap();
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        let value = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+        assert_eq!(value, Some(Value::from_str("1u64").unwrap()));
+
+        // Ensure an upgrade which changes the type of a mapping cannot be deployed.
+        let incompatible =
+            Program::<CurrentNetwork>::from_str(&program_string("1u64").replace("value as u64", "value as u32"))
+                .unwrap();
+        assert!(vm.deploy(&private_key, &incompatible, None, 10, None, rng).is_err());
+
+        // Ensure an upgrade which is not signed by the program owner is rejected.
+        let upgrade = Program::<CurrentNetwork>::from_str(&program_string("10u64")).unwrap();
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let transaction = vm.deploy(&other_private_key, &upgrade, None, 10, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+
+        // Deploy the next edition of the program.
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&view_key).unwrap());
+        let transaction = vm.deploy(&private_key, &upgrade, credits, 10, None, rng).unwrap();
+        match &transaction {
+            Transaction::Deploy(_, _, deployment, _) => assert_eq!(deployment.edition(), CurrentNetwork::EDITION + 1),
+            _ => panic!("Expected a deploy transaction"),
+        }
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        let upgrade_block =
+            sample_next_block(&vm, &private_key, &[transaction], &execution_block, &mut unspent_records, rng).unwrap();
+        assert!(upgrade_block.transactions().iter().next().unwrap().is_accepted());
+        vm.add_next_block(&upgrade_block).unwrap();
+
+        // Ensure the upgraded program is loaded.
+        assert_eq!(vm.process().read().get_program(program_id).unwrap(), &upgrade);
+        assert_eq!(vm.transaction_store().get_program(&program_id).unwrap(), Some(upgrade.clone()));
+
+        // Increment the counter with the upgraded logic.
+        let execution =
+            create_execution(&vm, private_key, "upgradable.aleo", "bump", vec![], &mut unspent_records, rng);
+        let execution_block =
+            sample_next_block(&vm, &private_key, &[execution], &upgrade_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&execution_block).unwrap();
+
+        // Ensure the mapping is preserved, and updated by the upgraded logic.
+        let value = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+        assert_eq!(value, Some(Value::from_str("11u64").unwrap()));
+
+        // Ensure a VM loaded from the same storage uses the upgraded program.
+        let reloaded = VM::from(vm.store.clone()).unwrap();
+        assert_eq!(reloaded.process().read().get_program(program_id).unwrap(), &upgrade);
+    }
 }
@@ -113,6 +113,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             transaction_store: &TransactionStore<N, T>,
             transaction_id: N::TransactionID,
         ) -> Result<Vec<(ProgramID<N>, Deployment<N>)>> {
+            // Retrieve the program ID from the transaction ID.
+            let Some(program_id) = transaction_store.get_program_id(&transaction_id)? else {
+                bail!("Program ID for deployment transaction '{transaction_id}' is not found in storage.");
+            };
+            // Return early if the deployment has been upgraded, as only the latest edition is loaded.
+            if transaction_store.find_transaction_id_from_program_id(&program_id)? != Some(transaction_id) {
+                return Ok(vec![]);
+            }
+
             // Retrieve the deployment from the transaction ID.
             let deployment = match transaction_store.get_deployment(&transaction_id)? {
                 Some(deployment) => deployment,
@@ -400,6 +409,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -490,7 +500,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
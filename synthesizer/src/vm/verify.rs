@@ -146,17 +146,45 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
-                    bail!("Program ID '{}' already exists", deployment.program_id());
+                // Retrieve the deployment store.
+                let deployment_store = self.transaction_store().deployment_store();
+                // Check the edition, based on whether the program is already deployed.
+                match deployment_store.get_edition(deployment.program_id())? {
+                    // If the program is deployed, ensure the deployment is the next edition, signed by the owner.
+                    Some(edition) => {
+                        // Ensure the edition is correct.
+                        if Some(deployment.edition()) != edition.checked_add(1) {
+                            bail!("Invalid upgrade transaction '{id}' - expected edition {}", edition.saturating_add(1))
+                        }
+                        // Ensure the upgrade is authorized by the owner of the deployed program.
+                        match deployment_store.get_owner(deployment.program_id())? {
+                            Some(program_owner) if program_owner.address() == owner.address() => (),
+                            _ => bail!(
+                                "Invalid upgrade transaction '{id}' - '{}' is not the owner of '{}'",
+                                owner.address(),
+                                deployment.program_id()
+                            ),
+                        }
+                        // Ensure the program exists in the process.
+                        if !self.contains_program(deployment.program_id()) {
+                            bail!("Program ID '{}' does not exist", deployment.program_id());
+                        }
+                    }
+                    // Otherwise, ensure the deployment is the first edition of a new program.
+                    None => {
+                        // Ensure the edition is correct.
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                        // Ensure the program ID does not already exist in the store.
+                        if self.transaction_store().contains_program_id(deployment.program_id())? {
+                            bail!("Program ID '{}' is already deployed", deployment.program_id())
+                        }
+                        // Ensure the program does not already exist in the process.
+                        if self.contains_program(deployment.program_id()) {
+                            bail!("Program ID '{}' already exists", deployment.program_id());
+                        }
+                    }
                 }
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
@@ -245,6 +273,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
+                    // Ensure the execution does not contain memos, as they are only bound by the fee.
+                    ensure!(
+                        execution.transitions().all(|transition| transition.memos().is_empty()),
+                        "Transaction '{id}' contains memos without a fee (execution)"
+                    );
                 }
             }
             // Note: This transaction type does not need to check the fee amount, because:
@@ -383,7 +416,7 @@ mod tests {
         account::{Address, ViewKey},
         types::Field,
     };
-    use ledger_block::{Block, Header, Metadata, Transaction};
+    use ledger_block::{Block, Execution, Header, Memo, Metadata, Transaction};
 
     type CurrentNetwork = test_helpers::CurrentNetwork;
 
@@ -513,6 +546,41 @@ mod tests {
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_fee_rejects_unbound_memos() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Initialize a memo for the first output.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+        let plaintext = Plaintext::from_str("{ note: 1field }").unwrap();
+        let memo = Memo::new(0, &address, &plaintext, rng).unwrap();
+
+        // Ensure an execution without a fee cannot contain memos.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+        vm.check_fee(&transaction, None).unwrap();
+        let execution = transaction.execution().unwrap();
+        let transitions = execution.transitions().map(|transition| transition.clone().with_memos(vec![memo.clone()]));
+        let transitions = transitions.collect::<Result<Vec<_>>>().unwrap();
+        let execution = Execution::from(transitions.into_iter(), execution.global_state_root(), None).unwrap();
+        let candidate = Transaction::from_execution(execution, None).unwrap();
+        let error = vm.check_fee(&candidate, None).unwrap_err();
+        assert!(error.to_string().contains("memos"));
+
+        // Ensure a fee cannot contain memos.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let (Some(execution), Some(fee)) = (transaction.execution(), transaction.fee_transition()) else {
+            panic!("Expected an execution with a fee")
+        };
+        let execution_id = execution.to_execution_id().unwrap();
+        vm.check_fee_internal(&fee, execution_id).unwrap();
+        let transition = fee.transition().clone().with_memos(vec![memo]).unwrap();
+        let candidate = Fee::from(transition, fee.global_state_root(), fee.proof().cloned()).unwrap();
+        let error = vm.check_fee_internal(&candidate, execution_id).unwrap_err();
+        assert!(error.to_string().contains("memos"));
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
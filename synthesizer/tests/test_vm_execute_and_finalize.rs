@@ -542,6 +542,7 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     FinalizeGlobalState::new::<CurrentNetwork>(
         next_round,
         next_height,
+        latest_block.timestamp(),
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
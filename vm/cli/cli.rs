@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::{
+    circuit::{AleoCanaryV0, AleoTestnetV0, AleoV0},
+    cli::{
+        commands::{Build, Clean, Deploy, Execute, Fmt, Lint, New, Run, StartDevnet, Test, Update, UpdateDeps},
+        helpers::{dotenv_network, NetworkName},
+    },
+    console::network::{CanaryV0, MainnetV0, Network, TestnetV0},
+};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -31,6 +38,9 @@ pub struct CLI {
     /// Specify the verbosity [options: 0, 1, 2, 3]
     #[clap(default_value = "2", short, long)]
     pub verbosity: u8,
+    /// Specify the network [options: mainnet, testnet, canary] (defaults to `NETWORK` in '.env', or mainnet)
+    #[clap(global = true, long)]
+    pub network: Option<NetworkName>,
     /// Specify a subcommand.
     #[clap(subcommand)]
     pub command: Command,
@@ -42,26 +52,63 @@ pub enum Command {
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[clap(name = "deploy")]
+    Deploy(Deploy),
+    #[clap(name = "devnet")]
+    Devnet(StartDevnet),
     #[clap(name = "execute")]
     Execute(Execute),
+    #[clap(name = "fmt")]
+    Fmt(Fmt),
+    #[clap(name = "lint")]
+    Lint(Lint),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
     Run(Run),
+    #[clap(name = "test")]
+    Test(Test),
     #[clap(name = "update")]
     Update(Update),
+    #[clap(name = "update-deps")]
+    UpdateDeps(UpdateDeps),
+}
+
+impl CLI {
+    /// Returns the selected network, which defaults to the network in the environment.
+    pub fn network(&self) -> Result<NetworkName> {
+        match self.network {
+            Some(network) => Ok(network),
+            None => dotenv_network(),
+        }
+    }
 }
 
 impl Command {
-    /// Parse the command.
-    pub fn parse(self) -> Result<String> {
+    /// Parse the command, on the given network.
+    pub fn parse(self, network: NetworkName) -> Result<String> {
+        match network {
+            NetworkName::Mainnet => self.parse_on::<MainnetV0, AleoV0>(),
+            NetworkName::Testnet => self.parse_on::<TestnetV0, AleoTestnetV0>(),
+            NetworkName::Canary => self.parse_on::<CanaryV0, AleoCanaryV0>(),
+        }
+    }
+
+    /// Parse the command, on the network `N`.
+    fn parse_on<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
         match self {
-            Self::Build(command) => command.parse(),
-            Self::Clean(command) => command.parse(),
-            Self::Execute(command) => command.parse(),
-            Self::New(command) => command.parse(),
-            Self::Run(command) => command.parse(),
+            Self::Build(command) => command.parse::<N, A>(),
+            Self::Clean(command) => command.parse::<N>(),
+            Self::Deploy(command) => command.parse::<N, A>(),
+            Self::Devnet(command) => command.parse::<N>(),
+            Self::Execute(command) => command.parse::<N, A>(),
+            Self::Fmt(command) => command.parse::<N>(),
+            Self::Lint(command) => command.parse::<N>(),
+            Self::New(command) => command.parse::<N>(),
+            Self::Run(command) => command.parse::<N, A>(),
+            Self::Test(command) => command.parse::<N, A>(),
             Self::Update(command) => command.parse(),
+            Self::UpdateDeps(command) => command.parse::<N>(),
         }
     }
 }
@@ -76,4 +123,22 @@ mod tests {
         use clap::CommandFactory;
         CLI::command().debug_assert()
     }
+
+    #[test]
+    fn test_network_selector() {
+        // Ensure the network defaults to the environment.
+        let cli = CLI::parse_from(["snarkvm", "clean"]);
+        assert_eq!(cli.network, None);
+
+        // Ensure the network can be selected before or after the subcommand.
+        let cli = CLI::parse_from(["snarkvm", "--network", "testnet", "clean"]);
+        assert_eq!(cli.network().unwrap(), NetworkName::Testnet);
+        let cli = CLI::parse_from(["snarkvm", "clean", "--network", "canary"]);
+        assert_eq!(cli.network().unwrap(), NetworkName::Canary);
+
+        // Ensure an unknown network is rejected.
+        assert!(CLI::try_parse_from(["snarkvm", "--network", "devnet", "clean"]).is_err());
+        assert!("devnet".parse::<NetworkName>().is_err());
+        assert_eq!(" Mainnet ".parse::<NetworkName>().unwrap(), NetworkName::Mainnet);
+    }
 }
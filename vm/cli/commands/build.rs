@@ -27,19 +27,19 @@ pub struct Build {
 
 impl Build {
     /// Compiles an Aleo program with the specified name.
-    pub fn parse(self) -> Result<String> {
+    pub fn parse<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::<N>::open(&path)?;
 
         println!("⚠️  Attention - This command is deprecated. Use the {} command.\n", "'run'".to_string().bold());
 
         // Build the package, if the package requires building.
-        package.build::<Aleo>(self.endpoint)?;
+        package.build::<A>(self.endpoint)?;
 
-        // package.build::<Aleo>(match self.offline {
+        // package.build::<A>(match self.offline {
         //     true => None,
         //     false => Some(endpoint.unwrap_or("https://api.explorer.aleo.org/v0/mainnet/build".to_string())),
         // })?;
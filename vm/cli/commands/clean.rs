@@ -20,12 +20,12 @@ pub struct Clean;
 
 impl Clean {
     /// Cleans an Aleo package build directory.
-    pub fn parse(self) -> Result<String> {
+    pub fn parse<N: Network>(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
         // Clean the build directory.
-        Package::<CurrentNetwork>::clean(&path)?;
+        Package::<N>::clean(&path)?;
 
         // Prepare the path string.
         let path_string = format!("(in \"{}\")", path.join("build").display());
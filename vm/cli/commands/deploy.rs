@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    cli::helpers::Devnet,
+    console::program::{Plaintext, Record},
+    ledger::{query::Query, store::helpers::memory::BlockMemory},
+    package::DeployRequest,
+};
+
+/// Deploys an Aleo program, paying the deployment fee
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// The priority fee in microcredits, which is paid on top of the deployment cost.
+    #[clap(default_value = "0", long)]
+    priority_fee: u64,
+    /// Pays the fee privately with the smallest of the given credits records that covers the fee
+    /// (otherwise, the fee is paid publicly).
+    #[clap(long)]
+    record: Vec<String>,
+    /// Uses the specified endpoint to query the state of the network.
+    #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
+    endpoint: String,
+    /// Broadcasts the deploy transaction to the specified URL.
+    #[clap(long, conflicts_with = "local")]
+    broadcast: Option<String>,
+    /// Deploys to an in-process local devnet, whose genesis block funds the private key.
+    #[clap(long, conflicts_with_all = ["record", "broadcast"])]
+    local: bool,
+}
+
+impl Deploy {
+    /// Deploys the Aleo program in the current directory.
+    pub fn parse<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Parse the fee records.
+        let records =
+            self.record.iter().map(|record| Record::<N, Plaintext<N>>::from_str(record)).collect::<Result<Vec<_>>>()?;
+
+        // Load the package.
+        let package = Package::<N>::open(&path)?;
+        // Load the private key.
+        let private_key = crate::cli::helpers::dotenv_private_key::<N>()?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        // If the local devnet is selected, deploy the package and its imports to it.
+        if self.local {
+            println!("⏳ Starting a local devnet...\n");
+            // Initialize the devnet.
+            let devnet = Devnet::<N>::new(private_key, rng)?;
+            // Deploy the package.
+            for transaction in devnet.deploy_package(&package, self.priority_fee, rng)? {
+                if let Some(program_id) = transaction.deployment().map(|deployment| deployment.program_id()) {
+                    println!(" •  Deployed '{}' in transaction '{}'", program_id.to_string().bold(), transaction.id());
+                }
+            }
+            println!();
+
+            return Ok(format!(
+                "✅ Deployed '{}' to a local devnet at block {} {}",
+                package.program_id().to_string().bold(),
+                devnet.ledger().latest_height(),
+                path_string.dimmed()
+            ));
+        }
+
+        // Construct the deploy transaction.
+        let transaction = package.deploy_transaction::<A, _>(
+            &private_key,
+            records,
+            self.priority_fee,
+            Query::<_, BlockMemory<_>>::from(self.endpoint),
+            rng,
+        )?;
+
+        match self.broadcast {
+            Some(url) => {
+                // Send the deploy request.
+                let response = DeployRequest::new(transaction.clone(), *package.program_id()).send(&url)?;
+                // Ensure the transaction ID matches.
+                anyhow::ensure!(
+                    response.transaction_id() == &transaction.id(),
+                    "Transaction ID mismatch: {} != {}",
+                    response.transaction_id(),
+                    transaction.id()
+                );
+                Ok(format!(
+                    "✅ Broadcast the deployment of '{}' in transaction '{}' {}",
+                    package.program_id().to_string().bold(),
+                    transaction.id(),
+                    path_string.dimmed()
+                ))
+            }
+            None => {
+                // Print the transaction.
+                println!("{transaction}\n");
+                Ok(format!(
+                    "✅ Created a deployment for '{}' {}",
+                    package.program_id().to_string().bold(),
+                    path_string.dimmed()
+                ))
+            }
+        }
+    }
+}
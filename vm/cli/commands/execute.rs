@@ -13,46 +13,64 @@
 // limitations under the License.
 
 use super::*;
+use crate::console::program::{Plaintext, Record};
 
 /// Executes an Aleo program function locally
 #[derive(Debug, Parser)]
 pub struct Execute {
     /// The function name.
-    function: Identifier<CurrentNetwork>,
+    function: String,
     /// The function inputs.
-    inputs: Vec<Value<CurrentNetwork>>,
+    inputs: Vec<String>,
     /// Uses the specified endpoint.
     #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
     endpoint: String,
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
+    /// The priority fee in microcredits, which is paid on top of the execution cost.
+    #[clap(default_value = "0", long)]
+    priority_fee: u64,
+    /// Pays the fee privately with the smallest of the given credits records that covers the fee
+    /// (otherwise, the fee is paid publicly).
+    #[clap(long)]
+    record: Vec<String>,
+    /// Broadcasts the execute transaction to the specified URL.
+    #[clap(long)]
+    broadcast: Option<String>,
 }
 
 impl Execute {
     /// Compiles an Aleo program function with the specified name.
     #[allow(clippy::format_in_format_args)]
-    pub fn parse(self) -> Result<String> {
+    pub fn parse<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
+        // Parse the function name, inputs, and fee records.
+        let function = Identifier::<N>::from_str(&self.function)?;
+        let inputs = self.inputs.iter().map(|input| Value::<N>::from_str(input)).collect::<Result<Vec<_>>>()?;
+        let records =
+            self.record.iter().map(|record| Record::<N, Plaintext<N>>::from_str(record)).collect::<Result<Vec<_>>>()?;
+
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::<N>::open(&path)?;
         // Load the private key.
-        let private_key = crate::cli::helpers::dotenv_private_key()?;
+        let private_key = crate::cli::helpers::dotenv_private_key::<N>()?;
 
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
-        // Execute the request.
-        let (response, execution, metrics) =
-            package.execute::<Aleo, _>(self.endpoint, &private_key, self.function, &self.inputs, rng)?;
-
-        // TODO (howardwu): Include the option to execute a fee.
-        let fee = None;
-
-        // Construct the transaction.
-        let transaction = Transaction::from_execution(execution, fee)?;
+        // Execute the request, and pay for the execution.
+        let (response, transaction, metrics) = package.execute_transaction::<A, _>(
+            self.endpoint,
+            &private_key,
+            function,
+            &inputs,
+            records,
+            self.priority_fee,
+            rng,
+        )?;
 
         // Count the number of times a function is called.
         let mut program_frequency = HashMap::<String, usize>::new();
@@ -103,8 +121,21 @@ impl Execute {
         // Print the transaction.
         println!("{transaction}\n");
 
+        // Broadcast the transaction, if requested.
+        if let Some(url) = self.broadcast {
+            // Send the transaction, which is acknowledged with its transaction ID.
+            let transaction_id: N::TransactionID = ureq::post(&url).send_json(&transaction)?.into_json()?;
+            // Ensure the transaction ID matches.
+            anyhow::ensure!(
+                transaction_id == transaction.id(),
+                "Transaction ID mismatch: {transaction_id} != {}",
+                transaction.id()
+            );
+            println!("📡 Broadcast transaction '{transaction_id}' to '{url}'\n");
+        }
+
         // Prepare the locator.
-        let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", package.program_id(), self.function))?;
+        let locator = Locator::<N>::from_str(&format!("{}/{function}", package.program_id()))?;
         // Prepare the path string.
         let path_string = format!("(in \"{}\")", path.display());
 
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{file::AleoFile, synthesizer::Program};
+
+use anyhow::{anyhow, bail};
+use std::path::PathBuf;
+
+/// Formats Aleo programs into the canonical layout, preserving their comments
+#[derive(Debug, Parser)]
+pub struct Fmt {
+    /// The Aleo files to format (defaults to the main program in the current directory).
+    files: Vec<PathBuf>,
+    /// Checks that the files are formatted, without writing them.
+    #[clap(long)]
+    check: bool,
+}
+
+impl Fmt {
+    /// Formats the Aleo files.
+    pub fn parse<N: Network>(self) -> Result<String> {
+        // Retrieve the files to format.
+        let files = match self.files.is_empty() {
+            true => vec![std::env::current_dir()?.join(AleoFile::<N>::main_file_name())],
+            false => self.files,
+        };
+
+        // Format each file, and collect the files that were not formatted.
+        let mut unformatted = Vec::new();
+        for file in &files {
+            // Read the program from the file.
+            let source = std::fs::read_to_string(file)
+                .map_err(|error| anyhow!("Failed to read '{}': {error}", file.display()))?;
+            // Format the program.
+            let formatted = Program::<N>::format(&source)
+                .map_err(|error| anyhow!("Failed to format '{}': {error}", file.display()))?;
+            if formatted != source {
+                // If this is not a check, write the formatted program to the file.
+                if !self.check {
+                    std::fs::write(file, formatted)?;
+                }
+                unformatted.push(file);
+            }
+        }
+
+        match self.check {
+            true => {
+                // Print the files that are not formatted.
+                for file in &unformatted {
+                    println!("{} {}", "Not formatted:".yellow().bold(), file.display());
+                }
+                match unformatted.len() {
+                    0 => Ok(format!("✅ Checked {} files, which are formatted", files.len())),
+                    num_unformatted => bail!("{num_unformatted} of {} files are not formatted", files.len()),
+                }
+            }
+            false => Ok(format!(
+                "✅ Formatted {} files {}",
+                files.len(),
+                format!("({} changed)", unformatted.len()).dimmed()
+            )),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::synthesizer::process::lint::{Config, Rule, Severity};
+
+use anyhow::bail;
+
+/// Checks an Aleo program for likely mistakes, such as unused registers and arithmetic that always halts
+#[derive(Debug, Parser)]
+pub struct Lint {
+    /// Allows the given rule (may be repeated).
+    #[clap(long, value_name = "RULE")]
+    allow: Vec<String>,
+    /// Reports the given rule as a warning (may be repeated).
+    #[clap(long, value_name = "RULE")]
+    warn: Vec<String>,
+    /// Reports the given rule as an error (may be repeated).
+    #[clap(long, value_name = "RULE")]
+    deny: Vec<String>,
+    /// The maximum finalize cost in microcredits, for the 'finalize-cost' rule.
+    #[clap(default_value_t = Config::DEFAULT_MAX_FINALIZE_COST, long)]
+    max_finalize_cost: u64,
+    /// Prints the diagnostics as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+impl Lint {
+    /// Lints the Aleo program in the current directory.
+    pub fn parse<N: Network>(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Initialize the configuration, where '--deny' takes precedence over '--warn', and '--warn' over '--allow'.
+        let mut config = Config::default().with_max_finalize_cost(self.max_finalize_cost);
+        for (rules, severity) in
+            [(self.allow, Severity::Allow), (self.warn, Severity::Warn), (self.deny, Severity::Deny)]
+        {
+            for rule in rules {
+                config = config.with_severity(Rule::from_str(&rule)?, severity);
+            }
+        }
+
+        // Load the package.
+        let package = Package::<N>::open(&path)?;
+        // Lint the program.
+        let diagnostics = package.get_process()?.lint(package.program_id(), &config)?;
+
+        // Print the diagnostics.
+        match self.json {
+            true => println!("{}", serde_json::to_string_pretty(&diagnostics)?),
+            false => {
+                for diagnostic in &diagnostics {
+                    let label = format!("{}[{}]", diagnostic.severity(), diagnostic.rule());
+                    let label = match diagnostic.is_error() {
+                        true => label.red().bold(),
+                        false => label.yellow().bold(),
+                    };
+                    println!("{label}: {}\n  {} {}\n", diagnostic.message(), "-->".dimmed(), diagnostic.location());
+                }
+            }
+        }
+
+        // Fail if any diagnostic is an error.
+        let num_errors = diagnostics.iter().filter(|diagnostic| diagnostic.is_error()).count();
+        match num_errors {
+            0 => Ok(format!(
+                "✅ Linted '{}' {}",
+                package.program_id().to_string().bold(),
+                format!("({} warnings)", diagnostics.len()).dimmed()
+            )),
+            _ => bail!("Linting '{}' found {num_errors} errors", package.program_id()),
+        }
+    }
+}
@@ -18,21 +18,41 @@ pub use build::*;
 pub mod clean;
 pub use clean::*;
 
+pub mod deploy;
+pub use deploy::*;
+
 pub mod execute;
 pub use execute::*;
 
+pub mod fmt;
+pub use fmt::*;
+
+pub mod lint;
+pub use lint::*;
+
 pub mod new;
 pub use new::*;
 
 pub mod run;
 pub use run::*;
 
+pub mod start_devnet;
+pub use start_devnet::*;
+
+pub mod test;
+pub use test::*;
+
 pub mod update;
 pub use update::*;
 
+pub mod update_deps;
+pub use update_deps::*;
+
 use crate::{
-    console::program::{Identifier, Locator, ProgramID, Value},
-    ledger::block::Transaction,
+    console::{
+        network::Network,
+        program::{Identifier, Locator, ProgramID, Value},
+    },
     package::Package,
 };
 
@@ -43,6 +63,3 @@ use core::str::FromStr;
 use std::collections::HashMap;
 
 pub const LOCALE: &num_format::Locale = &num_format::Locale::en;
-
-pub(crate) type CurrentNetwork = crate::prelude::MainnetV0;
-pub(crate) type Aleo = crate::circuit::AleoV0;
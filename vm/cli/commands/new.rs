@@ -23,13 +23,13 @@ pub struct New {
 
 impl New {
     /// Creates an Aleo package with the specified name.
-    pub fn parse(self) -> Result<String> {
+    pub fn parse<N: Network>(self) -> Result<String> {
         // Derive the program directory path.
         let mut path = std::env::current_dir()?;
         path.push(&self.name);
 
         // Create the program ID from the name.
-        let id = ProgramID::<CurrentNetwork>::from_str(&format!("{}.aleo", self.name))?;
+        let id = ProgramID::<N>::from_str(&format!("{}.aleo", self.name))?;
 
         // Create the package.
         Package::create(&path, &id)?;
@@ -18,28 +18,32 @@ use super::*;
 #[derive(Debug, Parser)]
 pub struct Run {
     /// The function name.
-    function: Identifier<CurrentNetwork>,
+    function: String,
     /// The function inputs.
-    inputs: Vec<Value<CurrentNetwork>>,
+    inputs: Vec<String>,
 }
 
 impl Run {
     /// Compiles an Aleo program function with the specified name.
     #[allow(clippy::format_in_format_args)]
-    pub fn parse(self) -> Result<String> {
+    pub fn parse<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
+        // Parse the function name and inputs.
+        let function = Identifier::<N>::from_str(&self.function)?;
+        let inputs = self.inputs.iter().map(|input| Value::<N>::from_str(input)).collect::<Result<Vec<_>>>()?;
+
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::<N>::open(&path)?;
         // Load the private key.
-        let private_key = crate::cli::helpers::dotenv_private_key()?;
+        let private_key = crate::cli::helpers::dotenv_private_key::<N>()?;
 
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
         // Execute the request.
-        let (response, metrics) = package.run::<Aleo, _>(&private_key, self.function, &self.inputs, rng)?;
+        let (response, metrics) = package.run::<A, _>(&private_key, function, &inputs, rng)?;
 
         // Count the number of times a function is called.
         let mut program_frequency = HashMap::<String, usize>::new();
@@ -88,7 +92,7 @@ impl Run {
         println!();
 
         // Prepare the locator.
-        let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", package.program_id(), self.function))?;
+        let locator = Locator::<N>::from_str(&format!("{}/{function}", package.program_id()))?;
         // Prepare the path string.
         let path_string = format!("(in \"{}\")", path.display());
 
@@ -101,7 +105,7 @@ mod tests {
     use super::*;
     use crate::{
         cli::{Command, CLI},
-        prelude::{Identifier, Value},
+        prelude::{Identifier, MainnetV0, Value},
     };
 
     #[test]
@@ -110,12 +114,11 @@ mod tests {
         let cli = CLI::parse_from(&arg_vec);
 
         if let Command::Run(run) = cli.command {
-            assert_eq!(run.function, Identifier::try_from(arg_vec[2]).unwrap());
-            assert_eq!(run.inputs, vec![
-                Value::try_from(arg_vec[3]).unwrap(),
-                Value::try_from(arg_vec[4]).unwrap(),
-                Value::try_from(arg_vec[5]).unwrap()
-            ]);
+            assert_eq!(run.function, arg_vec[2]);
+            assert_eq!(run.inputs, vec![arg_vec[3], arg_vec[4], arg_vec[5]]);
+            // Ensure the function and inputs parse on the selected network.
+            assert!(Identifier::<MainnetV0>::from_str(&run.function).is_ok());
+            assert!(run.inputs.iter().all(|input| Value::<MainnetV0>::from_str(input).is_ok()));
         } else {
             panic!("Unexpected result of clap parsing!");
         }
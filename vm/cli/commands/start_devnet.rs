@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    cli::helpers::{Devnet, NetworkName, Rest},
+    console::account::{Address, PrivateKey},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+/// Runs a local devnet, which serves the REST API and produces blocks
+#[derive(Debug, Parser)]
+pub struct StartDevnet {
+    /// The number of funded development accounts (at least 4).
+    #[clap(default_value = "4", long)]
+    accounts: u16,
+    /// The address to serve the REST API on.
+    #[clap(default_value = "127.0.0.1:3030", long)]
+    listen: SocketAddr,
+    /// Produces a block every given number of seconds (if 0, blocks are only produced on demand).
+    #[clap(default_value = "10", long)]
+    block_interval: u64,
+}
+
+impl StartDevnet {
+    /// Starts the devnet, and serves the REST API until the process is stopped.
+    pub fn parse<N: Network>(self) -> Result<String> {
+        // Derive the development accounts, which are the same on every run.
+        let private_keys = (0..u64::from(self.accounts))
+            .map(|index| PrivateKey::<N>::new(&mut StdRng::seed_from_u64(index)))
+            .collect::<Result<Vec<_>>>()?;
+
+        println!("⏳ Starting a local devnet...\n");
+        // Initialize the devnet.
+        let devnet = Arc::new(Devnet::new_with_accounts(&private_keys, &mut rand::thread_rng())?);
+
+        // Print the development accounts.
+        for private_key in &private_keys {
+            let address = Address::try_from(private_key)?;
+            println!(" • {} {}", address.to_string().bold(), format!("({private_key})").dimmed());
+        }
+        println!();
+
+        // Produce blocks on a timer, if requested.
+        if self.block_interval > 0 {
+            let devnet = devnet.clone();
+            let interval = Duration::from_secs(self.block_interval);
+            std::thread::spawn(move || loop {
+                std::thread::sleep(interval);
+                match devnet.produce_block(&mut rand::thread_rng()) {
+                    Ok(block) => {
+                        println!(
+                            "📦 Produced block {} with {} transactions",
+                            block.height(),
+                            block.transactions().len()
+                        )
+                    }
+                    Err(error) => eprintln!("⚠️  Failed to produce a block - {error}"),
+                }
+            });
+        }
+
+        // Serve the REST API.
+        let listener = std::net::TcpListener::bind(self.listen)?;
+        println!("🌐 Serving the REST API at 'http://{}/{}'\n", self.listen, NetworkName::of::<N>()?);
+        Rest::new(devnet)?.serve(listener)?;
+
+        Ok("✅ Stopped the local devnet".to_string())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use anyhow::bail;
+
+/// Runs the tests of an Aleo package against a local ledger
+#[derive(Debug, Parser)]
+pub struct Test {
+    /// Only runs the tests whose name contains the given filter.
+    filter: Option<String>,
+}
+
+impl Test {
+    /// Runs the tests of the Aleo package in the current directory.
+    pub fn parse<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::<N>::open(&path)?;
+        // Run the tests.
+        let results = package.test(self.filter.as_deref())?;
+
+        // Print the result of each test case.
+        let (mut passed, mut failed) = (0, 0);
+        for (name, cases) in &results {
+            for (index, case) in cases.iter().enumerate() {
+                let label = format!("{name} #{index} ({})", case.locator);
+                if case.failures.is_empty() {
+                    passed += 1;
+                    println!(" • {} {}", "ok".green(), label.dimmed());
+                } else {
+                    failed += 1;
+                    println!(" • {} {}", "FAILED".red(), label.bold());
+                    case.failures.iter().for_each(|failure| println!("     {failure}"));
+                }
+            }
+        }
+        println!();
+
+        match failed {
+            0 => Ok(format!("✅ Passed {passed} test cases {}", format!("(in {} tests)", results.len()).dimmed())),
+            _ => bail!("{failed} of {} test cases failed", passed + failed),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Resolves the package dependencies, and pins them in the lock file
+#[derive(Debug, Parser)]
+pub struct UpdateDeps;
+
+impl UpdateDeps {
+    /// Resolves the dependencies of the Aleo package in the current directory.
+    pub fn parse<N: Network>(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::<N>::open(&path)?;
+        // Resolve the dependencies.
+        let lock_file = package.update_dependencies()?;
+
+        // Print the locked programs.
+        for program in lock_file.programs() {
+            let version = program.version().map(|version| format!(" v{version}")).unwrap_or_default();
+            println!(
+                " • {}{version} {}",
+                program.program_id().to_string().bold(),
+                format!("({})", program.source()).dimmed()
+            );
+        }
+        println!();
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", lock_file.path().display());
+
+        Ok(format!("✅ Locked {} dependencies {}", lock_file.programs().len(), path_string.dimmed()))
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    console::{
+        account::{Address, PrivateKey},
+        network::Network,
+        program::ProgramID,
+    },
+    ledger::{
+        block::Transaction,
+        committee::{Committee, MIN_VALIDATOR_STAKE},
+        store::helpers::memory::ConsensusMemory,
+        Block,
+        Ledger,
+    },
+    package::Package,
+    synthesizer::{program::Program, VM},
+};
+
+use anyhow::{bail, ensure, Result};
+use core::str::FromStr;
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use rand::{CryptoRng, Rng};
+
+/// An in-process ledger, which is used as a local development network.
+///
+/// The genesis block allocates the starting supply to the given private key, which then
+/// produces a block for every deployment.
+pub struct Devnet<N: Network> {
+    /// The private key of the beacon.
+    private_key: PrivateKey<N>,
+    /// The ledger.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+    /// The transactions waiting to be included in the next block.
+    mempool: Mutex<IndexMap<N::TransactionID, Transaction<N>>>,
+}
+
+impl<N: Network> Devnet<N> {
+    /// Initializes a new local devnet, whose genesis block is produced by the given private key.
+    pub fn new<R: Rng + CryptoRng>(private_key: PrivateKey<N>, rng: &mut R) -> Result<Self> {
+        // Initialize a VM, to sample the genesis block.
+        let vm = VM::from(crate::ledger::store::ConsensusStore::<_, ConsensusMemory<_>>::open(None)?)?;
+        // Sample the genesis block.
+        let genesis = vm.genesis_beacon(&private_key, rng)?;
+        // Initialize the ledger, in production storage mode (which is unused by in-memory storage).
+        let ledger = Ledger::load(genesis, None::<u16>.into())?;
+        Ok(Self { private_key, ledger, mempool: Default::default() })
+    }
+
+    /// Initializes a new local devnet, whose genesis block bonds the first four private keys as the committee,
+    /// and divides the remaining supply between all of the private keys. The first private key produces the blocks.
+    pub fn new_with_accounts<R: Rng + CryptoRng>(private_keys: &[PrivateKey<N>], rng: &mut R) -> Result<Self> {
+        ensure!(private_keys.len() >= 4, "The devnet requires at least 4 accounts");
+        // Retrieve the addresses.
+        let addresses = private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+
+        // Construct the committee.
+        let members = addresses[..4].iter().map(|address| (*address, (MIN_VALIDATOR_STAKE, true, 0u8))).collect();
+        let committee = Committee::<N>::new_genesis(members)?;
+        // Construct the bonded balances.
+        let bonded_balances = committee
+            .members()
+            .iter()
+            .map(|(address, (amount, _, _))| (*address, (*address, *address, *amount)))
+            .collect();
+
+        // Divide the remaining supply between the accounts, allocating any remainder to the first account.
+        let remaining_supply = N::STARTING_SUPPLY - (MIN_VALIDATOR_STAKE * 4);
+        let (balance, remainder) =
+            (remaining_supply / addresses.len() as u64, remaining_supply % addresses.len() as u64);
+        let public_balances = addresses
+            .iter()
+            .enumerate()
+            .map(|(index, address)| (*address, if index == 0 { balance + remainder } else { balance }))
+            .collect();
+
+        // Initialize a VM, to sample the genesis block.
+        let vm = VM::from(crate::ledger::store::ConsensusStore::<_, ConsensusMemory<_>>::open(None)?)?;
+        // Sample the genesis block.
+        let genesis = vm.genesis_quorum(&private_keys[0], committee, public_balances, bonded_balances, rng)?;
+        // Initialize the ledger, in production storage mode (which is unused by in-memory storage).
+        let ledger = Ledger::load(genesis, None::<u16>.into())?;
+        Ok(Self { private_key: private_keys[0], ledger, mempool: Default::default() })
+    }
+
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+
+    /// Deploys the imports of the given package which are not yet on the devnet, followed by its program,
+    /// advancing one block per deployment. Returns the deploy transactions.
+    pub fn deploy_package<R: Rng + CryptoRng>(
+        &self,
+        package: &Package<N>,
+        priority_fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+
+        let mut transactions = Vec::new();
+        // Deploy the imports (in order).
+        for program_id in package.program().imports().keys() {
+            if program_id != &credits_program_id && !self.ledger.vm().contains_program(program_id) {
+                // Open the Aleo program file.
+                let import_program_file = crate::file::AleoFile::open(&package.imports_directory(), program_id, false)?;
+                transactions.push(self.deploy(import_program_file.program(), priority_fee_in_microcredits, rng)?);
+            }
+        }
+        // Deploy the program.
+        transactions.push(self.deploy(package.program(), priority_fee_in_microcredits, rng)?);
+        Ok(transactions)
+    }
+
+    /// Deploys the given program, paying a public fee, and advances the devnet to the next block.
+    fn deploy<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        priority_fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Create the deploy transaction.
+        let transaction =
+            self.ledger.vm().deploy(&self.private_key, program, None, priority_fee_in_microcredits, None, rng)?;
+        // Advance to the next block.
+        self.advance_to_next_block(vec![transaction.clone()], rng)?;
+        Ok(transaction)
+    }
+
+    /// Produces a block containing the given transactions, ensuring they are all accepted.
+    pub fn advance_to_next_block<R: Rng + CryptoRng>(
+        &self,
+        transactions: Vec<Transaction<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+        // Prepare the next block.
+        let block =
+            self.ledger.prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], transactions, rng)?;
+        // Ensure every transaction was accepted.
+        for transaction_id in transaction_ids {
+            match block.transactions().get(&transaction_id) {
+                Some(confirmed) if confirmed.is_accepted() => (),
+                _ => bail!("Transaction '{transaction_id}' was not accepted by the devnet"),
+            }
+        }
+        // Check and add the block to the ledger.
+        self.ledger.check_next_block(&block, rng)?;
+        self.ledger.advance_to_next_block(&block)
+    }
+
+    /// Verifies the given transaction, and adds it to the mempool. Returns the transaction ID.
+    pub fn submit_transaction<R: Rng + CryptoRng>(
+        &self,
+        transaction: Transaction<N>,
+        rng: &mut R,
+    ) -> Result<N::TransactionID> {
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already in the ledger.
+        ensure!(
+            !self.ledger.contains_transaction_id(&transaction_id)?,
+            "Transaction '{transaction_id}' already exists in the ledger"
+        );
+        // Verify the transaction.
+        self.ledger.vm().check_transaction(&transaction, None, rng)?;
+        // Add the transaction to the mempool.
+        self.mempool.lock().insert(transaction_id, transaction);
+        Ok(transaction_id)
+    }
+
+    /// Returns the number of transactions in the mempool.
+    pub fn num_pending_transactions(&self) -> usize {
+        self.mempool.lock().len()
+    }
+
+    /// Produces a block containing the transactions in the mempool, and returns the block.
+    ///
+    /// Unlike `advance_to_next_block`, transactions which are rejected or aborted are still included.
+    pub fn produce_block<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Block<N>> {
+        // Lock the mempool for the duration, so that blocks are produced one at a time.
+        let mut mempool = self.mempool.lock();
+        // Take the pending transactions.
+        let transactions = mempool.drain(..).map(|(_, transaction)| transaction).collect();
+        // Prepare the next block.
+        let block =
+            self.ledger.prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], transactions, rng)?;
+        // Check and add the block to the ledger.
+        self.ledger.check_next_block(&block, rng)?;
+        self.ledger.advance_to_next_block(&block)?;
+        Ok(block)
+    }
+}
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{cli::CurrentNetwork, console::account::PrivateKey};
+use crate::{
+    cli::NetworkName,
+    console::{account::PrivateKey, network::Network},
+};
 
 use anyhow::{anyhow, Result};
 
@@ -38,16 +41,26 @@ fn dotenv_load() -> Result<()> {
 }
 
 /// Returns the private key from the environment.
-pub fn dotenv_private_key() -> Result<PrivateKey<CurrentNetwork>> {
+pub fn dotenv_private_key<N: Network>() -> Result<PrivateKey<N>> {
     if cfg!(test) {
         let rng = &mut crate::utilities::TestRng::fixed(123456789);
-        PrivateKey::<CurrentNetwork>::new(rng)
+        PrivateKey::<N>::new(rng)
     } else {
         use std::str::FromStr;
         dotenv_load()?;
         // Load the private key from the environment.
         let private_key = dotenvy::var("PRIVATE_KEY").map_err(|e| anyhow!("Missing PRIVATE_KEY - {e}"))?;
         // Parse the private key.
-        PrivateKey::<CurrentNetwork>::from_str(&private_key)
+        PrivateKey::<N>::from_str(&private_key)
+    }
+}
+
+/// Returns the network from the environment, or mainnet if it is not set.
+pub fn dotenv_network() -> Result<NetworkName> {
+    // The '.env' file is optional, as commands such as 'new' run outside of a package.
+    let _ = dotenvy::dotenv();
+    match dotenvy::var("NETWORK") {
+        Ok(network) => network.parse(),
+        Err(_) => Ok(NetworkName::default()),
     }
 }
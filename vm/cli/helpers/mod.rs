@@ -12,8 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod devnet;
+pub use devnet::*;
+
 pub mod env;
 pub use env::*;
 
+pub mod network;
+pub use network::*;
+
+pub mod rest;
+pub use rest::*;
+
 pub mod updater;
 pub use updater::*;
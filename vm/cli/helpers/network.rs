@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::console::network::{CanaryV0, MainnetV0, Network, TestnetV0};
+
+use anyhow::{anyhow, bail, Error, Result};
+use clap::ValueEnum;
+use core::{fmt, str::FromStr};
+
+/// The networks which the CLI can target.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum NetworkName {
+    #[default]
+    Mainnet,
+    Testnet,
+    Canary,
+}
+
+impl NetworkName {
+    /// Returns the name of the network `N`.
+    pub fn of<N: Network>() -> Result<Self> {
+        match N::ID {
+            MainnetV0::ID => Ok(Self::Mainnet),
+            TestnetV0::ID => Ok(Self::Testnet),
+            CanaryV0::ID => Ok(Self::Canary),
+            _ => bail!("Unsupported network ID '{}'", N::ID),
+        }
+    }
+}
+
+impl FromStr for NetworkName {
+    type Err = Error;
+
+    /// Parses a network name, such as `mainnet`, ignoring case.
+    fn from_str(network: &str) -> Result<Self> {
+        <Self as ValueEnum>::from_str(network.trim(), true).map_err(|_| {
+            anyhow!("Invalid network '{network}' (expected one of 'mainnet', 'testnet', or 'canary')")
+        })
+    }
+}
+
+impl fmt::Display for NetworkName {
+    /// Prints the network name.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Mainnet => write!(f, "mainnet"),
+            Self::Testnet => write!(f, "testnet"),
+            Self::Canary => write!(f, "canary"),
+        }
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    cli::helpers::{Devnet, NetworkName},
+    console::{
+        network::Network,
+        program::{Identifier, Plaintext, ProgramID},
+        types::Field,
+    },
+    ledger::block::Transaction,
+    prelude::Serialize,
+};
+
+use anyhow::{anyhow, bail, Result};
+use core::str::FromStr;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+};
+
+/// A minimal HTTP server for the devnet, which serves the routes that `Query::REST` expects.
+///
+/// The routes are prefixed by the network name, such as `/mainnet`:
+///  - `GET /{network}/latest/height`
+///  - `GET /{network}/latest/hash`
+///  - `GET /{network}/latest/block`
+///  - `GET /{network}/latest/stateRoot`
+///  - `GET /{network}/block/{height}`
+///  - `GET /{network}/transaction/{id}`
+///  - `GET /{network}/statePath/{commitment}`
+///  - `GET /{network}/program/{id}`
+///  - `GET /{network}/program/{id}/mapping/{name}/{key}`
+///  - `POST /{network}/transaction/broadcast`
+///  - `POST /{network}/devnet/produceBlock`
+pub struct Rest<N: Network> {
+    /// The devnet.
+    devnet: Arc<Devnet<N>>,
+    /// The network name, which prefixes every route.
+    network: NetworkName,
+}
+
+/// An HTTP response, as a status code and a JSON body.
+type Response = (u16, String);
+
+impl<N: Network> Rest<N> {
+    /// The maximum size of a request body, in bytes.
+    /// Note: The JSON encoding of a transaction is up to a few times larger than its byte encoding.
+    const MAX_BODY_SIZE: usize = 4 * N::MAX_TRANSACTION_SIZE;
+
+    /// Initializes a new server for the given devnet.
+    pub fn new(devnet: Arc<Devnet<N>>) -> Result<Self> {
+        Ok(Self { devnet, network: NetworkName::of::<N>()? })
+    }
+
+    /// Serves requests from the given listener, handling each connection on its own thread.
+    pub fn serve(self, listener: TcpListener) -> Result<()> {
+        let server = Arc::new(self);
+        for stream in listener.incoming() {
+            let server = server.clone();
+            let stream = stream?;
+            std::thread::spawn(move || {
+                if let Err(error) = server.handle_connection(stream) {
+                    eprintln!("⚠️  Failed to handle a request - {error}");
+                }
+            });
+        }
+        Ok(())
+    }
+
+    /// Reads a request from the given stream, and writes the response.
+    fn handle_connection(&self, mut stream: TcpStream) -> Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        // Read the request line, such as 'GET /mainnet/latest/height HTTP/1.1'.
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => (method.to_string(), target.to_string()),
+            _ => bail!("Malformed request line '{}'", request_line.trim()),
+        };
+
+        // Read the headers, retaining the content length.
+        let mut content_length = 0usize;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse()?;
+                }
+            }
+        }
+
+        // Ensure the body is within the maximum size, before allocating it.
+        if content_length > Self::MAX_BODY_SIZE {
+            let error = format!("The request body exceeds the maximum size of {} bytes", Self::MAX_BODY_SIZE);
+            return write_response(&mut stream, 413, &to_json(&error)?);
+        }
+
+        // Read the body.
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body)?;
+
+        // Route the request.
+        let path = target.split('?').next().unwrap_or_default();
+        let (status, body) = self.route(&method, path, &String::from_utf8(body)?);
+
+        // Write the response.
+        write_response(&mut stream, status, &body)
+    }
+
+    /// Returns the response for the given method, path, and body.
+    pub fn route(&self, method: &str, path: &str, body: &str) -> Response {
+        // Ensure the route is for this network.
+        let segments = path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+        let segments = match segments.split_first() {
+            Some((network, segments)) if *network == self.network.to_string() => segments,
+            _ => {
+                return (404, to_json(&format!("Expected a route prefixed by '/{}'", self.network)).unwrap_or_default())
+            }
+        };
+
+        // Handle the route, mapping errors on queries to 404, and errors on submissions to 400.
+        match method {
+            "GET" => match self.get(segments) {
+                Ok(response) => (200, response),
+                Err(error) => (404, to_json(&error.to_string()).unwrap_or_default()),
+            },
+            "POST" => match self.post(segments, body) {
+                Ok(response) => (200, response),
+                Err(error) => (400, to_json(&error.to_string()).unwrap_or_default()),
+            },
+            _ => (404, to_json(&format!("Unsupported method '{method}'")).unwrap_or_default()),
+        }
+    }
+
+    /// Returns the JSON response for the given query.
+    fn get(&self, segments: &[&str]) -> Result<String> {
+        let ledger = self.devnet.ledger();
+        match segments {
+            ["latest", "height"] => to_json(&ledger.latest_height()),
+            ["latest", "hash"] => to_json(&ledger.latest_hash()),
+            ["latest", "block"] => to_json(&ledger.latest_block()),
+            ["latest", "stateRoot"] => to_json(&ledger.latest_state_root()),
+            ["block", height] => to_json(&ledger.get_block(height.parse()?)?),
+            ["transaction", id] => {
+                let id = N::TransactionID::from_str(id).map_err(|_| anyhow!("Invalid transaction ID '{id}'"))?;
+                to_json(&ledger.get_transaction(id)?)
+            }
+            ["statePath", commitment] => to_json(&ledger.get_state_path_for_commitment(&Field::from_str(commitment)?)?),
+            ["program", id] => to_json(&ledger.get_program(ProgramID::from_str(id)?)?),
+            ["program", id, "mapping", name, key] => to_json(&ledger.vm().finalize_store().get_value_confirmed(
+                ProgramID::from_str(id)?,
+                Identifier::from_str(name)?,
+                &Plaintext::from_str(key)?,
+            )?),
+            _ => bail!("Unknown route 'GET /{}/{}'", self.network, segments.join("/")),
+        }
+    }
+
+    /// Returns the JSON response for the given submission.
+    fn post(&self, segments: &[&str], body: &str) -> Result<String> {
+        match segments {
+            ["transaction", "broadcast"] => self.broadcast(body),
+            ["devnet", "produceBlock"] => {
+                let block = self.devnet.produce_block(&mut rand::thread_rng())?;
+                println!("📦 Produced block {} with {} transactions", block.height(), block.transactions().len());
+                to_json(&block)
+            }
+            _ => bail!("Unknown route 'POST /{}/{}'", self.network, segments.join("/")),
+        }
+    }
+
+    /// Adds the given transaction to the devnet mempool, and returns its transaction ID.
+    fn broadcast(&self, body: &str) -> Result<String> {
+        let transaction: Transaction<N> = serde_json::from_str(body)?;
+        let transaction_id = self.devnet.submit_transaction(transaction, &mut rand::thread_rng())?;
+        println!("📨 Received transaction '{transaction_id}'");
+        to_json(&transaction_id)
+    }
+}
+
+/// Writes the given status code and JSON body as an HTTP response.
+fn write_response(stream: &mut TcpStream, status: u16, body: &str) -> Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        413 => "Payload Too Large",
+        _ => "Not Found",
+    };
+    write!(stream, "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\n")?;
+    write!(stream, "Content-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len())?;
+    Ok(stream.flush()?)
+}
+
+/// Serializes the given value as JSON.
+fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    Ok(serde_json::to_string(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::{account::PrivateKey, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_route() {
+        let rng = &mut crate::utilities::TestRng::default();
+
+        // Initialize the devnet.
+        let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng)).collect::<Result<Vec<_>>>().unwrap();
+        let devnet = Arc::new(Devnet::new_with_accounts(&private_keys, rng).unwrap());
+        let rest = Rest::new(devnet.clone()).unwrap();
+
+        // Ensure the queries return the latest state.
+        assert_eq!(rest.route("GET", "/mainnet/latest/height", ""), (200, "0".to_string()));
+        assert_eq!(
+            rest.route("GET", "/mainnet/latest/stateRoot", ""),
+            (200, to_json(&devnet.ledger().latest_state_root()).unwrap())
+        );
+        assert_eq!(rest.route("GET", "/mainnet/program/credits.aleo", "").0, 200);
+
+        // Ensure the funded accounts are queryable.
+        let address = crate::console::account::Address::try_from(&private_keys[1]).unwrap();
+        let (status, balance) =
+            rest.route("GET", &format!("/mainnet/program/credits.aleo/mapping/account/{address}"), "");
+        assert_eq!(status, 200);
+        assert_ne!(balance, "null");
+
+        // Ensure unknown routes, networks, and programs are not found.
+        assert_eq!(rest.route("GET", "/mainnet/unknown", "").0, 404);
+        assert_eq!(rest.route("GET", "/testnet/latest/height", "").0, 404);
+        assert_eq!(rest.route("GET", "/mainnet/program/missing.aleo", "").0, 404);
+        // Ensure malformed transactions are rejected.
+        assert_eq!(rest.route("POST", "/mainnet/transaction/broadcast", "{}").0, 400);
+
+        // Ensure a block is produced on demand.
+        assert_eq!(rest.route("POST", "/mainnet/devnet/produceBlock", "").0, 200);
+        assert_eq!(rest.route("GET", "/mainnet/latest/height", ""), (200, "1".to_string()));
+        assert_eq!(rest.route("GET", "/mainnet/block/1", ""), (200, to_json(&devnet.ledger().latest_block()).unwrap()));
+    }
+
+    #[test]
+    fn test_oversized_body() {
+        let rng = &mut crate::utilities::TestRng::default();
+
+        // Initialize the server.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let devnet = Arc::new(Devnet::new_with_accounts(&[private_key], rng).unwrap());
+        let rest = Rest::new(devnet).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || rest.serve(listener));
+
+        // Send a request that declares a body larger than the maximum size.
+        let mut stream = TcpStream::connect(address).unwrap();
+        let content_length = Rest::<CurrentNetwork>::MAX_BODY_SIZE + 1;
+        write!(stream, "POST /mainnet/transaction/broadcast HTTP/1.1\r\nContent-Length: {content_length}\r\n\r\n")
+            .unwrap();
+
+        // Ensure the request is rejected without reading the body.
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"), "{response}");
+    }
+}
@@ -22,7 +22,7 @@ fn main() -> anyhow::Result<()> {
     // Run the updater.
     println!("{}", Updater::print_cli());
     // Run the CLI.
-    match cli.command.parse() {
+    match cli.network().and_then(|network| cli.command.parse(network)) {
         Ok(output) => println!("{output}\n"),
         Err(error) => println!("⚠️  {error}\n"),
     }
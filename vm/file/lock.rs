@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    console::types::Field,
+    prelude::{Network, ProgramID, ToBits, ToBytes},
+    synthesizer::Program,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use core::str::FromStr;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const LOCK_FILE_NAME: &str = "program.lock";
+
+/// A dependency that is pinned in the lock file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockedProgram<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The package version, if the dependency is versioned.
+    version: Option<String>,
+    /// The dependency source.
+    source: String,
+    /// The program checksum.
+    checksum: Field<N>,
+}
+
+impl<N: Network> LockedProgram<N> {
+    /// Initializes a new locked program, by computing the checksum of the given program.
+    pub fn new(program: &Program<N>, version: Option<String>, source: String) -> Result<Self> {
+        Ok(Self { program_id: *program.id(), version, source, checksum: Self::compute_checksum(program)? })
+    }
+
+    /// Returns the checksum of the given program.
+    pub fn compute_checksum(program: &Program<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&program.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the package version, if the dependency is versioned.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the dependency source.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the program checksum.
+    pub const fn checksum(&self) -> &Field<N> {
+        &self.checksum
+    }
+
+    /// Ensures the given program matches the pinned program ID and checksum.
+    pub fn verify(&self, program: &Program<N>) -> Result<()> {
+        ensure!(program.id() == &self.program_id, "Expected '{}', found '{}'", self.program_id, program.id());
+        ensure!(
+            Self::compute_checksum(program)? == self.checksum,
+            "The checksum of '{}' does not match '{LOCK_FILE_NAME}' (run 'snarkvm update-deps')",
+            self.program_id
+        );
+        Ok(())
+    }
+}
+
+pub struct LockFile<N: Network> {
+    /// The file path.
+    path: PathBuf,
+    /// The locked programs, in the order they must be added to a process.
+    programs: Vec<LockedProgram<N>>,
+}
+
+impl<N: Network> LockFile<N> {
+    /// Writes a lock file for the given programs, replacing any existing lock file in the directory.
+    pub fn write(directory: &Path, programs: Vec<LockedProgram<N>>) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the lock file contents.
+        let entries = programs
+            .iter()
+            .map(|program| {
+                let mut entry = serde_json::Map::new();
+                entry.insert("program".to_string(), program.program_id.to_string().into());
+                if let Some(version) = &program.version {
+                    entry.insert("version".to_string(), version.clone().into());
+                }
+                entry.insert("source".to_string(), program.source.clone().into());
+                entry.insert("checksum".to_string(), program.checksum.to_string().into());
+                serde_json::Value::Object(entry)
+            })
+            .collect::<Vec<_>>();
+        let lock_string = serde_json::to_string_pretty(&serde_json::json!({ "programs": entries }))?;
+
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Write the file.
+        File::create(&path)?.write_all(format!("{lock_string}\n").as_bytes())?;
+
+        // Return the lock file.
+        Ok(Self { path, programs })
+    }
+
+    /// Opens the lock file for reading.
+    pub fn open(directory: &Path) -> Result<Self> {
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Ensure the file path exists.
+        ensure!(path.exists(), "Lock file is missing: '{}'", path.display());
+
+        // Read the file to a string.
+        let lock_string = fs::read_to_string(&path)?;
+        let json: serde_json::Value = serde_json::from_str(&lock_string)?;
+
+        // Retrieve the locked programs.
+        let entries = json["programs"].as_array().ok_or_else(|| anyhow!("Locked programs not found."))?;
+        let programs = entries
+            .iter()
+            .map(|entry| {
+                // Retrieve the given field as a string.
+                let field =
+                    |name: &str| entry[name].as_str().ok_or_else(|| anyhow!("Locked program {name} not found."));
+                Ok(LockedProgram {
+                    program_id: ProgramID::from_str(field("program")?)?,
+                    version: entry["version"].as_str().map(|version| version.to_string()),
+                    source: field("source")?.to_string(),
+                    checksum: Field::from_str(field("checksum")?)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Return the lock file.
+        Ok(Self { path, programs })
+    }
+
+    /// Returns `true` if the lock file exists at the given path.
+    pub fn exists_at(directory: &Path) -> bool {
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Return the result.
+        path.is_file() && path.exists()
+    }
+
+    /// Returns the lock file name.
+    pub const fn file_name() -> &'static str {
+        LOCK_FILE_NAME
+    }
+
+    /// Returns the file path.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the locked programs, in the order they must be added to a process.
+    pub fn programs(&self) -> &[LockedProgram<N>] {
+        &self.programs
+    }
+
+    /// Returns the locked program for the given program ID, if it exists.
+    pub fn get(&self, program_id: &ProgramID<N>) -> Option<&LockedProgram<N>> {
+        self.programs.iter().find(|program| program.program_id == *program_id)
+    }
+}
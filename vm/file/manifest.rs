@@ -17,8 +17,9 @@ use crate::{
     synthesizer::Program,
 };
 
-use anyhow::{anyhow, ensure, Result};
-use core::str::FromStr;
+use anyhow::{anyhow, bail, ensure, Result};
+use core::{fmt, str::FromStr};
+use indexmap::IndexMap;
 use std::{
     fs::{self, File},
     io::Write,
@@ -27,11 +28,78 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// The source of a program dependency, as declared in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dependency {
+    /// A package at the given directory, which is relative to the declaring package.
+    Path { path: PathBuf, version: Option<String> },
+    /// A deployed program, which is fetched from the given query endpoint.
+    Endpoint { endpoint: String },
+    /// A package at `{registry}/{program_id}/{version}`, in the given registry directory.
+    Registry { registry: PathBuf, version: String },
+}
+
+impl Dependency {
+    /// Parses a dependency from its manifest entry.
+    fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the (optional) version.
+        let version = match json.get("version") {
+            Some(version) => Some(version.as_str().ok_or_else(|| anyhow!("Dependency version must be a string"))?),
+            None => None,
+        };
+        // Retrieve the given field as a string.
+        let field = |name: &str| {
+            json.get(name).map(|value| value.as_str().ok_or_else(|| anyhow!("Dependency {name} must be a string")))
+        };
+
+        match (field("path"), field("endpoint"), field("registry")) {
+            (Some(path), None, None) => {
+                Ok(Self::Path { path: PathBuf::from(path?), version: version.map(|version| version.to_string()) })
+            }
+            (None, Some(endpoint), None) => {
+                ensure!(version.is_none(), "Dependencies fetched from an endpoint are not versioned");
+                Ok(Self::Endpoint { endpoint: endpoint?.to_string() })
+            }
+            (None, None, Some(registry)) => match version {
+                Some(version) => {
+                    Ok(Self::Registry { registry: PathBuf::from(registry?), version: version.to_string() })
+                }
+                None => bail!("Dependencies from a registry must specify a version"),
+            },
+            _ => bail!("Dependencies must specify exactly one of 'path', 'endpoint', or 'registry'"),
+        }
+    }
+
+    /// Returns the version requirement, if one is specified.
+    pub fn version(&self) -> Option<&str> {
+        match self {
+            Self::Path { version, .. } => version.as_deref(),
+            Self::Endpoint { .. } => None,
+            Self::Registry { version, .. } => Some(version),
+        }
+    }
+}
+
+impl fmt::Display for Dependency {
+    /// Prints the dependency source, as recorded in the lock file.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Path { path, .. } => write!(f, "path+{}", path.display()),
+            Self::Endpoint { endpoint } => write!(f, "endpoint+{endpoint}"),
+            Self::Registry { registry, .. } => write!(f, "registry+{}", registry.display()),
+        }
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
     /// The program ID.
     program_id: ProgramID<N>,
+    /// The package version.
+    version: String,
+    /// The program dependencies.
+    dependencies: IndexMap<ProgramID<N>, Dependency>,
 }
 
 impl<N: Network> Manifest<N> {
@@ -62,7 +130,7 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id })
+        Ok(Self { path, program_id: *id, version: "0.0.0".to_string(), dependencies: IndexMap::new() })
     }
 
     /// Opens the manifest file for reading.
@@ -85,8 +153,23 @@ impl<N: Network> Manifest<N> {
         // Ensure the program name is valid.
         ensure!(!Program::is_reserved_keyword(id.name()), "Program name is invalid (reserved): {id}");
 
+        // Retrieve the version.
+        let version = json["version"].as_str().unwrap_or("0.0.0").to_string();
+
+        // Retrieve the dependencies, if any are declared.
+        let mut dependencies = IndexMap::new();
+        if let Some(entries) = json.get("dependencies") {
+            let entries = entries.as_object().ok_or_else(|| anyhow!("Dependencies must be an object."))?;
+            for (program_id, dependency) in entries {
+                let program_id = ProgramID::from_str(program_id)?;
+                let dependency = Dependency::from_json(dependency)
+                    .map_err(|error| anyhow!("Invalid dependency '{program_id}' in '{}' - {error}", path.display()))?;
+                dependencies.insert(program_id, dependency);
+            }
+        }
+
         // Return the manifest file.
-        Ok(Self { path, program_id: id })
+        Ok(Self { path, program_id: id, version, dependencies })
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -111,4 +194,14 @@ impl<N: Network> Manifest<N> {
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.program_id
     }
+
+    /// Returns the package version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the program dependencies.
+    pub const fn dependencies(&self) -> &IndexMap<ProgramID<N>, Dependency> {
+        &self.dependencies
+    }
 }
@@ -0,0 +1,339 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::file::{Dependency, LockFile, LockedProgram};
+
+use anyhow::anyhow;
+use indexmap::{IndexMap, IndexSet};
+
+impl<N: Network> Package<N> {
+    /// Resolves the manifest dependencies (transitively) into the imports directory,
+    /// and pins the checksum of every resolved program in the lock file.
+    pub fn update_dependencies(&self) -> Result<LockFile<N>> {
+        // Initialize the resolver.
+        let mut resolver = Resolver {
+            root_directory: &self.directory,
+            root_dependencies: self.manifest_file.dependencies(),
+            resolved: IndexMap::new(),
+            visiting: IndexSet::new(),
+        };
+        // Resolve the imports of the main program.
+        let scope = Scope::Package(&self.directory, self.manifest_file.dependencies());
+        for program_id in self.program().imports().keys() {
+            resolver.resolve(program_id, &scope)?;
+        }
+
+        // Prepare the imports directory.
+        let imports_directory = self.imports_directory();
+        // Create the imports directory if it does not exist.
+        if !resolver.resolved.is_empty() && !imports_directory.exists() {
+            std::fs::create_dir_all(&imports_directory)?;
+        }
+
+        // Write each resolved program to the imports directory (in order).
+        let mut programs = Vec::with_capacity(resolver.resolved.len());
+        for (program_id, (program_string, locked_program)) in resolver.resolved {
+            std::fs::write(imports_directory.join(program_id.to_string()), program_string)?;
+            programs.push(locked_program);
+        }
+
+        // Write the lock file.
+        LockFile::write(&self.directory, programs)
+    }
+}
+
+/// The context in which an import is resolved.
+enum Scope<'a, N: Network> {
+    /// The import is made by the package at the given directory, with the given dependencies.
+    Package(&'a Path, &'a IndexMap<ProgramID<N>, Dependency>),
+    /// The import is made by a program that was fetched from the given endpoint.
+    Endpoint(&'a str),
+}
+
+struct Resolver<'a, N: Network> {
+    /// The directory of the package being resolved.
+    root_directory: &'a Path,
+    /// The dependencies of the package being resolved, which take precedence over transitive declarations.
+    root_dependencies: &'a IndexMap<ProgramID<N>, Dependency>,
+    /// The resolved program strings and locked programs, in the order they must be added to a process.
+    resolved: IndexMap<ProgramID<N>, (String, LockedProgram<N>)>,
+    /// The programs that are currently being resolved, used to detect cycles.
+    visiting: IndexSet<ProgramID<N>>,
+}
+
+impl<N: Network> Resolver<'_, N> {
+    /// Resolves the given import, and its imports, within the given scope.
+    fn resolve(&mut self, program_id: &ProgramID<N>, scope: &Scope<N>) -> Result<()> {
+        // Skip 'credits.aleo', as every process is loaded with it.
+        if program_id.to_string() == "credits.aleo" {
+            return Ok(());
+        }
+        // Skip programs that are already resolved.
+        if self.resolved.contains_key(program_id) {
+            return Ok(());
+        }
+        // Ensure the program is not already being resolved.
+        if !self.visiting.insert(*program_id) {
+            bail!("Found a cyclic dependency on '{program_id}'")
+        }
+
+        // Select the dependency, along with the directory it is relative to.
+        let (dependency, base_directory) = match (self.root_dependencies.get(program_id), scope) {
+            (Some(dependency), _) => (dependency.clone(), self.root_directory.to_path_buf()),
+            (None, Scope::Package(directory, dependencies)) => match dependencies.get(program_id) {
+                Some(dependency) => (dependency.clone(), directory.to_path_buf()),
+                None => bail!(
+                    "Missing dependency '{program_id}' - declare it under 'dependencies' in '{}'",
+                    directory.join(Manifest::<N>::file_name()).display()
+                ),
+            },
+            (None, Scope::Endpoint(endpoint)) => {
+                (Dependency::Endpoint { endpoint: endpoint.to_string() }, self.root_directory.to_path_buf())
+            }
+        };
+
+        // Resolve the program from its source.
+        let (program_string, locked_program) = match &dependency {
+            Dependency::Path { path, version } => {
+                self.resolve_package(program_id, &base_directory.join(path), version.as_deref(), &dependency)?
+            }
+            Dependency::Registry { registry, version } => {
+                // Registries store each version of a package at '{registry}/{program_id}/{version}'.
+                let directory = base_directory.join(registry).join(program_id.to_string()).join(version);
+                self.resolve_package(program_id, &directory, Some(version), &dependency)?
+            }
+            Dependency::Endpoint { endpoint } => {
+                // Fetch the program.
+                let program = Query::<N, BlockMemory<N>>::from(endpoint.as_str()).get_program(program_id)?;
+                ensure!(
+                    program.id() == program_id,
+                    "Expected '{program_id}' from '{endpoint}', found '{}'",
+                    program.id()
+                );
+                // Resolve the imports from the same endpoint.
+                for import_id in program.imports().keys() {
+                    self.resolve(import_id, &Scope::Endpoint(endpoint))?;
+                }
+                (program.to_string(), LockedProgram::new(&program, None, dependency.to_string())?)
+            }
+        };
+
+        // Mark the program as resolved.
+        self.visiting.shift_remove(program_id);
+        self.resolved.insert(*program_id, (program_string, locked_program));
+        Ok(())
+    }
+
+    /// Resolves the package at the given directory, and its imports.
+    fn resolve_package(
+        &mut self,
+        program_id: &ProgramID<N>,
+        directory: &Path,
+        version: Option<&str>,
+        dependency: &Dependency,
+    ) -> Result<(String, LockedProgram<N>)> {
+        // Open the package.
+        let package = Package::<N>::open(directory)
+            .map_err(|error| anyhow!("Failed to open dependency '{program_id}' - {error}"))?;
+        // Ensure the program ID matches.
+        ensure!(
+            package.program_id() == program_id,
+            "Expected '{program_id}' at '{}', found '{}'",
+            directory.display(),
+            package.program_id()
+        );
+        // Ensure the version matches, if one is required.
+        let package_version = package.manifest_file().version();
+        if let Some(version) = version {
+            ensure!(
+                package_version == version,
+                "Expected version '{version}' of '{program_id}', found version '{package_version}'"
+            );
+        }
+
+        // Resolve the imports of the package, using its own dependencies.
+        let scope = Scope::Package(package.directory(), package.manifest_file().dependencies());
+        for import_id in package.program().imports().keys() {
+            self.resolve(import_id, &scope)?;
+        }
+
+        let locked_program =
+            LockedProgram::new(package.program(), Some(package_version.to_string()), dependency.to_string())?;
+        Ok((package.program_file().program_string().to_string(), locked_program))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::AleoFile;
+    use snarkvm_console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Writes a package with the given program and manifest dependencies to the given directory.
+    fn write_package(directory: &Path, program: &str, version: &str, dependencies: &str) {
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        std::fs::create_dir_all(directory).unwrap();
+        std::fs::write(directory.join("main.aleo"), program.to_string()).unwrap();
+        std::fs::write(
+            directory.join("program.json"),
+            format!(
+                r#"{{
+    "program": "{}",
+    "version": "{version}",
+    "description": "",
+    "license": "MIT",
+    "dependencies": {{ {dependencies} }}
+}}
+"#,
+                program.id()
+            ),
+        )
+        .unwrap();
+    }
+
+    const CHILD: &str = "
+program child.aleo;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;";
+
+    const PARENT: &str = "
+import child.aleo;
+
+program parent.aleo;
+
+function quadruple:
+    input r0 as u32.private;
+    call child.aleo/double r0 into r1;
+    call child.aleo/double r1 into r2;
+    output r2 as u32.private;";
+
+    const GRANDPARENT: &str = "
+import parent.aleo;
+
+program grandparent.aleo;
+
+function octuple:
+    input r0 as u32.private;
+    call parent.aleo/quadruple r0 into r1;
+    add r1 r1 into r2;
+    output r2 as u32.private;";
+
+    #[test]
+    fn test_update_dependencies() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Publish 'child.aleo' to a registry, and write 'parent.aleo' as a local package which depends on it.
+        write_package(&directory.join("registry").join("child.aleo").join("1.0.0"), CHILD, "1.0.0", "");
+        write_package(
+            &directory.join("parent"),
+            PARENT,
+            "0.2.0",
+            r#""child.aleo": { "registry": "../registry", "version": "1.0.0" }"#,
+        );
+        // Write 'grandparent.aleo', which only declares its direct dependency.
+        write_package(
+            &directory.join("grandparent"),
+            GRANDPARENT,
+            "0.0.0",
+            r#""parent.aleo": { "path": "../parent", "version": "0.2.0" }"#,
+        );
+
+        // Resolve the dependencies.
+        let package = Package::<CurrentNetwork>::open(&directory.join("grandparent")).unwrap();
+        let lock_file = package.update_dependencies().unwrap();
+
+        // Ensure the transitive dependencies are locked in order.
+        let programs = lock_file.programs();
+        assert_eq!(programs.len(), 2);
+        assert_eq!(programs[0].program_id().to_string(), "child.aleo");
+        assert_eq!(programs[0].version(), Some("1.0.0"));
+        assert_eq!(programs[0].source(), "registry+../registry");
+        assert_eq!(programs[1].program_id().to_string(), "parent.aleo");
+        assert_eq!(programs[1].version(), Some("0.2.0"));
+        assert_eq!(programs[1].source(), "path+../parent");
+
+        // Ensure the lock file round-trips.
+        let opened = LockFile::<CurrentNetwork>::open(package.directory()).unwrap();
+        assert_eq!(opened.programs(), lock_file.programs());
+
+        // Ensure the process loads the locked imports.
+        let process = package.get_process().unwrap();
+        assert!(process.contains_program(&ProgramID::from_str("child.aleo").unwrap()));
+        assert!(process.contains_program(&ProgramID::from_str("parent.aleo").unwrap()));
+
+        // Tamper with an import, and ensure the process can no longer be loaded.
+        let child = AleoFile::<CurrentNetwork>::open(&package.imports_directory(), programs[0].program_id(), false)
+            .unwrap()
+            .program_string()
+            .replace("add r0 r0", "mul r0 r0");
+        std::fs::write(package.imports_directory().join("child.aleo"), child).unwrap();
+        assert!(package.get_process().is_err());
+
+        // Ensure updating the dependencies restores the import.
+        package.update_dependencies().unwrap();
+        assert!(package.get_process().is_ok());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_update_dependencies_fails() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Ensure an undeclared dependency is rejected.
+        write_package(&directory.join("child"), CHILD, "1.0.0", "");
+        write_package(&directory.join("parent"), PARENT, "0.0.0", "");
+        let package = Package::<CurrentNetwork>::open(&directory.join("parent")).unwrap();
+        assert!(package.update_dependencies().is_err());
+
+        // Ensure a mismatched version is rejected.
+        write_package(
+            &directory.join("parent"),
+            PARENT,
+            "0.0.0",
+            r#""child.aleo": { "path": "../child", "version": "2.0.0" }"#,
+        );
+        let package = Package::<CurrentNetwork>::open(&directory.join("parent")).unwrap();
+        assert!(package.update_dependencies().is_err());
+
+        // Ensure a matching version is accepted.
+        write_package(
+            &directory.join("parent"),
+            PARENT,
+            "0.0.0",
+            r#""child.aleo": { "path": "../child", "version": "1.0.0" }"#,
+        );
+        let package = Package::<CurrentNetwork>::open(&directory.join("parent")).unwrap();
+        assert!(package.update_dependencies().is_ok());
+
+        // Ensure a dependency must have exactly one source.
+        write_package(
+            &directory.join("parent"),
+            PARENT,
+            "0.0.0",
+            r#""child.aleo": { "path": "../child", "endpoint": "https://api.explorer.aleo.org/v1" }"#,
+        );
+        assert!(Package::<CurrentNetwork>::open(&directory.join("parent")).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -12,20 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ledger::block::Deployment;
-use snarkvm_console::prelude::DeserializeExt;
+use crate::{
+    console::program::{Plaintext, ProgramOwner, Record},
+    ledger::block::{Deployment, Transaction},
+    prelude::de,
+    synthesizer::process::deployment_cost,
+};
 
 use super::*;
 
 pub struct DeployRequest<N: Network> {
-    deployment: Deployment<N>,
+    transaction: Transaction<N>,
     program_id: ProgramID<N>,
 }
 
 impl<N: Network> DeployRequest<N> {
-    /// Sends the request to the given endpoint.
-    pub fn new(deployment: Deployment<N>, program_id: ProgramID<N>) -> Self {
-        Self { deployment, program_id }
+    /// Initializes a new deploy request.
+    pub fn new(transaction: Transaction<N>, program_id: ProgramID<N>) -> Self {
+        Self { transaction, program_id }
     }
 
     /// Sends the request to the given endpoint.
@@ -33,94 +37,76 @@ impl<N: Network> DeployRequest<N> {
         Ok(ureq::post(endpoint).send_json(self)?.into_json()?)
     }
 
-    /// Returns the program.
-    pub const fn deployment(&self) -> &Deployment<N> {
-        &self.deployment
+    /// Returns the deploy transaction.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
     }
 
-    /// Returns the imports.
+    /// Returns the program ID.
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.program_id
     }
 }
 
 impl<N: Network> Serialize for DeployRequest<N> {
-    /// Serializes the deploy request into string or bytes.
+    /// Serializes the deploy request as the raw deploy transaction, as expected by the broadcast endpoint.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut request = serializer.serialize_struct("DeployRequest", 2)?;
-        // Serialize the deployment.
-        request.serialize_field("deployment", &self.deployment)?;
-        // Serialize the program ID.
-        request.serialize_field("program_id", &self.program_id)?;
-        request.end()
+        self.transaction.serialize(serializer)
     }
 }
 
 impl<'de, N: Network> Deserialize<'de> for DeployRequest<N> {
-    /// Deserializes the deploy request from a string or bytes.
+    /// Deserializes the deploy request from the raw deploy transaction.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        // Parse the request from a string into a value.
-        let mut request = serde_json::Value::deserialize(deserializer)?;
-        // Recover the leaf.
-        Ok(Self::new(
-            // Retrieve the program.
-            DeserializeExt::take_from_value::<D>(&mut request, "deployment")?,
-            // Retrieve the program ID.
-            DeserializeExt::take_from_value::<D>(&mut request, "program_id")?,
-        ))
+        // Parse the deploy transaction.
+        let transaction = Transaction::<N>::deserialize(deserializer)?;
+        // Retrieve the program ID from the deployment.
+        let program_id = match transaction.deployment() {
+            Some(deployment) => *deployment.program_id(),
+            None => return Err(de::Error::custom("Expected a deploy transaction")),
+        };
+        Ok(Self::new(transaction, program_id))
     }
 }
 
 pub struct DeployResponse<N: Network> {
-    deployment: Deployment<N>,
+    transaction_id: N::TransactionID,
 }
 
 impl<N: Network> DeployResponse<N> {
     /// Initializes a new deploy response.
-    pub const fn new(deployment: Deployment<N>) -> Self {
-        Self { deployment }
+    pub const fn new(transaction_id: N::TransactionID) -> Self {
+        Self { transaction_id }
     }
 
-    /// Returns the program ID.
-    pub const fn deployment(&self) -> &Deployment<N> {
-        &self.deployment
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
     }
 }
 
 impl<N: Network> Serialize for DeployResponse<N> {
-    /// Serializes the deploy response into string or bytes.
+    /// Serializes the deploy response as the bare transaction ID, as returned by the broadcast endpoint.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut response = serializer.serialize_struct("DeployResponse", 1)?;
-        response.serialize_field("deployment", &self.deployment)?;
-        response.end()
+        self.transaction_id.serialize(serializer)
     }
 }
 
 impl<'de, N: Network> Deserialize<'de> for DeployResponse<N> {
-    /// Deserializes the deploy response from a string or bytes.
+    /// Deserializes the deploy response from the bare transaction ID.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        // Parse the response from a string into a value.
-        let mut response = serde_json::Value::deserialize(deserializer)?;
-        // Recover the leaf.
-        Ok(Self::new(
-            // Retrieve the program ID.
-            DeserializeExt::take_from_value::<D>(&mut response, "deployment")?,
-        ))
+        Ok(Self::new(N::TransactionID::deserialize(deserializer)?))
     }
 }
 
 impl<N: Network> Package<N> {
-    pub fn deploy<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
-        &self,
-        endpoint: Option<String>,
-    ) -> Result<Deployment<N>> {
+    /// Returns the deployment of the main program.
+    pub fn deploy<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(&self) -> Result<Deployment<N>> {
         // Retrieve the main program.
         let program = self.program();
-        // Retrieve the main program ID.
-        let program_id = program.id();
 
         #[cfg(feature = "aleo-cli")]
-        println!("⏳ Deploying '{}'...\n", program_id.to_string().bold());
+        println!("⏳ Deploying '{}'...\n", program.id().to_string().bold());
 
         // Construct the process.
         let mut process = Process::<N>::load()?;
@@ -143,30 +129,58 @@ impl<N: Network> Package<N> {
         // Initialize the RNG.
         let rng = &mut rand::thread_rng();
         // Compute the deployment.
-        let deployment = process.deploy::<A, _>(program, rng).unwrap();
-
-        match endpoint {
-            Some(ref endpoint) => {
-                // Construct the deploy request.
-                let request = DeployRequest::new(deployment, *program_id);
-                // Send the deploy request.
-                let response = request.send(endpoint)?;
-                // Ensure the program ID matches.
-                ensure!(
-                    response.deployment.program_id() == program_id,
-                    "Program ID mismatch: {} != {program_id}",
-                    response.deployment.program_id()
-                );
-                Ok(response.deployment)
-            }
-            None => Ok(deployment),
-        }
+        process.deploy::<A, _>(program, rng)
+    }
+
+    /// Returns a deploy transaction for the main program, which pays for the deployment cost.
+    ///
+    /// If `fee_records` are provided, then a private fee will be included in the transaction, which is paid
+    /// with the smallest record that covers the fee; otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    pub fn deploy_transaction<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_records: Vec<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Query<N, BlockMemory<N>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the deployment.
+        let deployment = self.deploy::<A>()?;
+        // Ensure the transaction is not empty.
+        ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty transaction deployment");
+        // Compute the deployment ID.
+        let deployment_id = deployment.to_deployment_id()?;
+        // Construct the owner.
+        let owner = ProgramOwner::new(private_key, deployment_id, rng)?;
+
+        // Compute the minimum deployment cost.
+        let (minimum_deployment_cost, _) = deployment_cost(&deployment)?;
+
+        // Select the fee record.
+        let fee_record =
+            Self::select_fee_record(fee_records, minimum_deployment_cost.saturating_add(priority_fee_in_microcredits))?;
+        // Compute the fee.
+        let fee = self.prove_fee(
+            private_key,
+            fee_record,
+            minimum_deployment_cost,
+            priority_fee_in_microcredits,
+            deployment_id,
+            query,
+            rng,
+        )?;
+
+        // Return the deploy transaction.
+        Transaction::from_deployment(owner, deployment, fee)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_utilities::Uniform;
 
     type CurrentNetwork = snarkvm_console::network::MainnetV0;
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
@@ -177,7 +191,7 @@ mod tests {
         let (directory, package) = crate::package::test_helpers::sample_token_package();
 
         // Deploy the package.
-        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        let deployment = package.deploy::<CurrentAleo>().unwrap();
 
         // Ensure the deployment edition matches.
         assert_eq!(<CurrentNetwork as Network>::EDITION, deployment.edition());
@@ -196,7 +210,7 @@ mod tests {
         let (directory, package) = crate::package::test_helpers::sample_wallet_package();
 
         // Deploy the package.
-        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        let deployment = package.deploy::<CurrentAleo>().unwrap();
 
         // Ensure the deployment edition matches.
         assert_eq!(<CurrentNetwork as Network>::EDITION, deployment.edition());
@@ -208,4 +222,20 @@ mod tests {
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
+
+    #[test]
+    fn test_deploy_response_serde() {
+        let rng = &mut snarkvm_utilities::TestRng::default();
+
+        // Sample a deploy response.
+        let transaction_id: <CurrentNetwork as Network>::TransactionID = Uniform::rand(rng);
+        let expected = DeployResponse::<CurrentNetwork>::new(transaction_id);
+
+        // Ensure the response is the bare transaction ID.
+        assert_eq!(serde_json::to_string(&expected).unwrap(), serde_json::to_string(&transaction_id).unwrap());
+        // Ensure the response round trips through JSON.
+        let candidate: DeployResponse<CurrentNetwork> =
+            serde_json::from_str(&serde_json::to_string(&expected).unwrap()).unwrap();
+        assert_eq!(expected.transaction_id(), candidate.transaction_id());
+    }
 }
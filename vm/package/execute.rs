@@ -13,6 +13,11 @@
 // limitations under the License.
 
 use super::*;
+use crate::{
+    console::program::{Plaintext, Record},
+    ledger::block::Transaction,
+    synthesizer::process::execution_cost,
+};
 
 impl<N: Network> Package<N> {
     /// Executes a program function with the given inputs.
@@ -108,6 +113,47 @@ impl<N: Network> Package<N> {
         // Return the response, execution, and call metrics.
         Ok((response, execution, call_metrics))
     }
+
+    /// Executes a program function with the given inputs, and returns a transaction which pays for the execution cost.
+    ///
+    /// If `fee_records` are provided, then a private fee will be included in the transaction, which is paid
+    /// with the smallest record that covers the fee; otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub fn execute_transaction<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        endpoint: String,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        fee_records: Vec<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Transaction<N>, Vec<CallMetrics<N>>)> {
+        // Compute the execution.
+        let (response, execution, call_metrics) =
+            self.execute::<A, R>(endpoint.clone(), private_key, function_name, inputs, rng)?;
+
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, _) = execution_cost(&self.get_process()?, &execution)?;
+        // Select the fee record.
+        let fee_record =
+            Self::select_fee_record(fee_records, minimum_execution_cost.saturating_add(priority_fee_in_microcredits))?;
+        // Compute the fee.
+        let fee = self.prove_fee(
+            private_key,
+            fee_record,
+            minimum_execution_cost,
+            priority_fee_in_microcredits,
+            execution.to_execution_id()?,
+            Query::<_, BlockMemory<_>>::from(endpoint),
+            rng,
+        )?;
+
+        // Return the response, execute transaction, and call metrics.
+        Ok((response, Transaction::from_execution(execution, Some(fee))?, call_metrics))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    console::{
+        program::{Entry, Literal, Plaintext, Record},
+        types::Field,
+    },
+    ledger::{
+        block::Fee,
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    },
+    synthesizer::VM,
+};
+
+use super::*;
+
+impl<N: Network> Package<N> {
+    /// Returns a fee for the given deployment or execution ID.
+    ///
+    /// If a `fee_record` is provided, then a private fee is paid with the record;
+    /// otherwise, a public fee is paid from the account balance of the private key.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        query: Query<N, BlockMemory<N>>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        #[cfg(feature = "aleo-cli")]
+        println!(
+            "💰 Paying a {} fee of {} microcredits...\n",
+            if fee_record.is_some() { "private" } else { "public" },
+            base_fee_in_microcredits.saturating_add(priority_fee_in_microcredits)
+        );
+
+        // Initialize a VM, which contains `credits.aleo`.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        // Authorize the fee.
+        let authorization = match fee_record {
+            Some(record) => vm.authorize_fee_private(
+                private_key,
+                record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+            None => vm.authorize_fee_public(
+                private_key,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+        };
+        // Execute and prove the fee.
+        vm.execute_fee_authorization(authorization, Some(query), rng)
+    }
+
+    /// Returns the fee record to pay the given fee with, as the smallest of the given credits records
+    /// that covers the fee. If no records are given, then `None` is returned, and the fee is paid publicly.
+    pub fn select_fee_record(
+        records: Vec<Record<N, Plaintext<N>>>,
+        fee_in_microcredits: u64,
+    ) -> Result<Option<Record<N, Plaintext<N>>>> {
+        // If no records are given, then the fee is paid publicly.
+        if records.is_empty() {
+            return Ok(None);
+        }
+        // Retrieve the balance of each record.
+        let microcredits = Identifier::from_str("microcredits")?;
+        let mut candidates = Vec::with_capacity(records.len());
+        for record in records {
+            let balance = match record.find(&[microcredits])? {
+                Entry::Private(Plaintext::Literal(Literal::U64(amount), _))
+                | Entry::Public(Plaintext::Literal(Literal::U64(amount), _)) => *amount,
+                _ => bail!("The fee record does not contain a 'microcredits' entry"),
+            };
+            candidates.push((balance, record));
+        }
+        // Select the smallest record that covers the fee.
+        match candidates.into_iter().filter(|(balance, _)| *balance >= fee_in_microcredits).min_by_key(|(b, _)| *b) {
+            Some((_, record)) => Ok(Some(record)),
+            None => bail!("None of the given records covers the fee of {fee_in_microcredits} microcredits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::account::Address;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = snarkvm_console::network::MainnetV0;
+
+    #[test]
+    fn test_select_fee_record() {
+        let rng = &mut TestRng::default();
+
+        // Sample credits records with the given balances.
+        let owner = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let records = [5u64, 20, 10]
+            .iter()
+            .map(|amount| {
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                    "{{ owner: {owner}.private, microcredits: {amount}u64.private, _nonce: 0group.public }}"
+                ))
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let select = |fee| Package::<CurrentNetwork>::select_fee_record(records.clone(), fee);
+
+        // Ensure the fee is paid publicly, if no records are given.
+        assert!(Package::<CurrentNetwork>::select_fee_record(vec![], 1).unwrap().is_none());
+        // Ensure the smallest record that covers the fee is selected.
+        assert_eq!(select(8).unwrap(), Some(records[2].clone()));
+        assert_eq!(select(10).unwrap(), Some(records[2].clone()));
+        assert_eq!(select(11).unwrap(), Some(records[1].clone()));
+        // Ensure the selection fails, if no record covers the fee.
+        assert!(select(21).is_err());
+    }
+}
@@ -14,13 +14,17 @@
 
 mod build;
 mod clean;
+mod dependencies;
 mod deploy;
 mod execute;
+mod fee;
 mod is_build_required;
 mod run;
+mod test;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
+pub use test::{ProgramTest, TestCase, TestResult};
 
 use crate::{
     console::{
@@ -28,7 +32,7 @@ use crate::{
         network::Network,
         program::{Identifier, Locator, ProgramID, Response, Value},
     },
-    file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
+    file::{AVMFile, AleoFile, LockFile, Manifest, ProverFile, VerifierFile, README},
     ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
@@ -147,28 +151,52 @@ impl<N: Network> Package<N> {
         self.directory.join("imports")
     }
 
-    /// Returns a new process for the package.
-    pub fn get_process(&self) -> Result<Process<N>> {
-        // Create the process.
-        let mut process = Process::load()?;
-
+    /// Returns the imported programs, in the order they must be added to a process (excluding 'credits.aleo').
+    pub fn imported_programs(&self) -> Result<Vec<Program<N>>> {
         // Prepare the imports directory.
         let imports_directory = self.imports_directory();
 
+        // If the dependencies are locked, return the locked programs (in order).
+        if LockFile::<N>::exists_at(&self.directory) {
+            return LockFile::<N>::open(&self.directory)?
+                .programs()
+                .iter()
+                .map(|locked_program| {
+                    // Open the Aleo program file.
+                    let import_program_file = AleoFile::open(&imports_directory, locked_program.program_id(), false)?;
+                    // Ensure the import program matches the lock file.
+                    locked_program.verify(import_program_file.program())?;
+                    Ok(import_program_file.program().clone())
+                })
+                .collect();
+        }
+
         // Initialize the 'credits.aleo' program ID.
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
 
-        // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
-            // Don't add `credits.aleo` as the process is already loaded with it.
-            if program_id != &credits_program_id {
+        // Return the import programs (in order).
+        self.program()
+            .imports()
+            .keys()
+            // Skip `credits.aleo`, as every process is already loaded with it.
+            .filter(|program_id| *program_id != &credits_program_id)
+            .map(|program_id| {
                 // Open the Aleo program file.
                 let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-                // Add the import program.
-                process.add_program(import_program_file.program())?;
-            }
-            Ok::<_, Error>(())
-        })?;
+                Ok(import_program_file.program().clone())
+            })
+            .collect()
+    }
+
+    /// Returns a new process for the package.
+    pub fn get_process(&self) -> Result<Process<N>> {
+        // Create the process.
+        let mut process = Process::load()?;
+
+        // Add all import programs (in order) to the process.
+        for import_program in self.imported_programs()? {
+            process.add_program(&import_program)?;
+        }
 
         // Add the program to the process.
         process.add_program(self.program())?;
@@ -406,7 +434,7 @@ function main:
         match program_id.to_string().as_str() {
             "token.aleo" => {
                 // Sample a random private key.
-                let private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+                let private_key = crate::cli::helpers::dotenv_private_key::<CurrentNetwork>().unwrap();
                 let caller = Address::try_from(&private_key).unwrap();
 
                 // Initialize the function name.
@@ -420,7 +448,7 @@ function main:
             }
             "wallet.aleo" => {
                 // Initialize caller 0.
-                let caller0_private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+                let caller0_private_key = crate::cli::helpers::dotenv_private_key::<CurrentNetwork>().unwrap();
                 let caller0 = Address::try_from(&caller0_private_key).unwrap();
 
                 // Initialize caller 1.
@@ -442,7 +470,7 @@ function main:
             }
             "grandparent.aleo" => {
                 // Initialize caller 0.
-                let caller0_private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+                let caller0_private_key = crate::cli::helpers::dotenv_private_key::<CurrentNetwork>().unwrap();
 
                 // Initialize caller 1.
                 let caller1_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
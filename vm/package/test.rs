@@ -0,0 +1,575 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::{
+        account::{Address, ViewKey},
+        program::{compute_function_id, Literal, Plaintext, U16, U64},
+        types::{Boolean, Field},
+    },
+    ledger::{
+        block::{Output, Transaction, Transition},
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+        Ledger,
+    },
+    synthesizer::VM,
+};
+
+use anyhow::anyhow;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use serde_yaml::{Mapping, Value as Yaml};
+
+/// The amount of microcredits transferred to each additional test key.
+const TEST_KEY_BALANCE: u64 = 1_000_000_000_000;
+/// The default seed for the RNG of a test.
+const DEFAULT_RANDOMNESS: u64 = 123456789;
+
+/// A test file, which declares its configuration and cases in a leading `/* ... */` YAML comment,
+/// followed by any additional programs (separated by a line of `/`s) to deploy after the package.
+///
+/// ```text
+/// /*
+/// randomness: 42
+/// keys: [APrivateKey1...]
+/// cases:
+///   - program: token.aleo
+///     function: mint_public
+///     inputs: [aleo1..., 100u64]
+///     private_key: APrivateKey1...
+///     outputs: [100u64]
+///     status: accepted
+///     mappings:
+///       - { mapping: account, key: aleo1..., value: 100u64 }
+/// */
+/// ```
+///
+/// The `status` is one of `accepted` (by default), `rejected` (if finalize fails),
+/// `aborted` (if the transaction is excluded from the block), or `failed` (if the transaction
+/// cannot be constructed), in which case `error` may specify a substring of the error.
+pub struct ProgramTest<N: Network> {
+    /// The test name.
+    name: String,
+    /// The additional programs.
+    programs: Vec<Program<N>>,
+    /// The test cases.
+    cases: Vec<TestCase<N>>,
+    /// The seed for the RNG.
+    randomness: u64,
+    /// The additional keys, which are funded before the cases are run.
+    keys: Vec<PrivateKey<N>>,
+}
+
+/// A call to a program function, along with its expected results.
+pub struct TestCase<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The private key of the caller, if it is not the genesis key.
+    private_key: Option<PrivateKey<N>>,
+    /// The expected outputs, if they are checked.
+    outputs: Option<Vec<Value<N>>>,
+    /// The expected status.
+    status: String,
+    /// The expected substring of the error, if the case is expected to fail.
+    error: Option<String>,
+    /// The expected mapping values, as `(program ID, mapping name, key, value)`, after the case.
+    #[allow(clippy::type_complexity)]
+    mappings: Vec<(ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>)>,
+}
+
+/// The result of running a test case.
+pub struct TestResult {
+    /// The locator of the called function.
+    pub locator: String,
+    /// The reasons the case failed, which is empty if the case passed.
+    pub failures: Vec<String>,
+}
+
+impl<N: Network> Package<N> {
+    /// Returns the tests directory.
+    pub fn tests_directory(&self) -> PathBuf {
+        self.directory.join("tests")
+    }
+
+    /// Runs the tests in the tests directory whose name contains the given filter,
+    /// returning the results of each test case by test name.
+    pub fn test(&self, filter: Option<&str>) -> Result<Vec<(String, Vec<TestResult>)>> {
+        // Prepare the tests directory.
+        let tests_directory = self.tests_directory();
+        ensure!(tests_directory.exists(), "The tests directory does not exist: {}", tests_directory.display());
+
+        // Collect the test files (in order).
+        let mut paths = std::fs::read_dir(&tests_directory)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.extension().map_or(false, |extension| extension == "aleo"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        // Retrieve the programs to deploy before each test.
+        let mut programs = self.imported_programs()?;
+        programs.push(self.program().clone());
+
+        let mut results = Vec::new();
+        for path in paths {
+            let test = ProgramTest::<N>::open(&path)?;
+            if filter.map_or(true, |filter| test.name.contains(filter)) {
+                #[cfg(feature = "aleo-cli")]
+                println!("🧪 Running '{}'...\n", test.name.bold());
+                results.push((test.name.clone(), test.run(&programs)?));
+            }
+        }
+        Ok(results)
+    }
+}
+
+impl<N: Network> ProgramTest<N> {
+    /// Opens the test file at the given path.
+    pub fn open(path: &Path) -> Result<Self> {
+        // Retrieve the test name.
+        let name = path.file_stem().and_then(|stem| stem.to_str()).ok_or_else(|| anyhow!("Invalid test file name"))?;
+        // Read the test file.
+        let source = std::fs::read_to_string(path)?;
+        Self::parse(name, &source).map_err(|error| anyhow!("Invalid test '{}' - {error}", path.display()))
+    }
+
+    /// Parses a test from the given source.
+    pub fn parse(name: &str, source: &str) -> Result<Self> {
+        // Parse out the first comment, denoted by `/* ... */`.
+        let start = source.find("/*").ok_or_else(|| anyhow!("Missing the test configuration comment"))? + 2;
+        let end = start + source[start..].find("*/").ok_or_else(|| anyhow!("Unterminated configuration comment"))?;
+        let config = serde_yaml::from_str::<Mapping>(&source[start..end])?;
+
+        // Parse the optional seed for the RNG.
+        let randomness = match config.get("randomness") {
+            Some(randomness) => randomness.as_u64().ok_or_else(|| anyhow!("'randomness' must be a u64"))?,
+            None => DEFAULT_RANDOMNESS,
+        };
+        // Parse the optional additional keys.
+        let keys = match config.get("keys") {
+            Some(keys) => sequence(keys, "keys")?
+                .iter()
+                .map(|key| PrivateKey::from_str(string(key, "key")?))
+                .collect::<Result<_>>()?,
+            None => Vec::new(),
+        };
+        // Parse the cases.
+        let cases = sequence(config.get("cases").ok_or_else(|| anyhow!("Missing 'cases'"))?, "cases")?
+            .iter()
+            .map(TestCase::parse)
+            .collect::<Result<_>>()?;
+
+        // Parse the remainder of the test file into programs.
+        let programs = source[end + 2..]
+            .split("/////////////////////////////////////////////////")
+            .filter(|program| !program.trim().is_empty())
+            .map(Program::from_str)
+            .collect::<Result<_>>()?;
+
+        Ok(Self { name: name.to_string(), programs, cases, randomness, keys })
+    }
+
+    /// Returns the test name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the test against an in-memory ledger, after deploying the given programs and the test programs.
+    pub fn run(&self, programs: &[Program<N>]) -> Result<Vec<TestResult>> {
+        // Initialize the RNG.
+        let rng = &mut ChaChaRng::seed_from_u64(self.randomness);
+
+        // Initialize the ledger, with a genesis block which funds the genesis key.
+        let genesis_private_key = PrivateKey::<N>::new(rng)?;
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        let genesis = vm.genesis_beacon(&genesis_private_key, rng)?;
+        let ledger = Ledger::<N, ConsensusMemory<N>>::load(genesis, None::<u16>.into())?;
+
+        // Fund the additional keys.
+        let mut transactions = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            let inputs = [
+                Value::Plaintext(Plaintext::from(Literal::Address(Address::try_from(key)?))),
+                Value::Plaintext(Plaintext::from(Literal::U64(U64::new(TEST_KEY_BALANCE)))),
+            ];
+            transactions.push(ledger.vm().execute(
+                &genesis_private_key,
+                ("credits.aleo", "transfer_public"),
+                inputs.iter(),
+                None,
+                0,
+                None,
+                rng,
+            )?);
+        }
+        if !transactions.is_empty() {
+            for status in advance(&ledger, &genesis_private_key, transactions, rng)? {
+                ensure!(status == "accepted", "Failed to fund the test keys ({status})");
+            }
+        }
+
+        // Deploy the programs, one block at a time (as they may depend on each other).
+        for program in programs.iter().chain(&self.programs) {
+            let transaction = ledger.vm().deploy(&genesis_private_key, program, None, 0, None, rng)?;
+            let status = advance(&ledger, &genesis_private_key, vec![transaction], rng)?.remove(0);
+            ensure!(status == "accepted", "Failed to deploy '{}' ({status})", program.id());
+        }
+
+        // Run each test case.
+        self.cases.iter().map(|case| case.run(&ledger, &genesis_private_key, rng)).collect()
+    }
+}
+
+impl<N: Network> TestCase<N> {
+    /// Parses a test case from its YAML mapping.
+    fn parse(case: &Yaml) -> Result<Self> {
+        let field = |name: &str| case.get(name).ok_or_else(|| anyhow!("Missing '{name}' in test case"));
+
+        // Parse the call.
+        let program_id = ProgramID::from_str(string(field("program")?, "program")?)?;
+        let function_name = Identifier::from_str(string(field("function")?, "function")?)?;
+        let inputs = match case.get("inputs") {
+            Some(inputs) => sequence(inputs, "inputs")?.iter().map(value).collect::<Result<_>>()?,
+            None => Vec::new(),
+        };
+        let private_key =
+            case.get("private_key").map(|key| PrivateKey::from_str(string(key, "private_key")?)).transpose()?;
+
+        // Parse the expectations.
+        let outputs = case
+            .get("outputs")
+            .map(|outputs| sequence(outputs, "outputs")?.iter().map(value).collect::<Result<_>>())
+            .transpose()?;
+        let status = match case.get("status") {
+            Some(status) => string(status, "status")?.to_string(),
+            None => "accepted".to_string(),
+        };
+        ensure!(
+            ["accepted", "rejected", "aborted", "failed"].contains(&status.as_str()),
+            "Invalid status '{status}' (expected 'accepted', 'rejected', 'aborted', or 'failed')"
+        );
+        let error = case.get("error").map(|error| Ok::<_, Error>(string(error, "error")?.to_string())).transpose()?;
+        let mappings = match case.get("mappings") {
+            Some(mappings) => sequence(mappings, "mappings")?
+                .iter()
+                .map(|mapping| {
+                    let field = |name: &str| mapping.get(name).ok_or_else(|| anyhow!("Missing '{name}' in mapping"));
+                    let mapping_program_id = match mapping.get("program") {
+                        Some(program) => ProgramID::from_str(string(program, "program")?)?,
+                        None => program_id,
+                    };
+                    let mapping_name = Identifier::from_str(string(field("mapping")?, "mapping")?)?;
+                    let key = Plaintext::from_str(string(field("key")?, "key")?)?;
+                    let value = match field("value")? {
+                        Yaml::Null => None,
+                        expected => Some(value(expected)?),
+                    };
+                    Ok((mapping_program_id, mapping_name, key, value))
+                })
+                .collect::<Result<_>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self { program_id, function_name, inputs, private_key, outputs, status, error, mappings })
+    }
+
+    /// Runs the test case on the given ledger, and returns the result.
+    fn run<R: Rng + CryptoRng>(
+        &self,
+        ledger: &Ledger<N, ConsensusMemory<N>>,
+        genesis_private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<TestResult> {
+        let locator = Locator::new(self.program_id, self.function_name).to_string();
+        let private_key = self.private_key.as_ref().unwrap_or(genesis_private_key);
+        let mut failures = Vec::new();
+
+        // Construct and verify the transaction.
+        let result = ledger
+            .vm()
+            .execute(private_key, (self.program_id, self.function_name), self.inputs.iter(), None, 0, None, rng)
+            .and_then(|transaction| {
+                ledger.vm().check_transaction(&transaction, None, rng)?;
+                Ok(transaction)
+            });
+
+        let status = match result {
+            Ok(transaction) => {
+                // Check the outputs of the root transition, ignoring futures and the nonces of records.
+                if let Some(expected) = &self.outputs {
+                    let transition = transaction
+                        .transitions()
+                        .filter(|transition| {
+                            transition.program_id() == &self.program_id
+                                && transition.function_name() == &self.function_name
+                        })
+                        .last()
+                        .ok_or_else(|| anyhow!("Missing the '{locator}' transition in the transaction"))?;
+                    let actual = output_values(transition, private_key)?;
+                    let matches = expected.len() == actual.len()
+                        && expected.iter().zip(&actual).all(|(expected, actual)| match (expected, actual) {
+                            (Value::Record(expected), Value::Record(actual)) => {
+                                expected.owner() == actual.owner() && expected.data() == actual.data()
+                            }
+                            (expected, actual) => expected == actual,
+                        });
+                    if !matches {
+                        failures.push(format!(
+                            "Expected outputs [{}], found [{}]",
+                            expected.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+                            actual.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+                        ));
+                    }
+                }
+                // Add the transaction to the next block.
+                advance(ledger, genesis_private_key, vec![transaction], rng)?.remove(0)
+            }
+            Err(error) => {
+                // Check the error, if one is expected.
+                if let Some(expected) = &self.error {
+                    if !error.to_string().contains(expected.as_str()) {
+                        failures.push(format!("Expected an error containing '{expected}', found '{error}'"));
+                    }
+                }
+                "failed".to_string()
+            }
+        };
+
+        // Check the status.
+        if status != self.status {
+            failures.push(format!("Expected the transaction to be {}, but it was {status}", self.status));
+        }
+
+        // Check the mapping values.
+        for (program_id, mapping_name, key, expected) in &self.mappings {
+            let actual = ledger.vm().finalize_store().get_value_confirmed(*program_id, *mapping_name, key)?;
+            if actual != *expected {
+                let print =
+                    |value: &Option<Value<N>>| value.as_ref().map_or("nothing".to_string(), ToString::to_string);
+                failures.push(format!(
+                    "Expected '{program_id}/{mapping_name}[{key}]' to be {}, found {}",
+                    print(expected),
+                    print(&actual)
+                ));
+            }
+        }
+
+        Ok(TestResult { locator, failures })
+    }
+}
+
+/// Returns the output values of the given transition, as seen by the caller with the given private key.
+/// Futures are skipped, and private outputs and records are decrypted.
+fn output_values<N: Network>(transition: &Transition<N>, private_key: &PrivateKey<N>) -> Result<Vec<Value<N>>> {
+    let view_key = ViewKey::try_from(private_key)?;
+    // Compute the function ID and the transition view key, which together derive the private output view keys.
+    let function_id = compute_function_id(&U16::new(N::ID), transition.program_id(), transition.function_name())?;
+    let tvk = (*transition.tpk() * *view_key).to_x_coordinate();
+    let num_inputs = transition.inputs().len();
+
+    let mut values = Vec::with_capacity(transition.outputs().len());
+    for (index, output) in transition.outputs().iter().enumerate() {
+        match output {
+            Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                values.push(Value::Plaintext(plaintext.clone()))
+            }
+            Output::Private(_, Some(ciphertext)) => {
+                let index = Field::from_u16(u16::try_from(num_inputs + index)?);
+                let output_view_key = N::hash_psd4(&[function_id, tvk, index])?;
+                values.push(Value::Plaintext(ciphertext.decrypt_symmetric(output_view_key)?))
+            }
+            Output::Record(_, _, Some(record)) => values.push(Value::Record(record.decrypt(&view_key)?)),
+            Output::Future(..) => continue,
+            output => bail!("Cannot recover the output '{output}' from the transaction"),
+        }
+    }
+    Ok(values)
+}
+
+/// Adds the given transactions to the next block, and returns the status of each transaction.
+fn advance<N: Network, R: Rng + CryptoRng>(
+    ledger: &Ledger<N, ConsensusMemory<N>>,
+    private_key: &PrivateKey<N>,
+    transactions: Vec<Transaction<N>>,
+    rng: &mut R,
+) -> Result<Vec<String>> {
+    // Retrieve the transaction IDs.
+    let transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+    // Produce the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(private_key, vec![], vec![], transactions, rng)?;
+    ledger.check_next_block(&block, rng)?;
+    ledger.advance_to_next_block(&block)?;
+    // Determine the status of each transaction.
+    Ok(transaction_ids
+        .iter()
+        .map(|transaction_id| {
+            match block.transactions().find_confirmed_transaction_for_unconfirmed_transaction_id(transaction_id) {
+                Some(confirmed) if confirmed.is_accepted() => "accepted".to_string(),
+                Some(_) => "rejected".to_string(),
+                None => "aborted".to_string(),
+            }
+        })
+        .collect())
+}
+
+/// Returns the given YAML value as a string.
+fn string<'a>(yaml: &'a Yaml, name: &str) -> Result<&'a str> {
+    yaml.as_str().ok_or_else(|| anyhow!("'{name}' must be a string"))
+}
+
+/// Returns the given YAML value as a sequence.
+fn sequence<'a>(yaml: &'a Yaml, name: &str) -> Result<&'a Vec<Yaml>> {
+    yaml.as_sequence().ok_or_else(|| anyhow!("'{name}' must be a sequence"))
+}
+
+/// Returns the given YAML value as a program value, where booleans may be written without quotes.
+fn value<N: Network>(yaml: &Yaml) -> Result<Value<N>> {
+    match yaml {
+        Yaml::Bool(boolean) => Ok(Value::from(Literal::Boolean(Boolean::new(*boolean)))),
+        yaml => Value::from_str(string(yaml, "value")?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let test = ProgramTest::<CurrentNetwork>::parse(
+            "sample",
+            r"/*
+randomness: 42
+cases:
+  - program: sample.aleo
+    function: check
+    inputs: [true, 1u32]
+    outputs: [1u32]
+  - program: sample.aleo
+    function: check
+    inputs: [false, 1u32]
+    status: failed
+    error: assert
+*/
+
+program sample.aleo;
+
+function check:
+    input r0 as boolean.public;
+    input r1 as u32.public;
+    assert.eq r0 true;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+        assert_eq!(test.name(), "sample");
+        assert_eq!(test.randomness, 42);
+        assert_eq!(test.programs.len(), 1);
+        assert_eq!(test.cases.len(), 2);
+        assert_eq!(test.cases[0].inputs[0], Value::from_str("true").unwrap());
+        assert_eq!(test.cases[0].status, "accepted");
+        assert_eq!(test.cases[1].status, "failed");
+
+        // Ensure an invalid status is rejected.
+        assert!(ProgramTest::<CurrentNetwork>::parse(
+            "sample",
+            "/*\ncases:\n  - { program: sample.aleo, function: check, status: done }\n*/"
+        )
+        .is_err());
+        // Ensure a missing configuration is rejected.
+        assert!(ProgramTest::<CurrentNetwork>::parse("sample", "program sample.aleo;").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program counter.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function increment:
+    input r0 as u64.public;
+    assert.neq r0 0u64;
+    async increment self.caller r0 into r1;
+    output r0 as u64.public;
+    output r1 as counter.aleo/increment.future;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    lt r3 10u64 into r4;
+    assert.eq r4 true;
+    set r3 into counts[r0];",
+        )
+        .unwrap();
+
+        // Derive the genesis address, which is the default caller.
+        let caller = Address::try_from(
+            PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(DEFAULT_RANDOMNESS)).unwrap(),
+        )
+        .unwrap();
+
+        let test = ProgramTest::<CurrentNetwork>::parse(
+            "counter",
+            &format!(
+                r"/*
+cases:
+  - program: counter.aleo
+    function: increment
+    inputs: [3u64]
+    outputs: [3u64]
+    mappings:
+      - {{ mapping: counts, key: {caller}, value: 3u64 }}
+  - program: counter.aleo
+    function: increment
+    inputs: [0u64]
+    status: failed
+  - program: counter.aleo
+    function: increment
+    inputs: [9u64]
+    status: rejected
+    mappings:
+      - {{ mapping: counts, key: {caller}, value: 3u64 }}
+  - program: counter.aleo
+    function: increment
+    inputs: [1u64]
+    outputs: [2u64]
+*/"
+            ),
+        )
+        .unwrap();
+
+        let results = test.run(&[program]).unwrap();
+        assert_eq!(results.len(), 4);
+        assert!(results[0].failures.is_empty(), "{:?}", results[0].failures);
+        assert!(results[1].failures.is_empty(), "{:?}", results[1].failures);
+        assert!(results[2].failures.is_empty(), "{:?}", results[2].failures);
+        // Ensure the mismatched output is reported.
+        assert_eq!(results[3].failures.len(), 1);
+        assert_eq!(results[3].locator, "counter.aleo/increment");
+    }
+}